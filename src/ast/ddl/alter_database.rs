@@ -1,4 +1,6 @@
-use crate::ast::predule::{DDLStatement, SQLStatement};
+use crate::ast::predule::{CodeComments, DDLStatement, SQLStatement};
+use crate::lexer::predule::NodeSpan;
+use serde::{Deserialize, Serialize};
 
 /*
 ALTER DATABASE database_name
@@ -6,10 +8,13 @@ ALTER DATABASE database_name
     RENAME TO new_database_name
 };
 */
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct AlterDatabaseQuery {
     pub database_name: Option<String>,
     pub action: Option<AlterDatabaseAction>,
+    pub span: NodeSpan,
+    #[serde(default, skip_serializing_if = "CodeComments::is_empty")]
+    pub code_comments: CodeComments,
 }
 
 impl AlterDatabaseQuery {
@@ -17,6 +22,8 @@ impl AlterDatabaseQuery {
         AlterDatabaseQuery {
             database_name: None,
             action: None,
+            span: NodeSpan::default(),
+            code_comments: CodeComments::default(),
         }
     }
 
@@ -35,12 +42,13 @@ impl AlterDatabaseQuery {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", content = "value")]
 pub enum AlterDatabaseAction {
     RenameTo(AlterDatabaseRenameTo),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct AlterDatabaseRenameTo {
     pub name: String,
 }
@@ -1,29 +1,57 @@
 //use crate::ast::enums::SQLStatement;
-use crate::ast::predule::{Column, DataType, SQLStatement, TableName};
+use crate::ast::predule::{
+    CodeComments, Column, DataType, DropBehavior, Expression, Identity, IdentityKind, Index,
+    SQLStatement, TableConstraint, TableName, TableOption,
+};
+use crate::lexer::predule::NodeSpan;
+use serde::{Deserialize, Serialize};
 
 use super::DDLStatement;
 
 /*
-ALTER TABLE [database_name.]table_name
+ALTER TABLE [ONLY] [database_name.]table_name
+action [, action ...];
+
+action:
 {
     [RENAME TO new_table_name] |
     [RENAME COLUMN from_name TO new_name] |
     [ALTER COLUMN column_name ...] |
     [DROP COLUMN column_name] |
-    [ADD COLUMN column_name column_type ... ] ...
-};
+    [ADD COLUMN column_name column_type ... [FIRST | AFTER column_name]] |
+    [MODIFY [COLUMN] column_name column_type ... [FIRST | AFTER column_name]] |
+    [CHANGE [COLUMN] old_name new_name column_type ... [FIRST | AFTER column_name]] |
+    [ADD table_constraint [NOT VALID]] |
+    [ADD {INDEX | KEY} [index_name] (column_name [, ...])] |
+    [DROP CONSTRAINT [IF EXISTS] constraint_name [CASCADE | RESTRICT]] |
+    [DROP FOREIGN KEY constraint_name] |
+    [DROP PRIMARY KEY] |
+    [DROP {INDEX | KEY} index_name] |
+    [RENAME CONSTRAINT from_name TO new_name] |
+    [VALIDATE CONSTRAINT constraint_name] |
+    [option_name [=] value]
+}
 */
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct AlterTableQuery {
     pub table: Option<TableName>,
-    pub action: AlterTableAction,
+    pub actions: Vec<AlterTableAction>,
+    // 상속받은 테이블은 제외 (PostgreSQL)
+    #[serde(default)]
+    pub only: bool,
+    pub span: NodeSpan,
+    #[serde(default, skip_serializing_if = "CodeComments::is_empty")]
+    pub code_comments: CodeComments,
 }
 
 impl AlterTableQuery {
     pub fn builder() -> Self {
         AlterTableQuery {
             table: None,
-            action: AlterTableAction::None,
+            actions: vec![],
+            only: false,
+            span: NodeSpan::default(),
+            code_comments: CodeComments::default(),
         }
     }
 
@@ -32,8 +60,13 @@ impl AlterTableQuery {
         self
     }
 
-    pub fn set_action(mut self, action: AlterTableAction) -> Self {
-        self.action = action;
+    pub fn add_action(mut self, action: AlterTableAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    pub fn set_only(mut self, only: bool) -> Self {
+        self.only = only;
         self
     }
 
@@ -42,19 +75,30 @@ impl AlterTableQuery {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", content = "value")]
+#[allow(clippy::large_enum_variant)]
 pub enum AlterTableAction {
     AlterTableRenameTo(AlterTableRenameTo),
     AddColumn(AlterTableAddColumn),
+    ModifyColumn(AlterTableModifyColumn),
+    ChangeColumn(AlterTableChangeColumn),
     AlterColumn(AlterTableAlterColumn),
     DropColumn(AlterTableDropColumn),
     RenameColumn(AlterTableRenameColumn),
-    None,
+    AddConstraint(AlterTableAddConstraint),
+    DropConstraint(AlterTableDropConstraint),
+    DropPrimaryKey,
+    AddIndex(AlterTableAddIndex),
+    DropIndex(AlterTableDropIndex),
+    RenameConstraint(AlterTableRenameConstraint),
+    ValidateConstraint(AlterTableValidateConstraint),
+    SetTableOption(AlterTableSetTableOption),
 }
 
 // 테이블명 변경
 // ALTER TABLE [database_name.]table_name RENAME TO new_table_name;
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct AlterTableRenameTo {
     pub name: String,
 }
@@ -67,7 +111,7 @@ impl From<AlterTableRenameTo> for AlterTableAction {
 
 // 컬럼 이름 변경
 // ALTER TABLE [database_name.]table_name RENAME COLUMN from_name TO new_name;
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct AlterTableRenameColumn {
     pub from_name: String,
     pub to_name: String,
@@ -79,11 +123,21 @@ impl From<AlterTableRenameColumn> for AlterTableAction {
     }
 }
 
+// 컬럼 위치 지정 (MySQL)
+// FIRST | AFTER column_name
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", content = "value")]
+pub enum ColumnPosition {
+    First,
+    After(String),
+}
+
 // 컬럼 추가
-// ALTER TABLE [database_name.]table_name ADD COLUMN column_name column_type [NOT NULL | NULL] [PRIMARY KEY] [COMMENT 'comment'];
-#[derive(Clone, Debug, PartialEq)]
+// ALTER TABLE [database_name.]table_name ADD COLUMN column_name column_type [NOT NULL | NULL] [PRIMARY KEY] [COMMENT 'comment'] [FIRST | AFTER column_name];
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct AlterTableAddColumn {
     pub column: Column,
+    pub position: Option<ColumnPosition>,
 }
 
 impl From<AlterTableAddColumn> for AlterTableAction {
@@ -92,9 +146,38 @@ impl From<AlterTableAddColumn> for AlterTableAction {
     }
 }
 
+// 컬럼 정의 변경 (MySQL)
+// ALTER TABLE [database_name.]table_name MODIFY [COLUMN] column_name column_type ... [FIRST | AFTER column_name];
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct AlterTableModifyColumn {
+    pub column: Column,
+    pub position: Option<ColumnPosition>,
+}
+
+impl From<AlterTableModifyColumn> for AlterTableAction {
+    fn from(value: AlterTableModifyColumn) -> AlterTableAction {
+        AlterTableAction::ModifyColumn(value)
+    }
+}
+
+// 컬럼명과 정의 변경 (MySQL)
+// ALTER TABLE [database_name.]table_name CHANGE [COLUMN] old_name new_name column_type ... [FIRST | AFTER column_name];
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct AlterTableChangeColumn {
+    pub old_name: String,
+    pub column: Column,
+    pub position: Option<ColumnPosition>,
+}
+
+impl From<AlterTableChangeColumn> for AlterTableAction {
+    fn from(value: AlterTableChangeColumn) -> AlterTableAction {
+        AlterTableAction::ChangeColumn(value)
+    }
+}
+
 // 컬럼 삭제
 // ALTER TABLE [database_name.]table_name DROP COLUMN column_name;
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct AlterTableDropColumn {
     pub column_name: String,
 }
@@ -105,9 +188,118 @@ impl From<AlterTableDropColumn> for AlterTableAction {
     }
 }
 
+// 제약조건 추가
+// ALTER TABLE [database_name.]table_name ADD [CONSTRAINT constraint_name] { PRIMARY KEY | UNIQUE | FOREIGN KEY | CHECK } ... [NOT VALID];
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct AlterTableAddConstraint {
+    pub constraint: TableConstraint,
+    // 기존 행은 검사하지 않음 (PostgreSQL)
+    pub not_valid: bool,
+}
+
+impl From<AlterTableAddConstraint> for AlterTableAction {
+    fn from(value: AlterTableAddConstraint) -> AlterTableAction {
+        AlterTableAction::AddConstraint(value)
+    }
+}
+
+// 제약조건 삭제
+// ALTER TABLE [database_name.]table_name DROP CONSTRAINT [IF EXISTS] constraint_name [CASCADE | RESTRICT];
+// ALTER TABLE [database_name.]table_name DROP FOREIGN KEY constraint_name; (MySQL)
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterTableDropConstraint {
+    pub constraint_name: String,
+    pub if_exists: bool,
+    pub drop_behavior: Option<DropBehavior>,
+}
+
+impl From<AlterTableDropConstraint> for AlterTableAction {
+    fn from(value: AlterTableDropConstraint) -> AlterTableAction {
+        AlterTableAction::DropConstraint(value)
+    }
+}
+
+// 인덱스 추가 (MySQL)
+// ALTER TABLE [database_name.]table_name ADD {INDEX | KEY} [index_name] (column_name [ASC | DESC] [, ...]);
+// 인덱스명을 생략하면 첫번째 컬럼명을 사용합니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterTableAddIndex {
+    pub index: Index,
+}
+
+impl From<AlterTableAddIndex> for AlterTableAction {
+    fn from(value: AlterTableAddIndex) -> AlterTableAction {
+        AlterTableAction::AddIndex(value)
+    }
+}
+
+// 인덱스 삭제
+// ALTER TABLE [database_name.]table_name DROP {INDEX | KEY} index_name;
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterTableDropIndex {
+    pub index_name: String,
+}
+
+impl From<AlterTableDropIndex> for AlterTableAction {
+    fn from(value: AlterTableDropIndex) -> AlterTableAction {
+        AlterTableAction::DropIndex(value)
+    }
+}
+
+// 제약조건 이름 변경
+// ALTER TABLE [database_name.]table_name RENAME CONSTRAINT from_name TO to_name;
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterTableRenameConstraint {
+    pub from_name: String,
+    pub to_name: String,
+}
+
+impl From<AlterTableRenameConstraint> for AlterTableAction {
+    fn from(value: AlterTableRenameConstraint) -> AlterTableAction {
+        AlterTableAction::RenameConstraint(value)
+    }
+}
+
+// NOT VALID로 추가한 제약조건 검사 (PostgreSQL)
+// ALTER TABLE [database_name.]table_name VALIDATE CONSTRAINT constraint_name;
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterTableValidateConstraint {
+    pub constraint_name: String,
+}
+
+impl From<AlterTableValidateConstraint> for AlterTableAction {
+    fn from(value: AlterTableValidateConstraint) -> AlterTableAction {
+        AlterTableAction::ValidateConstraint(value)
+    }
+}
+
+// 테이블 옵션 변경 (MySQL)
+// ALTER TABLE [database_name.]table_name option_name [=] value;
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterTableSetTableOption {
+    pub option: TableOption,
+}
+
+impl From<AlterTableSetTableOption> for AlterTableAction {
+    fn from(value: AlterTableSetTableOption) -> AlterTableAction {
+        AlterTableAction::SetTableOption(value)
+    }
+}
+
 // 컬럼 변경
-// ALTER COLUMN column_name [TYPE type_name] [{SET | DROP} NOT NULL] [{SET | DROP} DEFAULT default_expr] [{SET | DROP} COMMENT 'comment']
-#[derive(Clone, Debug, PartialEq)]
+// ALTER [COLUMN] column_name
+// {
+//     [[SET DATA] TYPE type_name [USING expression]] |
+//     [{SET | DROP} NOT NULL] |
+//     [SET DEFAULT default_expr | DROP DEFAULT] |
+//     [SET COMMENT 'comment' | DROP COMMENT] |
+//     [ADD GENERATED {ALWAYS | BY DEFAULT} AS IDENTITY [( sequence_options )]] |
+//     [SET GENERATED {ALWAYS | BY DEFAULT}] |
+//     [DROP IDENTITY [IF EXISTS]] |
+//     [SET STATISTICS integer] |
+//     [SET COLLATION collation_name]
+// }
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct AlterTableAlterColumn {
     pub column_name: String,
     pub action: AlterColumnAction,
@@ -119,18 +311,28 @@ impl From<AlterTableAlterColumn> for AlterTableAction {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", content = "value")]
 pub enum AlterColumnAction {
     AlterColumnSetType(AlterColumnSetType),
     AlterColumnSetNotNull,
     AlterColumnDropNotNull,
     AlterColumnSetDefault(AlterColumnSetDefault),
     AlterColumnDropDefault(AlterColumnDropDefault),
+    AlterColumnSetComment(AlterColumnSetComment),
+    AlterColumnDropComment,
+    AlterColumnAddIdentity(AlterColumnAddIdentity),
+    AlterColumnSetIdentity(AlterColumnSetIdentity),
+    AlterColumnDropIdentity(AlterColumnDropIdentity),
+    AlterColumnSetStatistics(AlterColumnSetStatistics),
+    AlterColumnSetCollation(AlterColumnSetCollation),
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+// USING 절은 기존 값을 새 타입으로 변환하는 식입니다. (PostgreSQL)
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct AlterColumnSetType {
     pub data_type: DataType,
+    pub using: Option<Expression>,
 }
 
 impl From<AlterColumnSetType> for AlterColumnAction {
@@ -139,7 +341,7 @@ impl From<AlterColumnSetType> for AlterColumnAction {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct AlterColumnSetNotNull {}
 
 impl From<AlterColumnSetNotNull> for AlterColumnAction {
@@ -148,7 +350,7 @@ impl From<AlterColumnSetNotNull> for AlterColumnAction {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct AlterColumnDropNotNull {}
 
 impl From<AlterColumnDropNotNull> for AlterColumnAction {
@@ -157,9 +359,9 @@ impl From<AlterColumnDropNotNull> for AlterColumnAction {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct AlterColumnSetDefault {
-    pub expression: String,
+    pub expression: Expression,
 }
 
 impl From<AlterColumnSetDefault> for AlterColumnAction {
@@ -168,7 +370,7 @@ impl From<AlterColumnSetDefault> for AlterColumnAction {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct AlterColumnDropDefault {}
 
 impl From<AlterColumnDropDefault> for AlterColumnAction {
@@ -176,3 +378,80 @@ impl From<AlterColumnDropDefault> for AlterColumnAction {
         AlterColumnAction::AlterColumnDropDefault(value)
     }
 }
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterColumnSetComment {
+    pub comment: String,
+}
+
+impl From<AlterColumnSetComment> for AlterColumnAction {
+    fn from(value: AlterColumnSetComment) -> AlterColumnAction {
+        AlterColumnAction::AlterColumnSetComment(value)
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterColumnDropComment {}
+
+impl From<AlterColumnDropComment> for AlterColumnAction {
+    fn from(_value: AlterColumnDropComment) -> AlterColumnAction {
+        AlterColumnAction::AlterColumnDropComment
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterColumnAddIdentity {
+    pub identity: Identity,
+}
+
+impl From<AlterColumnAddIdentity> for AlterColumnAction {
+    fn from(value: AlterColumnAddIdentity) -> AlterColumnAction {
+        AlterColumnAction::AlterColumnAddIdentity(value)
+    }
+}
+
+// 기존 IDENTITY 컬럼의 생성 방식만 바꿉니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterColumnSetIdentity {
+    pub kind: IdentityKind,
+}
+
+impl From<AlterColumnSetIdentity> for AlterColumnAction {
+    fn from(value: AlterColumnSetIdentity) -> AlterColumnAction {
+        AlterColumnAction::AlterColumnSetIdentity(value)
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterColumnDropIdentity {
+    pub if_exists: bool,
+}
+
+impl From<AlterColumnDropIdentity> for AlterColumnAction {
+    fn from(value: AlterColumnDropIdentity) -> AlterColumnAction {
+        AlterColumnAction::AlterColumnDropIdentity(value)
+    }
+}
+
+// 통계 수집 대상 값의 개수 (-1은 기본값)
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterColumnSetStatistics {
+    pub value: i64,
+}
+
+impl From<AlterColumnSetStatistics> for AlterColumnAction {
+    fn from(value: AlterColumnSetStatistics) -> AlterColumnAction {
+        AlterColumnAction::AlterColumnSetStatistics(value)
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct AlterColumnSetCollation {
+    pub collation: String,
+}
+
+impl From<AlterColumnSetCollation> for AlterColumnAction {
+    fn from(value: AlterColumnSetCollation) -> AlterColumnAction {
+        AlterColumnAction::AlterColumnSetCollation(value)
+    }
+}
@@ -1,12 +1,17 @@
-pub use crate::ast::predule::{Column, DDLStatement, SQLStatement};
+pub use crate::ast::predule::{CodeComments, Column, DDLStatement, SQLStatement};
+use crate::lexer::predule::NodeSpan;
+use serde::{Deserialize, Serialize};
 
 /*
 CREATE DATABASE [IF NOT EXISTS] database_name;
 */
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct CreateDatabaseQuery {
     pub database_name: Option<String>,
     pub if_not_exists: bool,
+    pub span: NodeSpan,
+    #[serde(default, skip_serializing_if = "CodeComments::is_empty")]
+    pub code_comments: CodeComments,
 }
 
 impl CreateDatabaseQuery {
@@ -14,6 +19,8 @@ impl CreateDatabaseQuery {
         CreateDatabaseQuery {
             database_name: None,
             if_not_exists: false,
+            span: NodeSpan::default(),
+            code_comments: CodeComments::default(),
         }
     }
 
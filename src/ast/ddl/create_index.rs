@@ -1,17 +1,25 @@
-use crate::ast::predule::{Column, DDLStatement, SQLStatement, TableName};
+use crate::ast::predule::{CodeComments, DDLStatement, IndexColumn, SQLStatement, TableName};
+use crate::lexer::predule::NodeSpan;
+use serde::{Deserialize, Serialize};
 
 /*
-CREATE [ UNIQUE ] INDEX [ IF NOT EXISTS ] name ON table_name
-    ( column_name [, ...] )
+CREATE [ UNIQUE ] INDEX [ IF NOT EXISTS ] name ON [database_name.]table_name
+    [ USING method ] ( column_name [ASC | DESC] [, ...] )
 */
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct CreateIndexQuery {
     pub index_name: String,
     pub table: TableName,
-    pub columns: Vec<Column>,
+    pub columns: Vec<IndexColumn>,
     pub is_unique: bool,
     pub if_not_exists: bool,
+    // USING btree 등 인덱스 방식 (PostgreSQL)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    pub span: NodeSpan,
+    #[serde(default, skip_serializing_if = "CodeComments::is_empty")]
+    pub code_comments: CodeComments,
 }
 
 impl CreateIndexQuery {
@@ -22,6 +30,9 @@ impl CreateIndexQuery {
             is_unique: false,
             if_not_exists: false,
             index_name: "".into(),
+            method: None,
+            span: NodeSpan::default(),
+            code_comments: CodeComments::default(),
         }
     }
 
@@ -35,7 +46,7 @@ impl CreateIndexQuery {
         self
     }
 
-    pub fn add_column(mut self, column: Column) -> Self {
+    pub fn add_column(mut self, column: IndexColumn) -> Self {
         self.columns.push(column);
         self
     }
@@ -50,6 +61,11 @@ impl CreateIndexQuery {
         self
     }
 
+    pub fn set_method(mut self, method: String) -> Self {
+        self.method = Some(method);
+        self
+    }
+
     pub fn build(self) -> SQLStatement {
         SQLStatement::DDL(DDLStatement::CreateIndexQuery(self))
     }
@@ -1,29 +1,46 @@
 use crate::ast::predule::{
-    Column, DDLStatement, ForeignKey, SQLStatement, TableName, TableOptions, UniqueKey,
+    CheckConstraint, CodeComments, Column, DDLStatement, ForeignKey, Index, PrimaryKey,
+    SQLStatement, TableConstraint, TableName, TableOptions, UniqueKey,
 };
+use crate::errors::predule::Error;
+use crate::lexer::predule::NodeSpan;
+use serde::{Deserialize, Serialize};
 
 /*
 CREATE TABLE [IF NOT EXISTS] [database_name.]table_name (
     column_name data_type [NOT NULL | NULL] [PRIMARY KEY] [COMMENT 'comment'],
-    column_name data_type [NOT NULL | NULL] [PRIMARY KEY] [COMMENT 'comment'],
+    column_name data_type [NOT NULL | NULL] [DEFAULT expression] [UNIQUE [KEY]]
+        [[CONSTRAINT constraint_name] REFERENCES table_name [(column_name)]]
+        [[CONSTRAINT constraint_name] CHECK (expression)] [AUTO_INCREMENT]
+        [GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY [(sequence_options)]]
+        [[GENERATED ALWAYS] AS (expression) [STORED | VIRTUAL]] [COLLATE collation_name],
     ...
-    PRIMARY KEY (column_name),
-    UNIQUE (column_name),
-    FOREIGN KEY (column_name) REFERENCES table_name (column_name),
-    FOREIGN KEY (column_name) REFERENCES table_name (column_name),
+    [CONSTRAINT constraint_name] PRIMARY KEY (column_name [, ...]),
+    [CONSTRAINT constraint_name] UNIQUE [key_name] (column_name [, ...]),
+    [CONSTRAINT constraint_name] FOREIGN KEY [key_name] (column_name [, ...])
+        REFERENCES table_name (column_name [, ...])
+        [ON DELETE reference_option] [ON UPDATE reference_option],
+    [CONSTRAINT constraint_name] CHECK (expression),
+    {INDEX | KEY} [index_name] (column_name [ASC | DESC] [, ...]),
     ...
 );
 */
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct CreateTableQuery {
     pub table: Option<TableName>,
     pub columns: Vec<Column>,
-    pub primary_key: Vec<String>,
+    pub primary_key: Option<PrimaryKey>,
     pub foreign_keys: Vec<ForeignKey>,
     pub unique_keys: Vec<UniqueKey>,
+    pub check_constraints: Vec<CheckConstraint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indexes: Vec<Index>,
     pub table_options: Option<TableOptions>,
     pub if_not_exists: bool,
+    pub span: NodeSpan,
+    #[serde(default, skip_serializing_if = "CodeComments::is_empty")]
+    pub code_comments: CodeComments,
 }
 
 impl CreateTableQuery {
@@ -31,11 +48,15 @@ impl CreateTableQuery {
         CreateTableQuery {
             table: None,
             columns: vec![],
-            primary_key: vec![],
+            primary_key: None,
             foreign_keys: vec![],
             unique_keys: vec![],
+            check_constraints: vec![],
+            indexes: vec![],
             table_options: None,
             if_not_exists: false,
+            span: NodeSpan::default(),
+            code_comments: CodeComments::default(),
         }
     }
 
@@ -54,8 +75,8 @@ impl CreateTableQuery {
         self
     }
 
-    pub fn set_primary_key(mut self, columns: Vec<String>) -> Self {
-        self.primary_key = columns;
+    pub fn set_primary_key(mut self, primary_key: PrimaryKey) -> Self {
+        self.primary_key = Some(primary_key);
         self
     }
 
@@ -64,6 +85,46 @@ impl CreateTableQuery {
         self
     }
 
+    pub fn add_foreign_key(mut self, foreign_key: ForeignKey) -> Self {
+        self.foreign_keys.push(foreign_key);
+        self
+    }
+
+    pub fn add_check_constraint(mut self, check_constraint: CheckConstraint) -> Self {
+        self.check_constraints.push(check_constraint);
+        self
+    }
+
+    pub fn add_index(mut self, index: Index) -> Self {
+        self.indexes.push(index);
+        self
+    }
+
+    // 테이블 단위 기본키나 컬럼 단위 PRIMARY KEY가 이미 지정되어 있는지
+    pub fn has_primary_key(&self) -> bool {
+        self.primary_key.is_some() || self.columns.iter().any(|column| column.primary_key)
+    }
+
+    // 제약조건 종류에 맞는 필드에 추가합니다.
+    // 기본키는 하나만 허용하므로 이미 기본키가 있다면 에러를 반환합니다.
+    pub fn add_constraint(self, constraint: TableConstraint) -> Result<Self, Error> {
+        Ok(match constraint {
+            TableConstraint::PrimaryKey(primary_key) => {
+                if self.has_primary_key() {
+                    return Err(Error::parsing(
+                        "E1269",
+                        "multiple primary keys for table are not allowed",
+                    ));
+                }
+
+                self.set_primary_key(primary_key)
+            }
+            TableConstraint::UniqueKey(unique_key) => self.add_unique_key(unique_key),
+            TableConstraint::ForeignKey(foreign_key) => self.add_foreign_key(foreign_key),
+            TableConstraint::Check(check_constraint) => self.add_check_constraint(check_constraint),
+        })
+    }
+
     pub fn set_if_not_exists(mut self, if_not_exists: bool) -> Self {
         self.if_not_exists = if_not_exists;
         self
@@ -1,24 +1,33 @@
-pub use crate::ast::predule::{Column, DDLStatement, SQLStatement};
+pub use crate::ast::predule::{CodeComments, Column, DDLStatement, DropBehavior, SQLStatement};
+use crate::lexer::predule::NodeSpan;
+use serde::{Deserialize, Serialize};
 
 /*
-DROP DATABASE [IF EXISTS] database_name;
+DROP DATABASE [IF EXISTS] database_name [, ...] [CASCADE | RESTRICT];
 */
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct DropDatabaseQuery {
-    pub database_name: Option<String>,
+    pub database_names: Vec<String>,
     pub if_exists: bool,
+    pub drop_behavior: Option<DropBehavior>,
+    pub span: NodeSpan,
+    #[serde(default, skip_serializing_if = "CodeComments::is_empty")]
+    pub code_comments: CodeComments,
 }
 
 impl DropDatabaseQuery {
     pub fn builder() -> Self {
         DropDatabaseQuery {
-            database_name: None,
+            database_names: vec![],
             if_exists: false,
+            drop_behavior: None,
+            span: NodeSpan::default(),
+            code_comments: CodeComments::default(),
         }
     }
 
-    pub fn set_name(mut self, name: String) -> Self {
-        self.database_name = Some(name);
+    pub fn add_name(mut self, name: String) -> Self {
+        self.database_names.push(name);
         self
     }
 
@@ -27,6 +36,11 @@ impl DropDatabaseQuery {
         self
     }
 
+    pub fn set_drop_behavior(mut self, drop_behavior: DropBehavior) -> Self {
+        self.drop_behavior = Some(drop_behavior);
+        self
+    }
+
     pub fn build(self) -> SQLStatement {
         SQLStatement::DDL(DDLStatement::DropDatabaseQuery(self))
     }
@@ -0,0 +1,55 @@
+use crate::ast::predule::{CodeComments, DDLStatement, SQLStatement, TableName};
+use crate::lexer::predule::NodeSpan;
+use serde::{Deserialize, Serialize};
+
+/*
+DROP INDEX [IF EXISTS] [database_name.]index_name [ON [database_name.]table_name];
+*/
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropIndexQuery {
+    pub database_name: Option<String>,
+    pub index_name: String,
+    // ON table_name (MySQL)
+    pub table: Option<TableName>,
+    pub if_exists: bool,
+    pub span: NodeSpan,
+    #[serde(default, skip_serializing_if = "CodeComments::is_empty")]
+    pub code_comments: CodeComments,
+}
+
+impl DropIndexQuery {
+    pub fn builder() -> Self {
+        DropIndexQuery {
+            database_name: None,
+            index_name: "".into(),
+            table: None,
+            if_exists: false,
+            span: NodeSpan::default(),
+            code_comments: CodeComments::default(),
+        }
+    }
+
+    pub fn set_database_name(mut self, database_name: Option<String>) -> Self {
+        self.database_name = database_name;
+        self
+    }
+
+    pub fn set_index_name(mut self, index_name: String) -> Self {
+        self.index_name = index_name;
+        self
+    }
+
+    pub fn set_table(mut self, table: TableName) -> Self {
+        self.table = Some(table);
+        self
+    }
+
+    pub fn set_if_exists(mut self, if_exists: bool) -> Self {
+        self.if_exists = if_exists;
+        self
+    }
+
+    pub fn build(self) -> SQLStatement {
+        SQLStatement::DDL(DDLStatement::DropIndexQuery(self))
+    }
+}
@@ -1,24 +1,35 @@
-use crate::ast::predule::{DDLStatement, SQLStatement, TableName};
+use crate::ast::predule::{CodeComments, DDLStatement, DropBehavior, SQLStatement, TableName};
+use crate::lexer::predule::NodeSpan;
+use serde::{Deserialize, Serialize};
 
 /*
-DROP TABLE [IF EXISTS] [database_name.]table_name;
+DROP [TEMPORARY] TABLE [IF EXISTS] [database_name.]table_name [, ...] [CASCADE | RESTRICT];
 */
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct DropTableQuery {
-    pub table: Option<TableName>,
+    pub tables: Vec<TableName>,
     pub if_exists: bool,
+    pub temporary: bool,
+    pub drop_behavior: Option<DropBehavior>,
+    pub span: NodeSpan,
+    #[serde(default, skip_serializing_if = "CodeComments::is_empty")]
+    pub code_comments: CodeComments,
 }
 
 impl DropTableQuery {
     pub fn builder() -> Self {
         DropTableQuery {
-            table: None,
+            tables: vec![],
             if_exists: false,
+            temporary: false,
+            drop_behavior: None,
+            span: NodeSpan::default(),
+            code_comments: CodeComments::default(),
         }
     }
 
-    pub fn set_table(mut self, table: TableName) -> Self {
-        self.table = Some(table);
+    pub fn add_table(mut self, table: TableName) -> Self {
+        self.tables.push(table);
         self
     }
 
@@ -27,6 +38,16 @@ impl DropTableQuery {
         self
     }
 
+    pub fn set_temporary(mut self, temporary: bool) -> Self {
+        self.temporary = temporary;
+        self
+    }
+
+    pub fn set_drop_behavior(mut self, drop_behavior: DropBehavior) -> Self {
+        self.drop_behavior = Some(drop_behavior);
+        self
+    }
+
     pub fn build(self) -> SQLStatement {
         SQLStatement::DDL(DDLStatement::DropTableQuery(self))
     }
@@ -15,4 +15,7 @@ pub use create_database::*;
 pub use drop_database::*;
 
 pub mod create_index;
+pub mod drop_index;
+
 pub use create_index::*;
+pub use drop_index::*;
@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ast::predule::{BinaryOperator, ColumnName, DataType, Function, UnaryOperator};
+
+// SQL 표현식
+// DEFAULT, CHECK, 계산 컬럼 등에서 사용되는 표현식 트리입니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", content = "value")]
+pub enum Expression {
+    Integer(i64),
+    Float(f64),
+    String(String),
+    // X'1F' 형태의 16진수 문자열
+    HexString(String),
+    Boolean(bool),
+    Null,
+    Column(ColumnName),
+    SQLValueFunction(SQLValueFunction),
+    Unary(UnaryOperatorExpression),
+    Binary(BinaryOperatorExpression),
+    FunctionCall(CallExpression),
+    Cast(CastExpression),
+    Case(CaseExpression),
+    InList(InListExpression),
+    Between(BetweenExpression),
+    Parentheses(ParenthesesExpression),
+}
+
+// 괄호 없이 호출되는 표준 SQL 함수
+// CURRENT_TIMESTAMP, CURRENT_DATE 등
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub enum SQLValueFunction {
+    CurrentDate,
+    CurrentTime,
+    CurrentTimestamp,
+    LocalTime,
+    LocalTimestamp,
+    CurrentUser,
+    SessionUser,
+}
+
+impl SQLValueFunction {
+    pub fn from_name(name: &str) -> Option<SQLValueFunction> {
+        match name.to_uppercase().as_str() {
+            "CURRENT_DATE" => Some(SQLValueFunction::CurrentDate),
+            "CURRENT_TIME" => Some(SQLValueFunction::CurrentTime),
+            "CURRENT_TIMESTAMP" => Some(SQLValueFunction::CurrentTimestamp),
+            "LOCALTIME" => Some(SQLValueFunction::LocalTime),
+            "LOCALTIMESTAMP" => Some(SQLValueFunction::LocalTimestamp),
+            "CURRENT_USER" => Some(SQLValueFunction::CurrentUser),
+            "SESSION_USER" => Some(SQLValueFunction::SessionUser),
+            _ => None,
+        }
+    }
+}
+
+impl From<SQLValueFunction> for Expression {
+    fn from(value: SQLValueFunction) -> Expression {
+        Expression::SQLValueFunction(value)
+    }
+}
+
+impl From<ColumnName> for Expression {
+    fn from(value: ColumnName) -> Expression {
+        Expression::Column(value)
+    }
+}
+
+// 단항 연산 표현식
+// -A, NOT A
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct UnaryOperatorExpression {
+    pub operator: UnaryOperator,
+    pub operand: Box<Expression>,
+}
+
+impl From<UnaryOperatorExpression> for Expression {
+    fn from(value: UnaryOperatorExpression) -> Expression {
+        Expression::Unary(value)
+    }
+}
+
+// 2항 연산 표현식
+// A + B, A = B, A IS NOT NULL 등
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct BinaryOperatorExpression {
+    pub operator: BinaryOperator,
+    pub lhs: Box<Expression>,
+    pub rhs: Box<Expression>,
+}
+
+impl From<BinaryOperatorExpression> for Expression {
+    fn from(value: BinaryOperatorExpression) -> Expression {
+        Expression::Binary(value)
+    }
+}
+
+// 함수 호출 표현식
+// function_name([argument [, ...]])
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct CallExpression {
+    pub function: Function,
+    pub arguments: Vec<Expression>,
+}
+
+impl From<CallExpression> for Expression {
+    fn from(value: CallExpression) -> Expression {
+        Expression::FunctionCall(value)
+    }
+}
+
+// 타입 변환 표현식
+// CAST(expression AS data_type)
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct CastExpression {
+    pub expression: Box<Expression>,
+    pub data_type: DataType,
+}
+
+impl From<CastExpression> for Expression {
+    fn from(value: CastExpression) -> Expression {
+        Expression::Cast(value)
+    }
+}
+
+// 조건 분기 표현식
+// CASE [operand] WHEN condition THEN result [...] [ELSE result] END
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct CaseExpression {
+    pub operand: Option<Box<Expression>>,
+    pub when_clauses: Vec<WhenClause>,
+    pub else_result: Option<Box<Expression>>,
+}
+
+impl From<CaseExpression> for Expression {
+    fn from(value: CaseExpression) -> Expression {
+        Expression::Case(value)
+    }
+}
+
+// WHEN condition THEN result
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct WhenClause {
+    pub condition: Expression,
+    pub result: Expression,
+}
+
+// 목록 포함 여부 표현식
+// expression [NOT] IN (value [, ...])
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct InListExpression {
+    pub expression: Box<Expression>,
+    pub list: Vec<Expression>,
+    pub negated: bool,
+}
+
+impl From<InListExpression> for Expression {
+    fn from(value: InListExpression) -> Expression {
+        Expression::InList(value)
+    }
+}
+
+// 범위 표현식
+// expression [NOT] BETWEEN low AND high
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct BetweenExpression {
+    pub expression: Box<Expression>,
+    pub low: Box<Expression>,
+    pub high: Box<Expression>,
+    pub negated: bool,
+}
+
+impl From<BetweenExpression> for Expression {
+    fn from(value: BetweenExpression) -> Expression {
+        Expression::Between(value)
+    }
+}
+
+// 괄호로 감싼 표현식
+// (expression)
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct ParenthesesExpression {
+    pub expression: Box<Expression>,
+}
+
+impl From<ParenthesesExpression> for Expression {
+    fn from(value: ParenthesesExpression) -> Expression {
+        Expression::Parentheses(value)
+    }
+}
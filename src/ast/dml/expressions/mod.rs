@@ -1,2 +1,5 @@
 pub mod operators;
 pub use operators::*;
+
+pub mod expression;
+pub use expression::*;
@@ -3,56 +3,85 @@ use serde::{Deserialize, Serialize};
 // 2항연산자
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub enum BinaryOperator {
-    Add,     // A + B
-    Sub,     // A - B
-    Mul,     // A * B
-    Div,     // A / B
-    And,     // A AND B
-    Or,      // A OR B
-    Lt,      // A < B
-    Gt,      // A > B
-    Lte,     // A <= B
-    Gte,     // A >= B
-    Eq,      // A = B
-    Neq,     // A != B, A <> B
-    Like,    // A LIKE B
-    NotLike, // A NOT LIKE B
-    In,      // A In B
-    NotIn,   // A Not In B
-    Is,      // A Is B
-    IsNot,   // A Is Not B
+    Add,        // A + B
+    Sub,        // A - B
+    Mul,        // A * B
+    Div,        // A / B
+    Mod,        // A % B
+    Caret,      // A ^ B (PostgreSQL: 거듭제곱, MySQL: 비트 XOR)
+    Concat,     // A || B
+    BitAnd,     // A & B
+    BitOr,      // A | B
+    ShiftLeft,  // A << B
+    ShiftRight, // A >> B
+    And,        // A AND B
+    Or,         // A OR B
+    Lt,         // A < B
+    Gt,         // A > B
+    Lte,        // A <= B
+    Gte,        // A >= B
+    Eq,         // A = B
+    Neq,        // A != B, A <> B
+    Like,       // A LIKE B
+    NotLike,    // A NOT LIKE B
+    In,         // A In B
+    NotIn,      // A Not In B
+    Is,         // A Is B
+    IsNot,      // A Is Not B
 }
 
 // 단항연산자
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub enum UnaryOperator {
-    Pos, // +A
-    Neg, // -A
-    Not, // Not A
+    Pos,    // +A
+    Neg,    // -A
+    Not,    // Not A
+    BitNot, // ~A
 }
 
 impl BinaryOperator {
     // 2항연산자 우선순위 획득
+    // 값이 클수록 먼저 결합합니다.
     pub fn get_precedence(&self) -> i32 {
         match self {
             BinaryOperator::Add => 10,
             BinaryOperator::Sub => 10,
             BinaryOperator::Mul => 40,
             BinaryOperator::Div => 40,
-            BinaryOperator::And => 10,
-            BinaryOperator::Or => 10,
-            BinaryOperator::Lt => 10,
-            BinaryOperator::Gt => 10,
-            BinaryOperator::Lte => 10,
-            BinaryOperator::Gte => 10,
-            BinaryOperator::Eq => 10,
-            BinaryOperator::Neq => 10,
-            BinaryOperator::Like => 10,
-            BinaryOperator::NotLike => 10,
-            BinaryOperator::In => 10,
-            BinaryOperator::NotIn => 10,
-            BinaryOperator::Is => 10,
-            BinaryOperator::IsNot => 10,
+            BinaryOperator::Mod => 40,
+            BinaryOperator::Caret => 45,
+            BinaryOperator::Concat => 8,
+            BinaryOperator::BitAnd => 7,
+            BinaryOperator::BitOr => 6,
+            BinaryOperator::ShiftLeft => 8,
+            BinaryOperator::ShiftRight => 8,
+            BinaryOperator::And => 2,
+            BinaryOperator::Or => 1,
+            BinaryOperator::Lt => 5,
+            BinaryOperator::Gt => 5,
+            BinaryOperator::Lte => 5,
+            BinaryOperator::Gte => 5,
+            BinaryOperator::Eq => 5,
+            BinaryOperator::Neq => 5,
+            BinaryOperator::Like => 5,
+            BinaryOperator::NotLike => 5,
+            BinaryOperator::In => 5,
+            BinaryOperator::NotIn => 5,
+            BinaryOperator::Is => 5,
+            BinaryOperator::IsNot => 5,
+        }
+    }
+}
+
+impl UnaryOperator {
+    // 단항연산자 우선순위 획득
+    // NOT은 비교 연산보다 늦게 결합합니다.
+    pub fn get_precedence(&self) -> i32 {
+        match self {
+            UnaryOperator::Pos => 50,
+            UnaryOperator::Neg => 50,
+            UnaryOperator::Not => 3,
+            UnaryOperator::BitNot => 50,
         }
     }
 }
@@ -0,0 +1,11 @@
+use crate::ast::predule::Expression;
+use crate::lexer::predule::NodeSpan;
+use serde::{Deserialize, Serialize};
+
+// [CONSTRAINT constraint_name] CHECK (expression)
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct CheckConstraint {
+    pub constraint_name: Option<String>,
+    pub expression: Expression,
+    pub span: NodeSpan,
+}
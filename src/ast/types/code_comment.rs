@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+// 구문이나 컬럼 앞뒤에 붙은 코드 주석 (-- comment, /* comment */)
+// SQL의 COMMENT 'comment' 절과는 별개이며, 주석 기호와 앞뒤 공백을 뺀 내용만 담습니다.
+// 위치 정보처럼 AST 노드끼리 비교할 때는 주석이 달라도 같은 노드로 취급합니다.
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct CodeComments {
+    // 노드 앞에 붙은 주석
+    pub leading: Vec<String>,
+    // 노드가 끝나는 줄에 이어서 붙은 주석
+    pub trailing: Vec<String>,
+}
+
+impl CodeComments {
+    pub fn is_empty(&self) -> bool {
+        self.leading.is_empty() && self.trailing.is_empty()
+    }
+}
+
+impl PartialEq for CodeComments {
+    fn eq(&self, _other: &CodeComments) -> bool {
+        true
+    }
+}
+
+impl Eq for CodeComments {}
@@ -1,4 +1,5 @@
-use crate::ast::predule::DataType;
+use crate::ast::predule::{CheckConstraint, CodeComments, DataType, Expression, Reference};
+use crate::lexer::predule::{NodeSpan, Span};
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
@@ -6,9 +7,29 @@ pub struct Column {
     pub name: String,
     pub data_type: DataType,
     pub comment: String,
-    pub default: Option<String>,
+    pub default: Option<Expression>,
     pub not_null: bool,
     pub primary_key: bool,
+    // CONSTRAINT constraint_name PRIMARY KEY 로 지정한 이름
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_key_name: Option<String>,
+    pub unique: bool,
+    // CONSTRAINT constraint_name UNIQUE 로 지정한 이름
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unique_name: Option<String>,
+    pub reference: Option<Reference>,
+    pub checks: Vec<CheckConstraint>,
+    pub auto_increment: bool,
+    pub identity: Option<Identity>,
+    pub generated: Option<GeneratedColumn>,
+    // CHARACTER SET charset_name (MySQL)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub charset: Option<String>,
+    pub collation: Option<String>,
+    pub on_update: Option<Expression>,
+    pub span: NodeSpan,
+    #[serde(default, skip_serializing_if = "CodeComments::is_empty")]
+    pub code_comments: CodeComments,
 }
 
 impl Column {
@@ -22,9 +43,22 @@ pub struct ColumnBuilder {
     name: Option<String>,
     data_type: Option<DataType>,
     comment: Option<String>,
-    default: Option<String>,
+    default: Option<Expression>,
     not_null: Option<bool>,
     primary_key: Option<bool>,
+    primary_key_name: Option<String>,
+    unique: Option<bool>,
+    unique_name: Option<String>,
+    reference: Option<Reference>,
+    checks: Vec<CheckConstraint>,
+    auto_increment: Option<bool>,
+    identity: Option<Identity>,
+    generated: Option<GeneratedColumn>,
+    charset: Option<String>,
+    collation: Option<String>,
+    on_update: Option<Expression>,
+    span: Option<Span>,
+    code_comments: Option<CodeComments>,
 }
 
 impl ColumnBuilder {
@@ -43,7 +77,7 @@ impl ColumnBuilder {
         self
     }
 
-    pub fn set_default(mut self, default: String) -> Self {
+    pub fn set_default(mut self, default: Expression) -> Self {
         self.default = Some(default);
         self
     }
@@ -61,6 +95,71 @@ impl ColumnBuilder {
         self
     }
 
+    pub fn set_primary_key_name(mut self, primary_key_name: String) -> Self {
+        self.primary_key_name = Some(primary_key_name);
+        self
+    }
+
+    pub fn set_unique(mut self, unique: bool) -> Self {
+        self.unique = Some(unique);
+        self
+    }
+
+    pub fn set_unique_name(mut self, unique_name: String) -> Self {
+        self.unique_name = Some(unique_name);
+        self
+    }
+
+    pub fn set_reference(mut self, reference: Reference) -> Self {
+        self.reference = Some(reference);
+        self
+    }
+
+    pub fn add_check(mut self, check: CheckConstraint) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    pub fn set_auto_increment(mut self, auto_increment: bool) -> Self {
+        self.auto_increment = Some(auto_increment);
+        self
+    }
+
+    pub fn set_identity(mut self, identity: Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    pub fn set_generated(mut self, generated: GeneratedColumn) -> Self {
+        self.generated = Some(generated);
+        self
+    }
+
+    pub fn set_charset(mut self, charset: String) -> Self {
+        self.charset = Some(charset);
+        self
+    }
+
+    pub fn set_collation(mut self, collation: String) -> Self {
+        self.collation = Some(collation);
+        self
+    }
+
+    pub fn set_on_update(mut self, on_update: Expression) -> Self {
+        self.on_update = Some(on_update);
+        self
+    }
+
+    pub fn set_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    pub fn set_code_comments(mut self, code_comments: CodeComments) -> Self {
+        self.code_comments = Some(code_comments);
+        self
+    }
+
     pub fn build(self) -> Column {
         Column {
             name: self.name.unwrap(),
@@ -69,13 +168,49 @@ impl ColumnBuilder {
             default: self.default,
             not_null: self.not_null.unwrap_or(false),
             primary_key: self.primary_key.unwrap_or(false),
+            primary_key_name: self.primary_key_name,
+            unique: self.unique.unwrap_or(false),
+            unique_name: self.unique_name,
+            reference: self.reference,
+            checks: self.checks,
+            auto_increment: self.auto_increment.unwrap_or(false),
+            identity: self.identity,
+            generated: self.generated,
+            charset: self.charset,
+            collation: self.collation,
+            on_update: self.on_update,
+            span: self.span.unwrap_or_default().into(),
+            code_comments: self.code_comments.unwrap_or_default(),
         }
     }
 }
 
+// GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY [ ( [START WITH n] [INCREMENT BY n] ) ]
+// 식별 컬럼 정보입니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Identity {
+    pub kind: IdentityKind,
+    pub start: Option<i64>,
+    pub increment: Option<i64>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub enum IdentityKind {
+    Always,
+    ByDefault,
+}
+
+// [GENERATED ALWAYS] AS (expression) [STORED | VIRTUAL]
+// 계산 컬럼 정보입니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct GeneratedColumn {
+    pub expression: Expression,
+    pub stored: bool,
+}
+
 // [column_name.]table_name
 // 컬럼명을 가리키는 값입니다.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct ColumnName {
     pub table_name: Option<String>,
     pub column_name: String,
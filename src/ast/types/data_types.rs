@@ -2,31 +2,212 @@ use serde::{Deserialize, Serialize};
 
 // SQL 데이터 타입
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", content = "value")]
 pub enum DataType {
-    Int,
-    Float,
+    // 정수
+    // INT(11)처럼 지정된 표시 너비 (MySQL)
+    TinyInt(Option<i64>),
+    SmallInt(Option<i64>),
+    MediumInt(Option<i64>),
+    Int(Option<i64>),
+    BigInt(Option<i64>),
+    // INT UNSIGNED 등 부호 없는 정수 (MySQL)
+    Unsigned(Box<DataType>),
+    // INT SIGNED처럼 명시한 부호 있는 정수 (MySQL)
+    Signed(Box<DataType>),
+    // INT ZEROFILL 등 0으로 채워 출력하는 숫자 (MySQL)
+    Zerofill(Box<DataType>),
+    SmallSerial,
+    Serial,
+    BigSerial,
+
+    // 실수
+    Real,
+    // FLOAT[(precision)]
+    Float(Option<i64>),
+    Double,
+    // DECIMAL[(precision[, scale])], NUMERIC[(precision[, scale])]
+    Decimal {
+        precision: Option<i64>,
+        scale: Option<i64>,
+    },
+
     Boolean,
-    Varchar(i64),
+
+    // 문자열
+    Char(Option<i64>),
+    // VARCHAR[(length)]
+    Varchar(Option<i64>),
+    TinyText,
+    Text,
+    MediumText,
+    LongText,
+
+    // 바이너리
+    Binary(Option<i64>),
+    Varbinary(i64),
+    Blob,
+    LongBlob,
+    Bytea,
+
+    // 날짜 및 시간
+    Date,
+    Time {
+        precision: Option<i64>,
+        with_time_zone: bool,
+    },
+    Timestamp {
+        precision: Option<i64>,
+        with_time_zone: bool,
+    },
+    DateTime(Option<i64>),
+    Interval,
+    // YEAR[(4)] (MySQL)
+    Year(Option<i64>),
+
+    // 기타
+    Uuid,
+    Json,
+    Jsonb,
+    Xml,
+    // ENUM('a', 'b', ...) (MySQL)
+    Enum(Vec<String>),
+    // 도메인, 사용자 정의 타입 등 알 수 없는 타입명
+    Custom(String),
+    // text[] 등 배열 타입 (PostgreSQL)
+    Array(Box<DataType>),
 }
 
 impl DataType {
     pub fn type_code(&self) -> isize {
         match self {
-            DataType::Int => 1,
-            DataType::Float => 2,
+            DataType::Int(_) => 1,
+            DataType::Float(_) => 2,
             DataType::Boolean => 3,
             DataType::Varchar(_) => 4,
+            DataType::TinyInt(_) => 5,
+            DataType::SmallInt(_) => 6,
+            DataType::MediumInt(_) => 7,
+            DataType::BigInt(_) => 8,
+            DataType::Unsigned(_) => 9,
+            DataType::SmallSerial => 10,
+            DataType::Serial => 11,
+            DataType::BigSerial => 12,
+            DataType::Real => 13,
+            DataType::Double => 14,
+            DataType::Decimal { .. } => 15,
+            DataType::Char(_) => 16,
+            DataType::TinyText => 17,
+            DataType::Text => 18,
+            DataType::MediumText => 19,
+            DataType::LongText => 20,
+            DataType::Binary(_) => 21,
+            DataType::Varbinary(_) => 22,
+            DataType::Blob => 23,
+            DataType::LongBlob => 24,
+            DataType::Bytea => 25,
+            DataType::Date => 26,
+            DataType::Time { .. } => 27,
+            DataType::Timestamp { .. } => 28,
+            DataType::DateTime(_) => 29,
+            DataType::Interval => 30,
+            DataType::Year(_) => 31,
+            DataType::Uuid => 32,
+            DataType::Json => 33,
+            DataType::Jsonb => 34,
+            DataType::Xml => 35,
+            DataType::Enum(_) => 36,
+            DataType::Custom(_) => 37,
+            DataType::Array(_) => 38,
+            DataType::Signed(_) => 39,
+            DataType::Zerofill(_) => 40,
         }
     }
 }
 
+// (n) 형태의 선택적 인자 출력
+fn optional_length(length: Option<i64>) -> String {
+    match length {
+        Some(length) => format!("({})", length),
+        None => "".into(),
+    }
+}
+
 impl From<DataType> for String {
     fn from(value: DataType) -> Self {
         match value {
-            DataType::Int => "integer".into(),
-            DataType::Float => "float".into(),
+            DataType::TinyInt(width) => format!("tinyint{}", optional_length(width)),
+            DataType::SmallInt(width) => format!("smallint{}", optional_length(width)),
+            DataType::MediumInt(width) => format!("mediumint{}", optional_length(width)),
+            DataType::Int(width) => format!("integer{}", optional_length(width)),
+            DataType::BigInt(width) => format!("bigint{}", optional_length(width)),
+            DataType::Unsigned(data_type) => format!("{} unsigned", String::from(*data_type)),
+            DataType::Signed(data_type) => format!("{} signed", String::from(*data_type)),
+            DataType::Zerofill(data_type) => format!("{} zerofill", String::from(*data_type)),
+            DataType::SmallSerial => "smallserial".into(),
+            DataType::Serial => "serial".into(),
+            DataType::BigSerial => "bigserial".into(),
+            DataType::Real => "real".into(),
+            DataType::Float(precision) => format!("float{}", optional_length(precision)),
+            DataType::Double => "double precision".into(),
+            DataType::Decimal { precision, scale } => match (precision, scale) {
+                (Some(precision), Some(scale)) => format!("decimal({}, {})", precision, scale),
+                (precision, _) => format!("decimal{}", optional_length(precision)),
+            },
             DataType::Boolean => "boolean".into(),
-            DataType::Varchar(number) => format!("varchar({})", number),
+            DataType::Char(length) => format!("char{}", optional_length(length)),
+            DataType::Varchar(length) => format!("varchar{}", optional_length(length)),
+            DataType::TinyText => "tinytext".into(),
+            DataType::Text => "text".into(),
+            DataType::MediumText => "mediumtext".into(),
+            DataType::LongText => "longtext".into(),
+            DataType::Binary(length) => format!("binary{}", optional_length(length)),
+            DataType::Varbinary(length) => format!("varbinary({})", length),
+            DataType::Blob => "blob".into(),
+            DataType::LongBlob => "longblob".into(),
+            DataType::Bytea => "bytea".into(),
+            DataType::Date => "date".into(),
+            DataType::Time {
+                precision,
+                with_time_zone,
+            } => format!(
+                "time{}{}",
+                optional_length(precision),
+                if with_time_zone {
+                    " with time zone"
+                } else {
+                    ""
+                }
+            ),
+            DataType::Timestamp {
+                precision,
+                with_time_zone,
+            } => format!(
+                "timestamp{}{}",
+                optional_length(precision),
+                if with_time_zone {
+                    " with time zone"
+                } else {
+                    ""
+                }
+            ),
+            DataType::DateTime(precision) => format!("datetime{}", optional_length(precision)),
+            DataType::Interval => "interval".into(),
+            DataType::Year(width) => format!("year{}", optional_length(width)),
+            DataType::Uuid => "uuid".into(),
+            DataType::Json => "json".into(),
+            DataType::Jsonb => "jsonb".into(),
+            DataType::Xml => "xml".into(),
+            DataType::Enum(values) => format!(
+                "enum({})",
+                values
+                    .iter()
+                    .map(|value| format!("'{}'", value.replace('\'', "''")))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            DataType::Custom(type_name) => type_name,
+            DataType::Array(data_type) => format!("{}[]", String::from(*data_type)),
         }
     }
 }
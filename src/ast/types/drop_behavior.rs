@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+// DROP ... [CASCADE | RESTRICT]
+// 생략한 경우와 RESTRICT를 명시한 경우를 구분하기 위해 Option으로 감싸서 사용합니다.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DropBehavior {
+    // 의존하는 객체도 함께 삭제
+    Cascade,
+    // 의존하는 객체가 있다면 삭제하지 않음
+    Restrict,
+}
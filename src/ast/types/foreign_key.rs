@@ -1,11 +1,36 @@
 use crate::ast::predule::TableName;
+use crate::lexer::predule::NodeSpan;
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct ForeignKey {
-    pub key_name: String,
+    pub key_name: Option<String>,
     pub table: TableName,
     pub columns: Vec<String>,
     pub referenced_table: TableName,
     pub referenced_columns: Vec<String>,
+    pub on_delete: Option<ReferentialAction>,
+    pub on_update: Option<ReferentialAction>,
+    pub span: NodeSpan,
+}
+
+// 컬럼 단위 참조 제약조건
+// column_name data_type REFERENCES table_name [(column_name)] [ON DELETE action] [ON UPDATE action]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Reference {
+    pub key_name: Option<String>,
+    pub referenced_table: TableName,
+    pub referenced_columns: Vec<String>,
+    pub on_delete: Option<ReferentialAction>,
+    pub on_update: Option<ReferentialAction>,
+}
+
+// ON DELETE, ON UPDATE 참조 동작
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub enum ReferentialAction {
+    Cascade,
+    SetNull,
+    SetDefault,
+    Restrict,
+    NoAction,
 }
@@ -3,6 +3,7 @@ use std::convert::TryFrom;
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", content = "value")]
 pub enum Function {
     BuiltIn(BuiltInFunction),         // 내장함수
     UserDefined(UserDefinedFunction), // 사용자 정의 함수
@@ -21,6 +22,7 @@ impl Function {
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", content = "value")]
 pub enum BuiltInFunction {
     Aggregate(AggregateFunction),
     Conditional(ConditionalFunction),
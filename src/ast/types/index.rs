@@ -1,6 +1,34 @@
-#[derive(Clone, Debug, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+// 테이블에 생성된 인덱스 정보입니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct Index {
     pub index_name: String,
-    pub database_name: Option<String>,
-    pub columns: Vec<String>,
+    pub columns: Vec<IndexColumn>,
+    pub is_unique: bool,
+    // USING btree 등 인덱스 방식 (PostgreSQL)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+}
+
+// 인덱스 컬럼 정렬 순서
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+// column_name [ASC | DESC]
+// 인덱스를 구성하는 컬럼입니다. 정렬 순서를 생략하면 None입니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct IndexColumn {
+    pub column_name: String,
+    pub order: Option<SortOrder>,
+}
+
+impl IndexColumn {
+    pub fn new(column_name: String, order: Option<SortOrder>) -> Self {
+        IndexColumn { column_name, order }
+    }
 }
@@ -10,9 +10,21 @@ pub use foreign_key::*;
 pub mod unique_key;
 pub use unique_key::*;
 
+pub mod primary_key;
+pub use primary_key::*;
+
+pub mod check_constraint;
+pub use check_constraint::*;
+
+pub mod table_constraint;
+pub use table_constraint::*;
+
 pub mod table_options;
 pub use table_options::*;
 
+pub mod drop_behavior;
+pub use drop_behavior::*;
+
 pub mod data_types;
 pub use data_types::*;
 
@@ -24,3 +36,6 @@ pub use statement::*;
 
 pub mod index;
 pub use index::*;
+
+pub mod code_comment;
+pub use code_comment::*;
@@ -0,0 +1,9 @@
+use crate::lexer::predule::NodeSpan;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct PrimaryKey {
+    pub key_name: Option<String>,
+    pub columns: Vec<String>,
+    pub span: NodeSpan,
+}
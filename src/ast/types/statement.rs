@@ -1,14 +1,20 @@
 use crate::ast::ddl::{
     AlterDatabaseQuery, AlterTableQuery, CreateDatabaseQuery, CreateIndexQuery, CreateTableQuery,
-    DropDatabaseQuery, DropTableQuery,
+    DropDatabaseQuery, DropIndexQuery, DropTableQuery,
 };
+use crate::ast::predule::CodeComments;
+use crate::lexer::predule::Span;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", content = "value")]
 pub enum SQLStatement {
     DDL(DDLStatement),
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", content = "value")]
+#[allow(clippy::large_enum_variant)]
 pub enum DDLStatement {
     CreateDatabaseQuery(CreateDatabaseQuery),
     AlterDatabase(AlterDatabaseQuery),
@@ -17,4 +23,91 @@ pub enum DDLStatement {
     AlterTableQuery(AlterTableQuery),
     DropTableQuery(DropTableQuery),
     CreateIndexQuery(CreateIndexQuery),
+    DropIndexQuery(DropIndexQuery),
+}
+
+impl SQLStatement {
+    // 원본 SQL 내의 문장 위치
+    pub fn span(&self) -> Span {
+        match self {
+            SQLStatement::DDL(statement) => statement.span(),
+        }
+    }
+
+    pub fn set_span(self, span: Span) -> Self {
+        match self {
+            SQLStatement::DDL(statement) => SQLStatement::DDL(statement.set_span(span)),
+        }
+    }
+
+    // 문장 앞뒤에 붙은 코드 주석
+    pub fn code_comments(&self) -> &CodeComments {
+        match self {
+            SQLStatement::DDL(statement) => statement.code_comments(),
+        }
+    }
+
+    pub fn set_code_comments(self, code_comments: CodeComments) -> Self {
+        match self {
+            SQLStatement::DDL(statement) => {
+                SQLStatement::DDL(statement.set_code_comments(code_comments))
+            }
+        }
+    }
+}
+
+impl DDLStatement {
+    pub fn span(&self) -> Span {
+        match self {
+            DDLStatement::CreateDatabaseQuery(query) => query.span.into(),
+            DDLStatement::AlterDatabase(query) => query.span.into(),
+            DDLStatement::DropDatabaseQuery(query) => query.span.into(),
+            DDLStatement::CreateTableQuery(query) => query.span.into(),
+            DDLStatement::AlterTableQuery(query) => query.span.into(),
+            DDLStatement::DropTableQuery(query) => query.span.into(),
+            DDLStatement::CreateIndexQuery(query) => query.span.into(),
+            DDLStatement::DropIndexQuery(query) => query.span.into(),
+        }
+    }
+
+    pub fn set_span(mut self, span: Span) -> Self {
+        match &mut self {
+            DDLStatement::CreateDatabaseQuery(query) => query.span = span.into(),
+            DDLStatement::AlterDatabase(query) => query.span = span.into(),
+            DDLStatement::DropDatabaseQuery(query) => query.span = span.into(),
+            DDLStatement::CreateTableQuery(query) => query.span = span.into(),
+            DDLStatement::AlterTableQuery(query) => query.span = span.into(),
+            DDLStatement::DropTableQuery(query) => query.span = span.into(),
+            DDLStatement::CreateIndexQuery(query) => query.span = span.into(),
+            DDLStatement::DropIndexQuery(query) => query.span = span.into(),
+        }
+        self
+    }
+
+    pub fn code_comments(&self) -> &CodeComments {
+        match self {
+            DDLStatement::CreateDatabaseQuery(query) => &query.code_comments,
+            DDLStatement::AlterDatabase(query) => &query.code_comments,
+            DDLStatement::DropDatabaseQuery(query) => &query.code_comments,
+            DDLStatement::CreateTableQuery(query) => &query.code_comments,
+            DDLStatement::AlterTableQuery(query) => &query.code_comments,
+            DDLStatement::DropTableQuery(query) => &query.code_comments,
+            DDLStatement::CreateIndexQuery(query) => &query.code_comments,
+            DDLStatement::DropIndexQuery(query) => &query.code_comments,
+        }
+    }
+
+    pub fn set_code_comments(mut self, code_comments: CodeComments) -> Self {
+        match &mut self {
+            DDLStatement::CreateDatabaseQuery(query) => query.code_comments = code_comments,
+            DDLStatement::AlterDatabase(query) => query.code_comments = code_comments,
+            DDLStatement::DropDatabaseQuery(query) => query.code_comments = code_comments,
+            DDLStatement::CreateTableQuery(query) => query.code_comments = code_comments,
+            DDLStatement::AlterTableQuery(query) => query.code_comments = code_comments,
+            DDLStatement::DropTableQuery(query) => query.code_comments = code_comments,
+            DDLStatement::CreateIndexQuery(query) => query.code_comments = code_comments,
+            DDLStatement::DropIndexQuery(query) => query.code_comments = code_comments,
+        }
+        self
+    }
 }
@@ -0,0 +1,57 @@
+use crate::ast::predule::{CheckConstraint, ForeignKey, PrimaryKey, UniqueKey};
+use serde::{Deserialize, Serialize};
+
+// 테이블 단위 제약조건
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", content = "value")]
+pub enum TableConstraint {
+    PrimaryKey(PrimaryKey),
+    UniqueKey(UniqueKey),
+    ForeignKey(ForeignKey),
+    Check(CheckConstraint),
+}
+
+impl From<PrimaryKey> for TableConstraint {
+    fn from(value: PrimaryKey) -> TableConstraint {
+        TableConstraint::PrimaryKey(value)
+    }
+}
+
+impl From<UniqueKey> for TableConstraint {
+    fn from(value: UniqueKey) -> TableConstraint {
+        TableConstraint::UniqueKey(value)
+    }
+}
+
+impl From<ForeignKey> for TableConstraint {
+    fn from(value: ForeignKey) -> TableConstraint {
+        TableConstraint::ForeignKey(value)
+    }
+}
+
+impl From<CheckConstraint> for TableConstraint {
+    fn from(value: CheckConstraint) -> TableConstraint {
+        TableConstraint::Check(value)
+    }
+}
+
+// 테이블 단위 제약조건의 참조
+// CREATE TABLE의 제약조건 목록처럼 TableConstraint로 감싸져 있지 않은 제약조건을 복사하지 않고 함께 다룰 때 사용합니다.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TableConstraintRef<'a> {
+    PrimaryKey(&'a PrimaryKey),
+    UniqueKey(&'a UniqueKey),
+    ForeignKey(&'a ForeignKey),
+    Check(&'a CheckConstraint),
+}
+
+impl<'a> From<&'a TableConstraint> for TableConstraintRef<'a> {
+    fn from(value: &'a TableConstraint) -> TableConstraintRef<'a> {
+        match value {
+            TableConstraint::PrimaryKey(primary_key) => TableConstraintRef::PrimaryKey(primary_key),
+            TableConstraint::UniqueKey(unique_key) => TableConstraintRef::UniqueKey(unique_key),
+            TableConstraint::ForeignKey(foreign_key) => TableConstraintRef::ForeignKey(foreign_key),
+            TableConstraint::Check(check) => TableConstraintRef::Check(check),
+        }
+    }
+}
@@ -1,2 +1,25 @@
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct TableOptions {}
+use serde::{Deserialize, Serialize};
+
+// 테이블 정의 뒤에 오는 옵션 목록
+// ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 (MySQL), WITHOUT ROWID, STRICT (SQLite) 등
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Default)]
+pub struct TableOptions {
+    pub options: Vec<TableOption>,
+}
+
+// option_name [=] value
+// 값이 없는 옵션은 value가 None입니다.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableOption {
+    pub name: String,
+    pub value: Option<String>,
+}
+
+impl TableOption {
+    pub fn new(name: impl Into<String>, value: Option<String>) -> Self {
+        TableOption {
+            name: name.into(),
+            value,
+        }
+    }
+}
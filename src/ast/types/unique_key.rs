@@ -1,8 +1,10 @@
+use crate::lexer::predule::NodeSpan;
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 pub struct UniqueKey {
-    pub key_name: String,
+    pub key_name: Option<String>,
     pub database_name: Option<String>,
     pub columns: Vec<String>,
+    pub span: NodeSpan,
 }
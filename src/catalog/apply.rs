@@ -0,0 +1,815 @@
+use crate::ast::predule::{
+    AlterColumnAction, AlterDatabaseAction, AlterDatabaseQuery, AlterTableAction,
+    AlterTableAlterColumn, AlterTableQuery, Column, ColumnPosition, CreateDatabaseQuery,
+    CreateIndexQuery, CreateTableQuery, DDLStatement, DropBehavior, DropDatabaseQuery,
+    DropIndexQuery, DropTableQuery, Index, SQLStatement, TableConstraint, TableName,
+};
+use crate::catalog::predule::{Catalog, Database, Table};
+use crate::errors::predule::{Error, ErrorKind};
+
+impl Catalog {
+    // 문장 하나를 적용합니다.
+    // 스키마와 맞지 않는 문장이라면 카탈로그를 바꾸지 않고 오류를 반환합니다.
+    pub fn apply(&mut self, statement: &SQLStatement) -> Result<(), Error> {
+        let span = statement.span();
+
+        let result = match statement {
+            SQLStatement::DDL(statement) => match statement {
+                DDLStatement::CreateDatabaseQuery(query) => self.create_database(query),
+                DDLStatement::AlterDatabase(query) => self.alter_database(query),
+                DDLStatement::DropDatabaseQuery(query) => self.drop_database(query),
+                DDLStatement::CreateTableQuery(query) => self.create_table(query),
+                DDLStatement::AlterTableQuery(query) => self.alter_table(query),
+                DDLStatement::DropTableQuery(query) => self.drop_table(query),
+                DDLStatement::CreateIndexQuery(query) => self.create_index(query),
+                DDLStatement::DropIndexQuery(query) => self.drop_index(query),
+            },
+        };
+
+        result.map_err(|error| error.or_span(span))
+    }
+
+    fn create_database(&mut self, query: &CreateDatabaseQuery) -> Result<(), Error> {
+        let name = required(query.database_name.as_ref(), "database name")?;
+
+        if self.database(name).is_some() {
+            if query.if_not_exists {
+                return Ok(());
+            }
+
+            return Err(execute_error(
+                "E2001",
+                format!("database '{}' already exists", name),
+            ));
+        }
+
+        self.databases.push(Database::new(name.to_owned()));
+
+        Ok(())
+    }
+
+    fn alter_database(&mut self, query: &AlterDatabaseQuery) -> Result<(), Error> {
+        let name = required(query.database_name.as_ref(), "database name")?;
+
+        if self.database(name).is_none() {
+            return Err(execute_error(
+                "E2002",
+                format!("database '{}' does not exist", name),
+            ));
+        }
+
+        match &query.action {
+            Some(AlterDatabaseAction::RenameTo(action)) => {
+                if self.database(&action.name).is_some() {
+                    return Err(execute_error(
+                        "E2001",
+                        format!("database '{}' already exists", action.name),
+                    ));
+                }
+
+                for database in &mut self.databases {
+                    if &database.name == name {
+                        database.name = action.name.clone();
+                    }
+                }
+
+                let renamed_tables: Vec<TableName> = self
+                    .tables_in(name)
+                    .into_iter()
+                    .map(|table| table.name.clone())
+                    .collect();
+
+                for from in renamed_tables {
+                    let to = TableName::new(Some(action.name.clone()), from.table_name.clone());
+                    self.rename_table(&from, to);
+                }
+            }
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    // 하나라도 없는 데이터베이스가 있으면(IF EXISTS 제외) 아무것도 삭제하지 않습니다.
+    fn drop_database(&mut self, query: &DropDatabaseQuery) -> Result<(), Error> {
+        required(query.database_names.first(), "database name")?;
+
+        let mut names = vec![];
+
+        for name in &query.database_names {
+            if self.database(name).is_some() {
+                names.push(name);
+            } else if !query.if_exists {
+                return Err(execute_error(
+                    "E2002",
+                    format!("database '{}' does not exist", name),
+                ));
+            }
+        }
+
+        let dropped: Vec<TableName> = self
+            .tables
+            .iter()
+            .filter(|table| {
+                table
+                    .name
+                    .database_name
+                    .as_ref()
+                    .is_some_and(|name| names.contains(&name))
+            })
+            .map(|table| table.name.clone())
+            .collect();
+
+        let cascade = query.drop_behavior == Some(DropBehavior::Cascade);
+
+        if !cascade {
+            self.restrict_references(&dropped)?;
+        }
+
+        self.databases
+            .retain(|database| !names.contains(&&database.name));
+        self.tables.retain(|table| !dropped.contains(&table.name));
+
+        if cascade {
+            self.drop_references(&dropped);
+        }
+
+        Ok(())
+    }
+
+    fn create_table(&mut self, query: &CreateTableQuery) -> Result<(), Error> {
+        let name = required(query.table.as_ref(), "table name")?;
+
+        if self.table(name).is_some() {
+            if query.if_not_exists {
+                return Ok(());
+            }
+
+            return Err(execute_error(
+                "E2003",
+                format!("table '{}' already exists", display_table_name(name)),
+            ));
+        }
+
+        for (i, column) in query.columns.iter().enumerate() {
+            if query.columns[..i]
+                .iter()
+                .any(|previous| previous.name == column.name)
+            {
+                return Err(execute_error(
+                    "E2005",
+                    format!("column '{}' specified more than once", column.name),
+                )
+                .or_span(*column.span));
+            }
+        }
+
+        let mut table = Table::from(query.clone());
+
+        // 인덱스는 테이블을 추가한 뒤 CREATE INDEX와 같은 방법으로 확인합니다.
+        let indexes = std::mem::take(&mut table.indexes);
+
+        let primary_keys = table.primary_key.iter().count()
+            + table
+                .columns
+                .iter()
+                .filter(|column| column.primary_key)
+                .count();
+
+        if primary_keys > 1 {
+            return Err(already_has_primary_key(&table));
+        }
+
+        self.tables.push(table);
+
+        for index in &indexes {
+            if let Err(error) = self.add_index(name, index, false) {
+                self.tables.pop();
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn alter_table(&mut self, query: &AlterTableQuery) -> Result<(), Error> {
+        let mut name = required(query.table.as_ref(), "table name")?.clone();
+
+        // 동작 중 하나라도 실패하면 앞서 적용한 동작도 되돌립니다.
+        // ALTER TABLE은 대상 테이블과 이를 참조하는 테이블만 바꾸므로 해당 테이블만 보관합니다.
+        let snapshot: Vec<(usize, Table)> = self
+            .tables
+            .iter()
+            .enumerate()
+            .filter(|(_, table)| table.name == name || table.references(&name))
+            .map(|(index, table)| (index, table.clone()))
+            .collect();
+
+        for action in &query.actions {
+            if let Err(error) = self.alter_table_action(&name, action) {
+                for (index, table) in snapshot {
+                    self.tables[index] = table;
+                }
+
+                return Err(error);
+            }
+
+            // RENAME TO 뒤의 동작은 바뀐 이름의 테이블에 적용합니다.
+            if let AlterTableAction::AlterTableRenameTo(action) = action {
+                name = TableName::new(name.database_name.clone(), action.name.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn alter_table_action(
+        &mut self,
+        name: &TableName,
+        action: &AlterTableAction,
+    ) -> Result<(), Error> {
+        let table = self.existing_table(name)?;
+
+        match action {
+            AlterTableAction::AlterTableRenameTo(action) => {
+                let to = TableName::new(name.database_name.clone(), action.name.clone());
+
+                if self.table(&to).is_some() {
+                    return Err(execute_error(
+                        "E2003",
+                        format!("table '{}' already exists", display_table_name(&to)),
+                    ));
+                }
+
+                self.rename_table(name, to);
+            }
+            AlterTableAction::AddColumn(action) => {
+                if table.column(&action.column.name).is_some() {
+                    return Err(execute_error(
+                        "E2005",
+                        format!("column '{}' already exists", action.column.name),
+                    ));
+                }
+
+                primary_key_column(table, &action.column, &action.column.name)?;
+
+                existing_position(table, action.position.as_ref(), &action.column.name)?;
+
+                self.table_mut(name).unwrap().set_column(
+                    &action.column.name,
+                    action.column.clone(),
+                    action.position.as_ref(),
+                );
+            }
+            AlterTableAction::ModifyColumn(action) => {
+                existing_column(table, &action.column.name)?;
+                primary_key_column(table, &action.column, &action.column.name)?;
+                existing_position(table, action.position.as_ref(), &action.column.name)?;
+
+                self.table_mut(name).unwrap().set_column(
+                    &action.column.name,
+                    action.column.clone(),
+                    action.position.as_ref(),
+                );
+            }
+            AlterTableAction::ChangeColumn(action) => {
+                existing_column(table, &action.old_name)?;
+                primary_key_column(table, &action.column, &action.old_name)?;
+
+                if action.column.name != action.old_name
+                    && table.column(&action.column.name).is_some()
+                {
+                    return Err(execute_error(
+                        "E2005",
+                        format!("column '{}' already exists", action.column.name),
+                    ));
+                }
+
+                existing_position(table, action.position.as_ref(), &action.old_name)?;
+
+                self.rename_column(name, &action.old_name, &action.column.name);
+
+                self.table_mut(name).unwrap().set_column(
+                    &action.column.name,
+                    action.column.clone(),
+                    action.position.as_ref(),
+                );
+            }
+            AlterTableAction::AlterColumn(action) => {
+                existing_column(table, &action.column_name)?;
+
+                self.alter_column(name, action)?;
+            }
+            AlterTableAction::DropColumn(action) => {
+                existing_column(table, &action.column_name)?;
+
+                // 다른 외래키가 참조하는 컬럼은 삭제할 수 없습니다. (RESTRICT)
+                if let Some(referencing) = self
+                    .tables
+                    .iter()
+                    .find(|other| other.references_column(name, &action.column_name))
+                {
+                    return Err(execute_error(
+                        "E2015",
+                        format!(
+                            "cannot drop column '{}' because table '{}' references it",
+                            action.column_name,
+                            display_table_name(&referencing.name)
+                        ),
+                    ));
+                }
+
+                self.table_mut(name)
+                    .unwrap()
+                    .drop_column(&action.column_name);
+            }
+            AlterTableAction::RenameColumn(action) => {
+                existing_column(table, &action.from_name)?;
+
+                if table.column(&action.to_name).is_some() {
+                    return Err(execute_error(
+                        "E2005",
+                        format!("column '{}' already exists", action.to_name),
+                    ));
+                }
+
+                self.rename_column(name, &action.from_name, &action.to_name);
+            }
+            AlterTableAction::AddConstraint(action) => {
+                add_constraint(table, &action.constraint)?;
+
+                self.table_mut(name)
+                    .unwrap()
+                    .add_constraint(action.constraint.clone());
+            }
+            AlterTableAction::DropConstraint(action) => {
+                // CASCADE로 함께 삭제될 의존 객체(다른 테이블의 외래키 등)는 추적하지 않습니다.
+                if !self
+                    .table_mut(name)
+                    .unwrap()
+                    .drop_constraint(&action.constraint_name)
+                    && !action.if_exists
+                {
+                    return Err(execute_error(
+                        "E2010",
+                        format!("constraint '{}' does not exist", action.constraint_name),
+                    ));
+                }
+            }
+            AlterTableAction::DropPrimaryKey => {
+                if !table.has_primary_key() {
+                    return Err(execute_error(
+                        "E2010",
+                        format!(
+                            "table '{}' has no primary key",
+                            display_table_name(&table.name)
+                        ),
+                    ));
+                }
+
+                self.table_mut(name).unwrap().drop_primary_key();
+            }
+            AlterTableAction::AddIndex(action) => {
+                self.add_index(name, &action.index, false)?;
+            }
+            AlterTableAction::RenameConstraint(action) => {
+                existing_constraint(table, &action.from_name)?;
+
+                if table.has_constraint(&action.to_name) {
+                    return Err(execute_error(
+                        "E2012",
+                        format!("constraint '{}' already exists", action.to_name),
+                    ));
+                }
+
+                self.table_mut(name)
+                    .unwrap()
+                    .rename_constraint(&action.from_name, &action.to_name);
+            }
+            AlterTableAction::ValidateConstraint(action) => {
+                existing_constraint(table, &action.constraint_name)?;
+            }
+            AlterTableAction::DropIndex(action) => {
+                if table.index(&action.index_name).is_none() {
+                    return Err(execute_error(
+                        "E2011",
+                        format!("index '{}' does not exist", action.index_name),
+                    ));
+                }
+
+                self.table_mut(name)
+                    .unwrap()
+                    .indexes
+                    .retain(|index| index.index_name != action.index_name);
+            }
+            // 같은 이름의 옵션은 새 값으로 바꿉니다.
+            AlterTableAction::SetTableOption(action) => {
+                let table_options = self
+                    .table_mut(name)
+                    .unwrap()
+                    .table_options
+                    .get_or_insert_with(Default::default);
+
+                match table_options
+                    .options
+                    .iter_mut()
+                    .find(|option| option.name == action.option.name)
+                {
+                    Some(option) => *option = action.option.clone(),
+                    None => table_options.options.push(action.option.clone()),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // 컬럼명을 바꿉니다. 다른 테이블에서 참조하는 컬럼명도 함께 바꿉니다.
+    fn rename_column(&mut self, table_name: &TableName, from_name: &str, to_name: &str) {
+        self.table_mut(table_name)
+            .unwrap()
+            .rename_column(from_name, to_name);
+
+        for table in &mut self.tables {
+            for foreign_key in &mut table.foreign_keys {
+                if &foreign_key.referenced_table == table_name {
+                    rename_in(&mut foreign_key.referenced_columns, from_name, to_name);
+                }
+            }
+
+            for column in &mut table.columns {
+                if let Some(reference) = &mut column.reference {
+                    if &reference.referenced_table == table_name {
+                        rename_in(&mut reference.referenced_columns, from_name, to_name);
+                    }
+                }
+            }
+        }
+    }
+
+    fn alter_column(
+        &mut self,
+        table_name: &TableName,
+        action: &AlterTableAlterColumn,
+    ) -> Result<(), Error> {
+        let column = self
+            .table_mut(table_name)
+            .and_then(|table| table.column_mut(&action.column_name))
+            .unwrap();
+
+        let no_identity = |column: &Column| {
+            execute_error(
+                "E2014",
+                format!("column '{}' is not an identity column", column.name),
+            )
+        };
+
+        match &action.action {
+            AlterColumnAction::AlterColumnSetType(action) => {
+                column.data_type = action.data_type.clone();
+            }
+            AlterColumnAction::AlterColumnSetNotNull => {
+                column.not_null = true;
+            }
+            AlterColumnAction::AlterColumnDropNotNull => {
+                column.not_null = false;
+            }
+            AlterColumnAction::AlterColumnSetDefault(action) => {
+                column.default = Some(action.expression.clone());
+            }
+            AlterColumnAction::AlterColumnDropDefault(_) => {
+                column.default = None;
+            }
+            AlterColumnAction::AlterColumnSetComment(action) => {
+                column.comment = action.comment.clone();
+            }
+            AlterColumnAction::AlterColumnDropComment => {
+                column.comment = "".into();
+            }
+            AlterColumnAction::AlterColumnAddIdentity(action) => {
+                if column.identity.is_some() {
+                    return Err(execute_error(
+                        "E2014",
+                        format!("column '{}' is already an identity column", column.name),
+                    ));
+                }
+
+                column.identity = Some(action.identity.clone());
+            }
+            AlterColumnAction::AlterColumnSetIdentity(action) => match &mut column.identity {
+                Some(identity) => identity.kind = action.kind.clone(),
+                None => return Err(no_identity(column)),
+            },
+            AlterColumnAction::AlterColumnDropIdentity(action) => {
+                if column.identity.is_none() && !action.if_exists {
+                    return Err(no_identity(column));
+                }
+
+                column.identity = None;
+            }
+            // 통계 설정은 스키마에 영향을 주지 않습니다.
+            AlterColumnAction::AlterColumnSetStatistics(_) => {}
+            AlterColumnAction::AlterColumnSetCollation(action) => {
+                column.collation = Some(action.collation.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    // 하나라도 없는 테이블이 있으면(IF EXISTS 제외) 아무것도 삭제하지 않습니다.
+    // TEMPORARY 테이블은 따로 구분하지 않습니다.
+    fn drop_table(&mut self, query: &DropTableQuery) -> Result<(), Error> {
+        required(query.tables.first(), "table name")?;
+
+        let mut dropped = vec![];
+
+        for name in &query.tables {
+            if self.table(name).is_some() {
+                dropped.push(name.clone());
+            } else if !query.if_exists {
+                return Err(execute_error(
+                    "E2004",
+                    format!("table '{}' does not exist", display_table_name(name)),
+                ));
+            }
+        }
+
+        let cascade = query.drop_behavior == Some(DropBehavior::Cascade);
+
+        if !cascade {
+            self.restrict_references(&dropped)?;
+        }
+
+        self.tables.retain(|table| !dropped.contains(&table.name));
+
+        if cascade {
+            self.drop_references(&dropped);
+        }
+
+        Ok(())
+    }
+
+    // RESTRICT(기본값): 함께 삭제되지 않는 테이블이 외래키로 참조하고 있다면 삭제할 수 없습니다.
+    fn restrict_references(&self, dropped: &[TableName]) -> Result<(), Error> {
+        for table in &self.tables {
+            if dropped.contains(&table.name) {
+                continue;
+            }
+
+            if let Some(name) = dropped.iter().find(|name| table.references(name)) {
+                return Err(execute_error(
+                    "E2015",
+                    format!(
+                        "cannot drop table '{}' because table '{}' references it",
+                        display_table_name(name),
+                        display_table_name(&table.name)
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    // CASCADE: 삭제된 테이블을 참조하는 외래키를 함께 삭제합니다.
+    fn drop_references(&mut self, dropped: &[TableName]) {
+        for table in &mut self.tables {
+            table
+                .foreign_keys
+                .retain(|foreign_key| !dropped.contains(&foreign_key.referenced_table));
+
+            for column in &mut table.columns {
+                if column
+                    .reference
+                    .as_ref()
+                    .is_some_and(|reference| dropped.contains(&reference.referenced_table))
+                {
+                    column.reference = None;
+                }
+            }
+        }
+    }
+
+    fn create_index(&mut self, query: &CreateIndexQuery) -> Result<(), Error> {
+        let index = Index {
+            index_name: query.index_name.clone(),
+            columns: query.columns.clone(),
+            is_unique: query.is_unique,
+            method: query.method.clone(),
+        };
+
+        self.add_index(&query.table, &index, query.if_not_exists)
+    }
+
+    fn add_index(
+        &mut self,
+        table_name: &TableName,
+        index: &Index,
+        if_not_exists: bool,
+    ) -> Result<(), Error> {
+        let table = self.existing_table(table_name)?;
+
+        // 인덱스명은 테이블 단위로 중복될 수 없습니다.
+        // (MySQL은 테이블 단위, PostgreSQL은 스키마 단위이므로 좁은 쪽을 따릅니다.)
+        if table.index(&index.index_name).is_some() {
+            if if_not_exists {
+                return Ok(());
+            }
+
+            return Err(execute_error(
+                "E2007",
+                format!("index '{}' already exists", index.index_name),
+            ));
+        }
+
+        for column in &index.columns {
+            existing_column(table, &column.column_name)?;
+        }
+
+        self.table_mut(table_name)
+            .unwrap()
+            .indexes
+            .push(index.clone());
+
+        Ok(())
+    }
+
+    // ON으로 테이블을 지정하지 않으면 같은 데이터베이스의 모든 테이블에서 인덱스를 찾습니다.
+    fn drop_index(&mut self, query: &DropIndexQuery) -> Result<(), Error> {
+        let table_name = match &query.table {
+            Some(table_name) => {
+                let table = self.existing_table(table_name)?;
+
+                table.index(&query.index_name).map(|_| table_name.clone())
+            }
+            None => self
+                .tables
+                .iter()
+                .find(|table| {
+                    table.name.database_name == query.database_name
+                        && table.index(&query.index_name).is_some()
+                })
+                .map(|table| table.name.clone()),
+        };
+
+        match table_name {
+            Some(table_name) => {
+                self.table_mut(&table_name)
+                    .unwrap()
+                    .indexes
+                    .retain(|index| index.index_name != query.index_name);
+
+                Ok(())
+            }
+            None if query.if_exists => Ok(()),
+            None => Err(execute_error(
+                "E2011",
+                format!("index '{}' does not exist", query.index_name),
+            )),
+        }
+    }
+
+    fn existing_table(&self, name: &TableName) -> Result<&Table, Error> {
+        self.table(name).ok_or_else(|| {
+            execute_error(
+                "E2004",
+                format!("table '{}' does not exist", display_table_name(name)),
+            )
+        })
+    }
+
+    // 테이블명을 바꾸고, 다른 테이블에서 참조하는 테이블명도 함께 바꿉니다.
+    fn rename_table(&mut self, from: &TableName, to: TableName) {
+        for table in &mut self.tables {
+            if &table.name == from {
+                table.rename(to.clone());
+            }
+
+            for foreign_key in &mut table.foreign_keys {
+                if &foreign_key.referenced_table == from {
+                    foreign_key.referenced_table = to.clone();
+                }
+            }
+
+            for column in &mut table.columns {
+                if let Some(reference) = &mut column.reference {
+                    if &reference.referenced_table == from {
+                        reference.referenced_table = to.clone();
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn existing_column(table: &Table, column_name: &str) -> Result<(), Error> {
+    match table.column(column_name) {
+        Some(_) => Ok(()),
+        None => Err(execute_error(
+            "E2006",
+            format!(
+                "column '{}' does not exist in table '{}'",
+                column_name,
+                display_table_name(&table.name)
+            ),
+        )),
+    }
+}
+
+// AFTER로 지정한 컬럼이 존재하는지 확인합니다. 옮길 컬럼 자신은 기준이 될 수 없습니다.
+fn existing_position(
+    table: &Table,
+    position: Option<&ColumnPosition>,
+    column_name: &str,
+) -> Result<(), Error> {
+    match position {
+        Some(ColumnPosition::After(after)) if after == column_name => Err(execute_error(
+            "E2013",
+            format!("column '{}' cannot be placed after itself", column_name),
+        )),
+        Some(ColumnPosition::After(after)) => existing_column(table, after),
+        _ => Ok(()),
+    }
+}
+
+// 이름으로 지정한 제약조건이 존재하는지 확인합니다.
+fn existing_constraint(table: &Table, constraint_name: &str) -> Result<(), Error> {
+    if table.has_constraint(constraint_name) {
+        Ok(())
+    } else {
+        Err(execute_error(
+            "E2010",
+            format!("constraint '{}' does not exist", constraint_name),
+        ))
+    }
+}
+
+// 추가할 제약조건의 컬럼이 모두 존재하는지, 기본키가 중복되지 않는지 확인합니다.
+fn add_constraint(table: &Table, constraint: &TableConstraint) -> Result<(), Error> {
+    let columns = match constraint {
+        TableConstraint::PrimaryKey(primary_key) => {
+            if table.has_primary_key() {
+                return Err(already_has_primary_key(table));
+            }
+
+            &primary_key.columns
+        }
+        TableConstraint::UniqueKey(unique_key) => &unique_key.columns,
+        TableConstraint::ForeignKey(foreign_key) => &foreign_key.columns,
+        TableConstraint::Check(_) => return Ok(()),
+    };
+
+    for column in columns {
+        existing_column(table, column)?;
+    }
+
+    Ok(())
+}
+
+// 컬럼 단위 PRIMARY KEY로 기본키가 두 개가 되지 않는지 확인합니다.
+// 바뀌는 컬럼이 이미 기본키라면 그대로 둘 수 있습니다.
+fn primary_key_column(table: &Table, column: &Column, replaced: &str) -> Result<(), Error> {
+    let replaced_primary_key = table
+        .column(replaced)
+        .is_some_and(|column| column.primary_key);
+
+    if column.primary_key && table.has_primary_key() && !replaced_primary_key {
+        return Err(already_has_primary_key(table));
+    }
+
+    Ok(())
+}
+
+fn already_has_primary_key(table: &Table) -> Error {
+    execute_error(
+        "E2009",
+        format!(
+            "table '{}' already has a primary key",
+            display_table_name(&table.name)
+        ),
+    )
+}
+
+fn required<'a, T>(value: Option<&'a T>, what: &str) -> Result<&'a T, Error> {
+    value.ok_or_else(|| execute_error("E2008", format!("{} is missing", what)))
+}
+
+fn rename_in(columns: &mut [String], from_name: &str, to_name: &str) {
+    for column in columns {
+        if column == from_name {
+            *column = to_name.to_owned();
+        }
+    }
+}
+
+fn display_table_name(table_name: &TableName) -> String {
+    match &table_name.database_name {
+        Some(database_name) => format!("{}.{}", database_name, table_name.table_name),
+        None => table_name.table_name.clone(),
+    }
+}
+
+fn execute_error(code: &'static str, message: String) -> Error {
+    Error::Execute(Box::new(ErrorKind::new(code, message)))
+}
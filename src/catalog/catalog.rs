@@ -0,0 +1,65 @@
+use crate::ast::predule::{SQLStatement, TableName};
+use crate::catalog::predule::Table;
+use crate::errors::predule::Error;
+
+// 데이터베이스 정보
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Database {
+    pub name: String,
+}
+
+impl Database {
+    pub fn new(name: String) -> Self {
+        Database { name }
+    }
+}
+
+// DDL 문장을 순서대로 적용해서 만들어진 스키마 정보입니다.
+// 데이터베이스와 테이블은 생성된 순서를 유지합니다.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Catalog {
+    pub databases: Vec<Database>,
+    pub tables: Vec<Table>,
+}
+
+impl Catalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 문장들을 순서대로 적용한 카탈로그를 생성합니다.
+    pub fn from_statements(statements: &[SQLStatement]) -> Result<Self, Error> {
+        let mut catalog = Self::new();
+        catalog.apply_all(statements)?;
+        Ok(catalog)
+    }
+
+    // 문장들을 순서대로 적용합니다. 첫 오류에서 중단합니다.
+    pub fn apply_all(&mut self, statements: &[SQLStatement]) -> Result<(), Error> {
+        for statement in statements {
+            self.apply(statement)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn database(&self, name: &str) -> Option<&Database> {
+        self.databases.iter().find(|database| database.name == name)
+    }
+
+    pub fn table(&self, name: &TableName) -> Option<&Table> {
+        self.tables.iter().find(|table| &table.name == name)
+    }
+
+    pub fn table_mut(&mut self, name: &TableName) -> Option<&mut Table> {
+        self.tables.iter_mut().find(|table| &table.name == name)
+    }
+
+    // 해당 데이터베이스에 속한 테이블 목록
+    pub fn tables_in(&self, database_name: &str) -> Vec<&Table> {
+        self.tables
+            .iter()
+            .filter(|table| table.name.database_name.as_deref() == Some(database_name))
+            .collect()
+    }
+}
@@ -0,0 +1,749 @@
+use std::sync::Arc;
+
+use crate::ast::predule::{
+    AlterColumnAction, AlterColumnAddIdentity, AlterColumnDropDefault, AlterColumnDropIdentity,
+    AlterColumnSetCollation, AlterColumnSetComment, AlterColumnSetDefault, AlterColumnSetIdentity,
+    AlterColumnSetType, AlterTableAction, AlterTableAddColumn, AlterTableAddConstraint,
+    AlterTableAlterColumn, AlterTableDropColumn, AlterTableDropConstraint, AlterTableDropIndex,
+    AlterTableModifyColumn, AlterTableQuery, AlterTableRenameColumn, AlterTableSetTableOption,
+    Column, CreateDatabaseQuery, CreateIndexQuery, CreateTableQuery, DropDatabaseQuery,
+    DropIndexQuery, DropTableQuery, ForeignKey, Index, SQLStatement, TableConstraint, TableName,
+};
+use crate::catalog::predule::{Catalog, Table};
+use crate::dialect::predule::{Dialect, GenericDialect};
+use crate::errors::predule::{Error, ErrorKind};
+use crate::renderer::predule::ToSql;
+
+// 스키마 비교 옵션
+#[derive(Debug, Clone)]
+pub struct DiffOptions {
+    // 생성할 문장의 방언
+    // 방언에 없는 구문으로만 표현할 수 있는 변경은 오류로 반환합니다.
+    pub dialect: Arc<dyn Dialect>,
+    // 삭제된 컬럼과 추가된 컬럼의 정의가 이름을 제외하고 1:1로 같다면 컬럼명 변경으로 판단할지 여부
+    // 정의가 같은 다른 컬럼으로 바꾼 경우에도 이름 변경으로 판단하므로 기본값은 false입니다.
+    pub detect_renames: bool,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        DiffOptions {
+            dialect: Arc::new(GenericDialect {}),
+            detect_renames: false,
+        }
+    }
+}
+
+impl DiffOptions {
+    pub fn set_dialect(mut self, dialect: Arc<dyn Dialect>) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    pub fn set_detect_renames(mut self, detect_renames: bool) -> Self {
+        self.detect_renames = detect_renames;
+        self
+    }
+}
+
+impl Catalog {
+    // 현재 스키마(self)를 target 스키마로 바꾸기 위한 DDL 문장들을 생성합니다.
+    // 생성된 문장들을 순서대로 적용하면 target과 같은 스키마가 됩니다. (테이블 순서는 다를 수 있습니다.)
+    //
+    // 참조 관계가 깨지지 않도록 다음 순서로 생성합니다.
+    // 데이터베이스 생성 -> 제약조건과 인덱스 삭제 -> 테이블 삭제 -> 테이블 생성
+    // -> 테이블 변경 -> 외래키 추가 -> 데이터베이스 삭제
+    //
+    // 이름이 없는 제약조건의 삭제처럼 방언의 문장으로 표현할 수 없는 변경이 있다면 오류를 반환합니다.
+    pub fn diff(&self, target: &Catalog, options: DiffOptions) -> Result<Vec<SQLStatement>, Error> {
+        let dialect = options.dialect.as_ref();
+
+        let mut statements = vec![];
+
+        for database in &target.databases {
+            if self.database(&database.name).is_none() {
+                statements.push(
+                    CreateDatabaseQuery::builder()
+                        .set_name(database.name.clone())
+                        .build(),
+                );
+            }
+        }
+
+        let altered: Vec<(&Table, &Table)> = target
+            .tables
+            .iter()
+            .filter_map(|table| self.table(&table.name).map(|source| (source, table)))
+            .collect();
+        let created: Vec<&Table> = target
+            .tables
+            .iter()
+            .filter(|table| self.table(&table.name).is_none())
+            .collect();
+        let dropped: Vec<&Table> = self
+            .tables
+            .iter()
+            .filter(|table| {
+                let dropped_with_database = table.name.database_name.as_ref().is_some_and(|name| {
+                    self.database(name).is_some() && target.database(name).is_none()
+                });
+
+                target.table(&table.name).is_none() && !dropped_with_database
+            })
+            .collect();
+
+        for (source, target) in &altered {
+            statements.extend(drop_constraints(source, target, dialect)?);
+        }
+
+        statements.extend(drop_tables(dropped));
+
+        let mut foreign_keys = vec![];
+
+        for (table, deferred) in creation_order(created) {
+            statements.extend(create_table(table, &deferred));
+
+            foreign_keys.extend(
+                deferred
+                    .into_iter()
+                    .map(|foreign_key| add_constraint(&table.name, foreign_key.into())),
+            );
+        }
+
+        for (source, target) in &altered {
+            statements.extend(alter_table(source, target, &options)?);
+
+            foreign_keys.extend(
+                added_constraints(source, target)
+                    .into_iter()
+                    .filter(|constraint| matches!(constraint, TableConstraint::ForeignKey(_)))
+                    .map(|constraint| add_constraint(&target.name, constraint)),
+            );
+        }
+
+        statements.extend(foreign_keys);
+
+        for database in &self.databases {
+            if target.database(&database.name).is_none() {
+                statements.push(
+                    DropDatabaseQuery::builder()
+                        .add_name(database.name.clone())
+                        .build(),
+                );
+            }
+        }
+
+        Ok(statements)
+    }
+}
+
+// 참조되는 테이블을 먼저 생성합니다. 그 외에는 target의 순서를 유지합니다.
+// 순환 참조가 있다면 아직 생성되지 않은 테이블을 가리키는 테이블 단위 외래키를
+// 모든 테이블을 생성한 뒤 ADD CONSTRAINT로 추가하도록 분리합니다.
+// 컬럼 단위 REFERENCES는 분리할 수 없으므로 그대로 둡니다.
+fn creation_order(tables: Vec<&Table>) -> Vec<(&Table, Vec<ForeignKey>)> {
+    let mut remaining = tables;
+    let mut ordered: Vec<(&Table, Vec<ForeignKey>)> = vec![];
+
+    while !remaining.is_empty() {
+        let index = remaining
+            .iter()
+            .position(|table| {
+                !remaining
+                    .iter()
+                    .any(|other| other.name != table.name && table.references(&other.name))
+            })
+            .unwrap_or(0);
+
+        let table = remaining.remove(index);
+
+        let deferred = table
+            .foreign_keys
+            .iter()
+            .filter(|foreign_key| {
+                foreign_key.referenced_table != table.name
+                    && remaining
+                        .iter()
+                        .any(|other| other.name == foreign_key.referenced_table)
+            })
+            .cloned()
+            .collect();
+
+        ordered.push((table, deferred));
+    }
+
+    ordered
+}
+
+// 다른 테이블이 참조하는 테이블은 나중에 삭제합니다.
+// 서로 참조하는 테이블들은 한 문장에서 함께 삭제합니다.
+fn drop_tables(tables: Vec<&Table>) -> Vec<SQLStatement> {
+    let mut remaining = tables;
+    let mut statements = vec![];
+
+    while !remaining.is_empty() {
+        let index = remaining.iter().position(|table| {
+            !remaining
+                .iter()
+                .any(|other| other.name != table.name && other.references(&table.name))
+        });
+
+        let dropped = match index {
+            Some(index) => vec![remaining.remove(index)],
+            None => std::mem::take(&mut remaining),
+        };
+
+        let mut query = DropTableQuery::builder();
+
+        for table in dropped {
+            query = query.add_table(table.name.clone());
+        }
+
+        statements.push(query.build());
+    }
+
+    statements
+}
+
+// 새 테이블은 CREATE TABLE 뒤에 인덱스를 생성합니다.
+// deferred의 외래키는 나중에 따로 추가하므로 제외합니다.
+fn create_table(table: &Table, deferred: &[ForeignKey]) -> Vec<SQLStatement> {
+    let mut query = CreateTableQuery::builder().set_table(table.name.clone());
+
+    query.columns = table.columns.clone();
+    query.primary_key = table.primary_key.clone();
+    query.foreign_keys = table
+        .foreign_keys
+        .iter()
+        .filter(|foreign_key| !deferred.contains(foreign_key))
+        .cloned()
+        .collect();
+    query.unique_keys = table.unique_keys.clone();
+    query.check_constraints = table.check_constraints.clone();
+    query.table_options = table.table_options.clone();
+
+    let mut statements = vec![query.build()];
+
+    statements.extend(
+        table
+            .indexes
+            .iter()
+            .map(|index| create_index(&table.name, index)),
+    );
+
+    statements
+}
+
+fn create_index(table_name: &TableName, index: &Index) -> SQLStatement {
+    let mut query = CreateIndexQuery::builder()
+        .set_table(table_name.clone())
+        .set_index_name(index.index_name.clone())
+        .set_unique(index.is_unique);
+
+    query.columns = index.columns.clone();
+    query.method = index.method.clone();
+
+    query.build()
+}
+
+fn add_constraint(table_name: &TableName, constraint: TableConstraint) -> SQLStatement {
+    AlterTableQuery::builder()
+        .set_table(table_name.clone())
+        .add_action(
+            AlterTableAddConstraint {
+                constraint,
+                not_valid: false,
+            }
+            .into(),
+        )
+        .build()
+}
+
+// 같은 테이블에서 삭제되거나 바뀐 제약조건과 인덱스를 삭제합니다.
+// 다른 테이블의 컬럼이나 테이블을 삭제하기 전에 외래키가 먼저 삭제되어야 하므로 따로 생성합니다.
+fn drop_constraints(
+    source: &Table,
+    target: &Table,
+    dialect: &dyn Dialect,
+) -> Result<Vec<SQLStatement>, Error> {
+    let mut statements: Vec<SQLStatement> = vec![];
+    let mut actions: Vec<AlterTableAction> = vec![];
+
+    let target_constraints = constraints(target);
+
+    for constraint in &constraints(source) {
+        if !target_constraints.contains(constraint) {
+            actions.push(drop_constraint(source, constraint, dialect)?);
+        }
+    }
+
+    for index in &source.indexes {
+        if target.index(&index.index_name) != Some(index) {
+            if dialect.supports_alter_table_drop_index() {
+                actions.push(
+                    AlterTableDropIndex {
+                        index_name: index.index_name.clone(),
+                    }
+                    .into(),
+                );
+            } else {
+                statements.push(
+                    DropIndexQuery::builder()
+                        .set_database_name(source.name.database_name.clone())
+                        .set_index_name(index.index_name.clone())
+                        .build(),
+                );
+            }
+        }
+    }
+
+    statements.extend(alter_table_statements(&source.name, actions));
+
+    Ok(statements)
+}
+
+// 같은 테이블의 변경 사항
+// 컬럼 변경 -> 외래키를 제외한 제약조건 추가 -> 테이블 옵션 변경 -> 인덱스 추가 순서로 생성합니다.
+fn alter_table(
+    source: &Table,
+    target: &Table,
+    options: &DiffOptions,
+) -> Result<Vec<SQLStatement>, Error> {
+    let mut actions = diff_columns(source, target, options)?;
+
+    actions.extend(
+        added_constraints(source, target)
+            .into_iter()
+            .filter(|constraint| !matches!(constraint, TableConstraint::ForeignKey(_)))
+            .map(|constraint| {
+                AlterTableAddConstraint {
+                    constraint,
+                    not_valid: false,
+                }
+                .into()
+            }),
+    );
+
+    actions.extend(diff_table_options(
+        source,
+        target,
+        options.dialect.as_ref(),
+    )?);
+
+    let mut statements = alter_table_statements(&target.name, actions);
+
+    for index in &target.indexes {
+        if source.index(&index.index_name) != Some(index) {
+            statements.push(create_index(&target.name, index));
+        }
+    }
+
+    Ok(statements)
+}
+
+fn alter_table_statements(
+    table_name: &TableName,
+    actions: Vec<AlterTableAction>,
+) -> Vec<SQLStatement> {
+    actions
+        .into_iter()
+        .map(|action| {
+            AlterTableQuery::builder()
+                .set_table(table_name.clone())
+                .add_action(action)
+                .build()
+        })
+        .collect()
+}
+
+fn added_constraints(source: &Table, target: &Table) -> Vec<TableConstraint> {
+    let source_constraints = constraints(source);
+
+    constraints(target)
+        .into_iter()
+        .filter(|constraint| !source_constraints.contains(constraint))
+        .collect()
+}
+
+fn diff_columns(
+    source: &Table,
+    target: &Table,
+    options: &DiffOptions,
+) -> Result<Vec<AlterTableAction>, Error> {
+    let mut actions: Vec<AlterTableAction> = vec![];
+
+    let mut dropped: Vec<&Column> = source
+        .columns
+        .iter()
+        .filter(|column| target.column(&column.name).is_none())
+        .collect();
+    let mut added: Vec<&Column> = target
+        .columns
+        .iter()
+        .filter(|column| source.column(&column.name).is_none())
+        .collect();
+
+    if options.detect_renames {
+        for (from, to) in renamed_columns(&dropped, &added) {
+            dropped.retain(|column| column.name != from.name);
+            added.retain(|column| column.name != to.name);
+
+            actions.push(
+                AlterTableRenameColumn {
+                    from_name: from.name.clone(),
+                    to_name: to.name.clone(),
+                }
+                .into(),
+            );
+        }
+    }
+
+    for column in dropped {
+        actions.push(
+            AlterTableDropColumn {
+                column_name: column.name.clone(),
+            }
+            .into(),
+        );
+    }
+
+    for column in added {
+        actions.push(
+            AlterTableAddColumn {
+                column: column.clone(),
+                position: None,
+            }
+            .into(),
+        );
+    }
+
+    for to in &target.columns {
+        if let Some(from) = source.column(&to.name) {
+            actions.extend(diff_column(
+                &target.name,
+                from,
+                to,
+                options.dialect.as_ref(),
+            )?);
+        }
+    }
+
+    Ok(actions)
+}
+
+// 정의가 같은 컬럼이 양쪽에 하나씩만 있는 경우에만 이름 변경으로 판단합니다.
+fn renamed_columns<'a>(
+    dropped: &[&'a Column],
+    added: &[&'a Column],
+) -> Vec<(&'a Column, &'a Column)> {
+    dropped
+        .iter()
+        .filter_map(|from| {
+            let candidates: Vec<&&Column> = added
+                .iter()
+                .filter(|to| same_definition(from, to))
+                .collect();
+            let sources = dropped
+                .iter()
+                .filter(|other| same_definition(other, from))
+                .count();
+
+            match candidates.as_slice() {
+                [to] if sources == 1 => Some((*from, **to)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+// 컬럼 단위 제약조건(PRIMARY KEY, UNIQUE, REFERENCES, CHECK)과 AUTO_INCREMENT, GENERATED, ON UPDATE, CHARACTER SET은
+// 바꾸는 ALTER COLUMN 문이 없으므로 컬럼 정의 전체를 MODIFY COLUMN으로 바꿉니다.
+// MODIFY COLUMN을 지원하는 방언은 타입, NOT NULL, 코멘트 변경과 ALTER COLUMN으로 표현할 수 없는 변경도
+// MODIFY COLUMN으로 바꿉니다.
+fn diff_column(
+    table_name: &TableName,
+    from: &Column,
+    to: &Column,
+    dialect: &dyn Dialect,
+) -> Result<Vec<AlterTableAction>, Error> {
+    let redefined = from.primary_key != to.primary_key
+        || from.primary_key_name != to.primary_key_name
+        || from.unique != to.unique
+        || from.unique_name != to.unique_name
+        || from.reference != to.reference
+        || from.checks != to.checks
+        || from.auto_increment != to.auto_increment
+        || from.generated != to.generated
+        || from.on_update != to.on_update
+        || from.charset != to.charset;
+
+    let actions = alter_column_actions(from, to);
+
+    let unsupported = actions
+        .iter()
+        .find(|action| !dialect.supports_alter_column_action(action));
+
+    let modify = |actions: &[AlterColumnAction]| {
+        actions.iter().any(|action| {
+            matches!(
+                action,
+                AlterColumnAction::AlterColumnSetType(_)
+                    | AlterColumnAction::AlterColumnSetNotNull
+                    | AlterColumnAction::AlterColumnDropNotNull
+                    | AlterColumnAction::AlterColumnSetComment(_)
+                    | AlterColumnAction::AlterColumnDropComment
+            )
+        })
+    };
+
+    if dialect.supports_modify_column() {
+        if redefined || unsupported.is_some() || modify(&actions) {
+            return Ok(vec![AlterTableModifyColumn {
+                column: to.clone(),
+                position: None,
+            }
+            .into()]);
+        }
+    } else if redefined {
+        return Err(diff_error(
+            "E2017",
+            format!(
+                "column '{}' of table '{}' changes a column-level constraint or attribute, which cannot be expressed without MODIFY COLUMN in the {} dialect",
+                to.name,
+                table_name.to_sql(dialect),
+                dialect.name()
+            ),
+        ));
+    } else if let Some(action) = unsupported {
+        return Err(diff_error(
+            "E2017",
+            format!(
+                "column '{}' of table '{}' cannot be changed with ALTER COLUMN {} in the {} dialect",
+                to.name,
+                table_name.to_sql(dialect),
+                action.to_sql(dialect),
+                dialect.name()
+            ),
+        ));
+    }
+
+    Ok(actions
+        .into_iter()
+        .map(|action| {
+            AlterTableAlterColumn {
+                column_name: to.name.clone(),
+                action,
+            }
+            .into()
+        })
+        .collect())
+}
+
+// 컬럼 타입, NOT NULL, 기본값, 코멘트, IDENTITY, 콜레이션 변경
+// 콜레이션을 기본값으로 되돌리는 문장은 없으므로 삭제된 콜레이션은 무시합니다.
+fn alter_column_actions(from: &Column, to: &Column) -> Vec<AlterColumnAction> {
+    let mut actions = vec![];
+
+    if from.data_type != to.data_type {
+        actions.push(
+            AlterColumnSetType {
+                data_type: to.data_type.clone(),
+                using: None,
+            }
+            .into(),
+        );
+    }
+
+    if from.not_null != to.not_null {
+        actions.push(if to.not_null {
+            AlterColumnAction::AlterColumnSetNotNull
+        } else {
+            AlterColumnAction::AlterColumnDropNotNull
+        });
+    }
+
+    if from.default != to.default {
+        actions.push(match &to.default {
+            Some(expression) => AlterColumnSetDefault {
+                expression: expression.clone(),
+            }
+            .into(),
+            None => AlterColumnDropDefault {}.into(),
+        });
+    }
+
+    if from.comment != to.comment {
+        actions.push(if to.comment.is_empty() {
+            AlterColumnAction::AlterColumnDropComment
+        } else {
+            AlterColumnSetComment {
+                comment: to.comment.clone(),
+            }
+            .into()
+        });
+    }
+
+    match (&from.identity, &to.identity) {
+        (None, Some(identity)) => actions.push(
+            AlterColumnAddIdentity {
+                identity: identity.clone(),
+            }
+            .into(),
+        ),
+        (Some(_), None) => actions.push(AlterColumnDropIdentity { if_exists: false }.into()),
+        (Some(before), Some(after)) if before != after => {
+            if before.start == after.start && before.increment == after.increment {
+                actions.push(
+                    AlterColumnSetIdentity {
+                        kind: after.kind.clone(),
+                    }
+                    .into(),
+                );
+            } else {
+                actions.push(AlterColumnDropIdentity { if_exists: false }.into());
+                actions.push(
+                    AlterColumnAddIdentity {
+                        identity: after.clone(),
+                    }
+                    .into(),
+                );
+            }
+        }
+        _ => {}
+    }
+
+    if from.collation != to.collation {
+        if let Some(collation) = &to.collation {
+            actions.push(
+                AlterColumnSetCollation {
+                    collation: collation.clone(),
+                }
+                .into(),
+            );
+        }
+    }
+
+    actions
+}
+
+// 추가되거나 값이 바뀐 옵션만 설정합니다.
+// 옵션을 기본값으로 되돌리는 문장은 없으므로 삭제된 옵션은 표현할 수 없습니다.
+fn diff_table_options(
+    source: &Table,
+    target: &Table,
+    dialect: &dyn Dialect,
+) -> Result<Vec<AlterTableAction>, Error> {
+    let source_options = source
+        .table_options
+        .as_ref()
+        .map(|table_options| table_options.options.as_slice())
+        .unwrap_or_default();
+    let target_options = target
+        .table_options
+        .as_ref()
+        .map(|table_options| table_options.options.as_slice())
+        .unwrap_or_default();
+
+    let removed = source_options
+        .iter()
+        .find(|from| !target_options.iter().any(|to| to.name == from.name));
+
+    if let Some(option) = removed {
+        return Err(diff_error(
+            "E2018",
+            format!(
+                "table option '{}' of table '{}' cannot be removed",
+                option.name,
+                target.name.to_sql(dialect)
+            ),
+        ));
+    }
+
+    let changed: Vec<AlterTableAction> = target_options
+        .iter()
+        .filter(|to| !source_options.contains(to))
+        .map(|option| {
+            AlterTableSetTableOption {
+                option: option.clone(),
+            }
+            .into()
+        })
+        .collect();
+
+    if !changed.is_empty() && !dialect.supports_alter_table_options() {
+        return Err(diff_error(
+            "E2018",
+            format!(
+                "table options of table '{}' cannot be changed in the {} dialect",
+                target.name.to_sql(dialect),
+                dialect.name()
+            ),
+        ));
+    }
+
+    Ok(changed)
+}
+
+fn same_definition(from: &Column, to: &Column) -> bool {
+    let mut renamed = from.clone();
+    renamed.name = to.name.clone();
+
+    &renamed == to
+}
+
+fn constraints(table: &Table) -> Vec<TableConstraint> {
+    let mut constraints: Vec<TableConstraint> = vec![];
+
+    if let Some(primary_key) = &table.primary_key {
+        constraints.push(primary_key.clone().into());
+    }
+
+    constraints.extend(table.unique_keys.iter().cloned().map(Into::into));
+    constraints.extend(table.foreign_keys.iter().cloned().map(Into::into));
+    constraints.extend(table.check_constraints.iter().cloned().map(Into::into));
+
+    constraints
+}
+
+// 제약조건은 이름으로만 삭제할 수 있습니다.
+// 기본키는 DROP PRIMARY KEY를 지원하는 방언이라면 이름이 없어도 삭제할 수 있습니다.
+fn drop_constraint(
+    table: &Table,
+    constraint: &TableConstraint,
+    dialect: &dyn Dialect,
+) -> Result<AlterTableAction, Error> {
+    if let TableConstraint::PrimaryKey(_) = constraint {
+        if dialect.supports_alter_table_drop_primary_key() {
+            return Ok(AlterTableAction::DropPrimaryKey);
+        }
+    }
+
+    let name = match constraint {
+        TableConstraint::PrimaryKey(primary_key) => &primary_key.key_name,
+        TableConstraint::UniqueKey(unique_key) => &unique_key.key_name,
+        TableConstraint::ForeignKey(foreign_key) => &foreign_key.key_name,
+        TableConstraint::Check(check) => &check.constraint_name,
+    };
+
+    match name {
+        Some(name) => Ok(AlterTableDropConstraint {
+            constraint_name: name.clone(),
+            if_exists: false,
+            drop_behavior: None,
+        }
+        .into()),
+        None => Err(diff_error(
+            "E2016",
+            format!(
+                "unnamed constraint '{}' of table '{}' cannot be dropped in the {} dialect",
+                constraint.to_sql(dialect),
+                table.name.to_sql(dialect),
+                dialect.name()
+            ),
+        )),
+    }
+}
+
+fn diff_error(code: &'static str, message: String) -> Error {
+    Error::Execute(Box::new(ErrorKind::new(code, message)))
+}
@@ -0,0 +1,8 @@
+pub mod apply;
+#[allow(clippy::module_inception)]
+pub mod catalog;
+pub mod diff;
+pub mod predule;
+pub mod table;
+
+pub(crate) mod test;
@@ -0,0 +1,3 @@
+pub use super::catalog::*;
+pub use super::diff::*;
+pub use super::table::*;
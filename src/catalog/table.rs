@@ -0,0 +1,360 @@
+use crate::ast::predule::{
+    CheckConstraint, Column, ColumnName, ColumnPosition, CreateTableQuery, Expression, ForeignKey,
+    Index, PrimaryKey, TableConstraint, TableName, TableOptions, UniqueKey,
+};
+use crate::visitor::predule::{walk_expression, Visitor};
+
+// 테이블 정보
+// 컬럼과 제약조건은 정의된 순서를 유지합니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Table {
+    pub name: TableName,
+    pub columns: Vec<Column>,
+    pub primary_key: Option<PrimaryKey>,
+    pub foreign_keys: Vec<ForeignKey>,
+    pub unique_keys: Vec<UniqueKey>,
+    pub check_constraints: Vec<CheckConstraint>,
+    pub indexes: Vec<Index>,
+    pub table_options: Option<TableOptions>,
+}
+
+impl Table {
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+
+    pub fn column_mut(&mut self, name: &str) -> Option<&mut Column> {
+        self.columns.iter_mut().find(|column| column.name == name)
+    }
+
+    pub fn index(&self, name: &str) -> Option<&Index> {
+        self.indexes.iter().find(|index| index.index_name == name)
+    }
+
+    // 테이블 단위 기본키나 컬럼 단위 PRIMARY KEY가 있는지
+    pub fn has_primary_key(&self) -> bool {
+        self.primary_key.is_some() || self.columns.iter().any(|column| column.primary_key)
+    }
+
+    // 테이블 단위 기본키와 컬럼 단위 PRIMARY KEY를 모두 삭제합니다.
+    pub(crate) fn drop_primary_key(&mut self) {
+        self.primary_key = None;
+
+        for column in &mut self.columns {
+            column.primary_key = false;
+            column.primary_key_name = None;
+        }
+    }
+
+    // 테이블 단위 외래키나 컬럼 단위 REFERENCES로 해당 테이블을 참조하는지
+    pub fn references(&self, table_name: &TableName) -> bool {
+        self.foreign_keys
+            .iter()
+            .any(|foreign_key| &foreign_key.referenced_table == table_name)
+            || self.columns.iter().any(|column| {
+                column
+                    .reference
+                    .as_ref()
+                    .is_some_and(|reference| &reference.referenced_table == table_name)
+            })
+    }
+
+    // 외래키나 REFERENCES로 해당 테이블의 컬럼을 참조하는지
+    // 삭제될 컬럼 자신의 REFERENCES와, 그 컬럼을 포함하는 외래키는 제외합니다.
+    pub fn references_column(&self, table_name: &TableName, column_name: &str) -> bool {
+        let is_self = &self.name == table_name;
+        let contains = |columns: &Vec<String>| columns.iter().any(|column| column == column_name);
+
+        self.foreign_keys.iter().any(|foreign_key| {
+            &foreign_key.referenced_table == table_name
+                && contains(&foreign_key.referenced_columns)
+                && !(is_self && contains(&foreign_key.columns))
+        }) || self.columns.iter().any(|column| {
+            column.reference.as_ref().is_some_and(|reference| {
+                &reference.referenced_table == table_name
+                    && contains(&reference.referenced_columns)
+                    && !(is_self && column.name == column_name)
+            })
+        })
+    }
+
+    // 테이블명이 바뀐 경우, 자기 자신을 가리키는 외래키 정보도 함께 바꿉니다.
+    pub(crate) fn rename(&mut self, name: TableName) {
+        for foreign_key in &mut self.foreign_keys {
+            foreign_key.table = name.clone();
+        }
+
+        self.name = name;
+    }
+
+    // 컬럼명이 바뀐 경우, 제약조건과 인덱스 내의 컬럼명도 함께 바꿉니다.
+    pub(crate) fn rename_column(&mut self, from_name: &str, to_name: &str) {
+        let rename = |column_name: &mut String| {
+            if column_name == from_name {
+                *column_name = to_name.to_owned();
+            }
+        };
+
+        if let Some(column) = self.column_mut(from_name) {
+            column.name = to_name.to_owned();
+        }
+
+        if let Some(primary_key) = &mut self.primary_key {
+            primary_key.columns.iter_mut().for_each(rename);
+        }
+
+        for foreign_key in &mut self.foreign_keys {
+            foreign_key.columns.iter_mut().for_each(rename);
+        }
+
+        for unique_key in &mut self.unique_keys {
+            unique_key.columns.iter_mut().for_each(rename);
+        }
+
+        for index in &mut self.indexes {
+            index
+                .columns
+                .iter_mut()
+                .for_each(|column| rename(&mut column.column_name));
+        }
+    }
+
+    // 제약조건 종류에 맞는 목록에 추가합니다.
+    pub(crate) fn add_constraint(&mut self, constraint: TableConstraint) {
+        match constraint {
+            TableConstraint::PrimaryKey(primary_key) => self.primary_key = Some(primary_key),
+            TableConstraint::UniqueKey(unique_key) => self.unique_keys.push(unique_key),
+            TableConstraint::ForeignKey(foreign_key) => self.foreign_keys.push(foreign_key),
+            TableConstraint::Check(check) => self.check_constraints.push(check),
+        }
+    }
+
+    // 이름이 붙은 제약조건이 있는지
+    // 컬럼 단위로 이름을 붙인 CHECK, REFERENCES 제약조건도 포함합니다.
+    pub fn has_constraint(&self, name: &str) -> bool {
+        let name = Some(name);
+
+        let in_column = |column: &Column| {
+            column.primary_key_name.as_deref() == name
+                || column.unique_name.as_deref() == name
+                || column
+                    .checks
+                    .iter()
+                    .any(|check| check.constraint_name.as_deref() == name)
+                || column
+                    .reference
+                    .as_ref()
+                    .is_some_and(|reference| reference.key_name.as_deref() == name)
+        };
+
+        self.columns.iter().any(in_column)
+            || self
+                .primary_key
+                .iter()
+                .any(|primary_key| primary_key.key_name.as_deref() == name)
+            || self
+                .foreign_keys
+                .iter()
+                .any(|key| key.key_name.as_deref() == name)
+            || self
+                .unique_keys
+                .iter()
+                .any(|key| key.key_name.as_deref() == name)
+            || self
+                .check_constraints
+                .iter()
+                .any(|check| check.constraint_name.as_deref() == name)
+    }
+
+    // 제약조건 이름을 바꿉니다. 바뀐 제약조건이 없다면 false를 반환합니다.
+    pub(crate) fn rename_constraint(&mut self, from_name: &str, to_name: &str) -> bool {
+        if !self.has_constraint(from_name) {
+            return false;
+        }
+
+        let names = self
+            .primary_key
+            .iter_mut()
+            .map(|primary_key| &mut primary_key.key_name)
+            .chain(self.foreign_keys.iter_mut().map(|key| &mut key.key_name))
+            .chain(self.unique_keys.iter_mut().map(|key| &mut key.key_name))
+            .chain(
+                self.check_constraints
+                    .iter_mut()
+                    .map(|check| &mut check.constraint_name),
+            )
+            .chain(self.columns.iter_mut().flat_map(|column| {
+                column
+                    .checks
+                    .iter_mut()
+                    .map(|check| &mut check.constraint_name)
+                    .chain(
+                        column
+                            .reference
+                            .iter_mut()
+                            .map(|reference| &mut reference.key_name),
+                    )
+                    .chain([&mut column.primary_key_name, &mut column.unique_name])
+            }));
+
+        for name in names {
+            if name.as_deref() == Some(from_name) {
+                *name = Some(to_name.to_owned());
+            }
+        }
+
+        true
+    }
+
+    // 이름으로 제약조건을 삭제합니다. 삭제된 제약조건이 없다면 false를 반환합니다.
+    pub(crate) fn drop_constraint(&mut self, name: &str) -> bool {
+        if !self.has_constraint(name) {
+            return false;
+        }
+
+        let name = Some(name);
+
+        self.foreign_keys
+            .retain(|foreign_key| foreign_key.key_name.as_deref() != name);
+        self.unique_keys
+            .retain(|unique_key| unique_key.key_name.as_deref() != name);
+        self.check_constraints
+            .retain(|check| check.constraint_name.as_deref() != name);
+
+        for column in &mut self.columns {
+            column
+                .checks
+                .retain(|check| check.constraint_name.as_deref() != name);
+
+            if column
+                .reference
+                .as_ref()
+                .is_some_and(|reference| reference.key_name.as_deref() == name)
+            {
+                column.reference = None;
+            }
+
+            if column.primary_key_name.as_deref() == name {
+                column.primary_key = false;
+                column.primary_key_name = None;
+            }
+
+            if column.unique_name.as_deref() == name {
+                column.unique = false;
+                column.unique_name = None;
+            }
+        }
+
+        if self
+            .primary_key
+            .as_ref()
+            .is_some_and(|primary_key| primary_key.key_name.as_deref() == name)
+        {
+            self.primary_key = None;
+        }
+
+        true
+    }
+
+    // 컬럼 정의를 바꾸거나 새로 추가합니다.
+    // 위치가 없으면 기존 컬럼은 제자리에, 새 컬럼은 마지막에 둡니다.
+    pub(crate) fn set_column(
+        &mut self,
+        name: &str,
+        column: Column,
+        position: Option<&ColumnPosition>,
+    ) {
+        if let Some(index) = self.columns.iter().position(|column| column.name == name) {
+            if position.is_none() {
+                self.columns[index] = column;
+                return;
+            }
+
+            self.columns.remove(index);
+        }
+
+        let index = match position {
+            None => self.columns.len(),
+            Some(ColumnPosition::First) => 0,
+            Some(ColumnPosition::After(after)) => self
+                .columns
+                .iter()
+                .position(|column| &column.name == after)
+                .map_or(self.columns.len(), |index| index + 1),
+        };
+
+        self.columns.insert(index, column);
+    }
+
+    // 컬럼을 삭제합니다. 해당 컬럼을 포함하는 제약조건과 인덱스도 함께 삭제됩니다.
+    // 해당 컬럼을 사용하는 CHECK 제약조건도 함께 삭제됩니다.
+    pub(crate) fn drop_column(&mut self, name: &str) {
+        let contains = |columns: &Vec<String>| columns.iter().any(|column| column == name);
+
+        self.columns.retain(|column| column.name != name);
+
+        if self
+            .primary_key
+            .as_ref()
+            .is_some_and(|primary_key| contains(&primary_key.columns))
+        {
+            self.primary_key = None;
+        }
+
+        self.foreign_keys
+            .retain(|foreign_key| !contains(&foreign_key.columns));
+        self.unique_keys
+            .retain(|unique_key| !contains(&unique_key.columns));
+        self.check_constraints
+            .retain(|check| !uses_column(&check.expression, name));
+
+        for column in &mut self.columns {
+            column
+                .checks
+                .retain(|check| !uses_column(&check.expression, name));
+        }
+
+        self.indexes.retain(|index| {
+            !index
+                .columns
+                .iter()
+                .any(|column| column.column_name == name)
+        });
+    }
+}
+
+impl From<CreateTableQuery> for Table {
+    fn from(query: CreateTableQuery) -> Self {
+        Table {
+            name: query.table.unwrap_or_default(),
+            columns: query.columns,
+            primary_key: query.primary_key,
+            foreign_keys: query.foreign_keys,
+            unique_keys: query.unique_keys,
+            check_constraints: query.check_constraints,
+            indexes: query.indexes,
+            table_options: query.table_options,
+        }
+    }
+}
+
+// 식에서 해당 컬럼을 사용하는지
+fn uses_column(expression: &Expression, name: &str) -> bool {
+    struct ColumnUsage<'a> {
+        name: &'a str,
+        used: bool,
+    }
+
+    impl Visitor for ColumnUsage<'_> {
+        fn visit_column_name(&mut self, column_name: &ColumnName) {
+            if column_name.column_name == self.name {
+                self.used = true;
+            }
+        }
+    }
+
+    let mut usage = ColumnUsage { name, used: false };
+    walk_expression(&mut usage, expression);
+
+    usage.used
+}
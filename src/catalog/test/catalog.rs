@@ -0,0 +1,624 @@
+#![cfg(test)]
+
+use crate::ast::predule::DropTableQuery;
+use crate::ast::types::{DataType, IdentityKind, SortOrder, TableName};
+use crate::catalog::predule::Catalog;
+use crate::errors::predule::Error;
+use crate::lexer::predule::Location;
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+
+fn build(text: &str) -> Result<Catalog, Error> {
+    let mut parser = Parser::new(text.to_owned()).unwrap();
+
+    let statements = parser.parse(ParserContext::default()).unwrap();
+
+    Catalog::from_statements(&statements)
+}
+
+#[test]
+pub fn catalog_create_and_alter_table() {
+    let catalog = build(
+        r#"
+        CREATE DATABASE shop;
+        CREATE TABLE shop.person (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+            age INTEGER
+        );
+        ALTER TABLE shop.person ADD COLUMN email TEXT;
+        ALTER TABLE shop.person ALTER COLUMN name SET NOT NULL;
+        ALTER TABLE shop.person ALTER COLUMN age TYPE BIGINT;
+        ALTER TABLE shop.person DROP COLUMN age;
+        ALTER TABLE shop.person RENAME COLUMN email TO mail;
+        ALTER TABLE shop.person RENAME TO member;
+        CREATE UNIQUE INDEX idx_mail ON shop.member (mail DESC);
+    "#,
+    )
+    .unwrap();
+
+    assert_eq!(catalog.databases.len(), 1);
+    assert_eq!(catalog.tables_in("shop").len(), 1);
+
+    let table_name = TableName::new(Some("shop".to_owned()), "member".to_owned());
+    let table = catalog.table(&table_name).unwrap();
+
+    assert_eq!(
+        table
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["id", "name", "mail"]
+    );
+    assert!(table.column("name").unwrap().not_null);
+    assert_eq!(table.column("mail").unwrap().data_type, DataType::Text);
+
+    let index = table.index("idx_mail").unwrap();
+    assert!(index.is_unique);
+    assert_eq!(index.columns[0].column_name, "mail");
+    assert_eq!(index.columns[0].order, Some(SortOrder::Desc));
+}
+
+#[test]
+pub fn catalog_rename_updates_references() {
+    let catalog = build(
+        r#"
+        CREATE TABLE person (id INTEGER PRIMARY KEY);
+        CREATE TABLE post (
+            id INTEGER,
+            writer_id INTEGER,
+            CONSTRAINT fk_writer FOREIGN KEY (writer_id) REFERENCES person (id)
+        );
+        ALTER TABLE person RENAME COLUMN id TO person_id;
+        ALTER TABLE person RENAME TO member;
+        ALTER TABLE post RENAME COLUMN writer_id TO member_id;
+    "#,
+    )
+    .unwrap();
+
+    let post = catalog
+        .table(&TableName::new(None, "post".to_owned()))
+        .unwrap();
+    let foreign_key = &post.foreign_keys[0];
+
+    assert_eq!(foreign_key.columns, vec!["member_id".to_owned()]);
+    assert_eq!(
+        foreign_key.referenced_table,
+        TableName::new(None, "member".to_owned())
+    );
+    assert_eq!(foreign_key.referenced_columns, vec!["person_id".to_owned()]);
+}
+
+#[test]
+pub fn catalog_drop_column_removes_constraints() {
+    let catalog = build(
+        r#"
+        CREATE TABLE person (id INTEGER, email TEXT, UNIQUE (email));
+        CREATE INDEX idx_email ON person (email);
+        ALTER TABLE person DROP COLUMN email;
+    "#,
+    )
+    .unwrap();
+
+    let table = catalog
+        .table(&TableName::new(None, "person".to_owned()))
+        .unwrap();
+
+    assert!(table.unique_keys.is_empty());
+    assert!(table.indexes.is_empty());
+}
+
+#[test]
+pub fn catalog_drop_database_and_table() {
+    let catalog = build(
+        r#"
+        CREATE DATABASE shop;
+        CREATE TABLE shop.person (id INTEGER);
+        CREATE TABLE post (id INTEGER);
+        DROP DATABASE shop;
+        DROP TABLE post;
+        DROP TABLE IF EXISTS post;
+        DROP DATABASE IF EXISTS shop;
+    "#,
+    )
+    .unwrap();
+
+    assert!(catalog.databases.is_empty());
+    assert!(catalog.tables.is_empty());
+}
+
+#[test]
+pub fn catalog_conflicts() {
+    let cases = [
+        ("CREATE DATABASE a; CREATE DATABASE a;", "E2001"),
+        ("DROP DATABASE a;", "E2002"),
+        (
+            "CREATE TABLE a (id INTEGER); CREATE TABLE a (id INTEGER);",
+            "E2003",
+        ),
+        ("ALTER TABLE a ADD COLUMN id INTEGER;", "E2004"),
+        ("CREATE TABLE a (id INTEGER, id TEXT);", "E2005"),
+        (
+            "CREATE TABLE a (id INTEGER); ALTER TABLE a DROP COLUMN name;",
+            "E2006",
+        ),
+        (
+            "CREATE TABLE a (id INTEGER); CREATE INDEX i ON a (id); CREATE INDEX i ON a (id);",
+            "E2007",
+        ),
+    ];
+
+    for (text, code) in cases {
+        let error = build(text).unwrap_err();
+
+        assert!(matches!(error, Error::Execute(_)), "{}", text);
+        assert_eq!(error.code(), code, "{}", text);
+    }
+
+    // 실패한 문장의 위치가 함께 보고됩니다.
+    let error = build("CREATE TABLE a (id INTEGER);\nALTER TABLE a DROP COLUMN name;").unwrap_err();
+
+    assert_eq!(error.span().unwrap().start, Location::new(29, 2, 1));
+}
+
+#[test]
+pub fn catalog_if_not_exists() {
+    let catalog = build(
+        r#"
+        CREATE TABLE a (id INTEGER);
+        CREATE TABLE IF NOT EXISTS a (name TEXT);
+    "#,
+    )
+    .unwrap();
+
+    assert_eq!(catalog.tables.len(), 1);
+    assert!(catalog.tables[0].column("id").is_some());
+}
+
+#[test]
+pub fn catalog_alter_table_multiple_actions() {
+    let catalog = build(
+        "CREATE TABLE a (id INTEGER, name TEXT);
+        ALTER TABLE a ADD COLUMN age INTEGER, DROP COLUMN name, RENAME TO b, RENAME COLUMN id TO no;",
+    )
+    .unwrap();
+
+    let table = catalog.table(&TableName::new(None, "b".into())).unwrap();
+
+    assert_eq!(
+        table
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["no", "age"]
+    );
+
+    // 동작 하나가 실패하면 문장 전체가 적용되지 않습니다.
+    let mut catalog = build("CREATE TABLE a (id INTEGER);").unwrap();
+
+    let mut parser =
+        Parser::new("ALTER TABLE a ADD COLUMN age INTEGER, DROP COLUMN name;".to_owned()).unwrap();
+    let statements = parser.parse(ParserContext::default()).unwrap();
+
+    assert_eq!(catalog.apply(&statements[0]).unwrap_err().code(), "E2006");
+    assert!(catalog
+        .table(&TableName::new(None, "a".into()))
+        .unwrap()
+        .column("age")
+        .is_none());
+}
+
+#[test]
+pub fn catalog_alter_table_constraints() {
+    let catalog = build(
+        "CREATE TABLE a (id INTEGER, email TEXT, CONSTRAINT uq_a_email UNIQUE (email));
+        ALTER TABLE a RENAME CONSTRAINT uq_a_email TO uq_email, VALIDATE CONSTRAINT uq_email;
+        ALTER TABLE a ADD INDEX idx_a_id (id), DROP CONSTRAINT IF EXISTS uq_missing;",
+    )
+    .unwrap();
+
+    let table = catalog.table(&TableName::new(None, "a".into())).unwrap();
+
+    assert!(table.has_constraint("uq_email"));
+    assert!(!table.has_constraint("uq_a_email"));
+    assert!(table.index("idx_a_id").is_some());
+
+    let cases = [
+        ("ALTER TABLE a VALIDATE CONSTRAINT uq_missing;", "E2010"),
+        ("ALTER TABLE a DROP CONSTRAINT uq_missing;", "E2010"),
+        (
+            "ALTER TABLE a ADD CONSTRAINT uq_a_id UNIQUE (id), RENAME CONSTRAINT uq_a_id TO uq_email;",
+            "E2012",
+        ),
+    ];
+
+    for (text, code) in cases {
+        let mut catalog =
+            build("CREATE TABLE a (id INTEGER, email TEXT, CONSTRAINT uq_email UNIQUE (email));")
+                .unwrap();
+
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+        let statements = parser.parse(ParserContext::default()).unwrap();
+
+        assert_eq!(
+            catalog.apply(&statements[0]).unwrap_err().code(),
+            code,
+            "{}",
+            text
+        );
+    }
+}
+
+#[test]
+pub fn catalog_alter_table_column_position() {
+    let catalog = build(
+        "CREATE TABLE a (id INTEGER, name TEXT, age INTEGER, PRIMARY KEY (id));
+        ALTER TABLE a ADD COLUMN email TEXT AFTER id, MODIFY COLUMN age BIGINT FIRST;
+        ALTER TABLE a CHANGE COLUMN id no INTEGER NOT NULL AFTER name, MODIFY name VARCHAR(20);",
+    )
+    .unwrap();
+
+    let table = catalog.table(&TableName::new(None, "a".into())).unwrap();
+
+    assert_eq!(
+        table
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["age", "email", "name", "no"]
+    );
+    assert_eq!(
+        table.column("age").unwrap().data_type,
+        DataType::BigInt(None)
+    );
+    assert_eq!(
+        table.column("name").unwrap().data_type,
+        DataType::Varchar(Some(20))
+    );
+    assert_eq!(
+        table.primary_key.as_ref().unwrap().columns,
+        vec!["no".to_owned()]
+    );
+
+    let cases = [
+        ("ALTER TABLE a MODIFY COLUMN missing INTEGER;", "E2006"),
+        ("ALTER TABLE a ADD COLUMN b INTEGER AFTER missing;", "E2006"),
+        ("ALTER TABLE a CHANGE COLUMN id name INTEGER;", "E2005"),
+        ("ALTER TABLE a MODIFY COLUMN id INTEGER AFTER id;", "E2013"),
+    ];
+
+    for (text, code) in cases {
+        let mut catalog = build("CREATE TABLE a (id INTEGER, name TEXT);").unwrap();
+
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+        let statements = parser.parse(ParserContext::default()).unwrap();
+
+        assert_eq!(
+            catalog.apply(&statements[0]).unwrap_err().code(),
+            code,
+            "{}",
+            text
+        );
+    }
+}
+
+#[test]
+pub fn catalog_alter_column_identity() {
+    let catalog = build(
+        "CREATE TABLE a (id INTEGER, name TEXT COMMENT 'name');
+        ALTER TABLE a
+            ALTER COLUMN id ADD GENERATED ALWAYS AS IDENTITY,
+            ALTER COLUMN id SET GENERATED BY DEFAULT,
+            ALTER COLUMN name DROP COMMENT,
+            ALTER COLUMN name SET STATISTICS 100;",
+    )
+    .unwrap();
+
+    let table = catalog.table(&TableName::new(None, "a".into())).unwrap();
+
+    assert_eq!(
+        table.column("id").unwrap().identity.as_ref().unwrap().kind,
+        IdentityKind::ByDefault
+    );
+    assert_eq!(table.column("name").unwrap().comment, "");
+
+    let cases = [
+        ("ALTER TABLE a ALTER COLUMN id DROP IDENTITY;", "E2014"),
+        ("ALTER TABLE a ALTER COLUMN id SET GENERATED ALWAYS;", "E2014"),
+        (
+            "ALTER TABLE a ALTER COLUMN id ADD GENERATED ALWAYS AS IDENTITY, ALTER COLUMN id ADD GENERATED ALWAYS AS IDENTITY;",
+            "E2014",
+        ),
+    ];
+
+    for (text, code) in cases {
+        let mut catalog = build("CREATE TABLE a (id INTEGER);").unwrap();
+
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+        let statements = parser.parse(ParserContext::default()).unwrap();
+
+        assert_eq!(
+            catalog.apply(&statements[0]).unwrap_err().code(),
+            code,
+            "{}",
+            text
+        );
+    }
+
+    // IF EXISTS는 IDENTITY가 없어도 오류가 아닙니다.
+    let mut catalog = build("CREATE TABLE a (id INTEGER);").unwrap();
+    let mut parser =
+        Parser::new("ALTER TABLE a ALTER COLUMN id DROP IDENTITY IF EXISTS;".to_owned()).unwrap();
+    let statements = parser.parse(ParserContext::default()).unwrap();
+
+    assert!(catalog.apply(&statements[0]).is_ok());
+}
+
+#[test]
+pub fn catalog_drop_multiple() {
+    let catalog = build(
+        "CREATE DATABASE d;
+        CREATE DATABASE e;
+        CREATE TABLE d.a (id INTEGER PRIMARY KEY);
+        CREATE TABLE b (id INTEGER PRIMARY KEY);
+        CREATE TABLE c (a_id INTEGER REFERENCES d.a (id), b_id INTEGER, FOREIGN KEY (b_id) REFERENCES b (id));
+        CREATE TABLE x (id INTEGER);
+        DROP TABLE IF EXISTS b, missing, x CASCADE;
+        DROP DATABASE d, e CASCADE;",
+    )
+    .unwrap();
+
+    assert!(catalog.databases.is_empty());
+    assert_eq!(catalog.tables.len(), 1);
+
+    let table = catalog.table(&TableName::new(None, "c".into())).unwrap();
+
+    assert!(table.foreign_keys.is_empty());
+    assert!(table.column("a_id").unwrap().reference.is_none());
+
+    // 하나라도 없는 테이블이 있으면 아무것도 삭제하지 않습니다.
+    let mut catalog = build("CREATE TABLE a (id INTEGER); CREATE TABLE b (id INTEGER);").unwrap();
+
+    let mut parser = Parser::new("DROP TABLE a, missing, b;".to_owned()).unwrap();
+    let statements = parser.parse(ParserContext::default()).unwrap();
+
+    assert_eq!(catalog.apply(&statements[0]).unwrap_err().code(), "E2004");
+    assert_eq!(catalog.tables.len(), 2);
+}
+
+#[test]
+pub fn catalog_column_level_named_constraints() {
+    let catalog = build(
+        "CREATE TABLE p (id INTEGER PRIMARY KEY);
+        CREATE TABLE t (
+            a INTEGER CONSTRAINT chk CHECK (a > 0) CHECK (a < 10),
+            b INTEGER CONSTRAINT fk_p REFERENCES p (id)
+        );
+        ALTER TABLE t RENAME CONSTRAINT chk TO chk_a;
+        ALTER TABLE t DROP CONSTRAINT chk_a;
+        ALTER TABLE t DROP CONSTRAINT fk_p;",
+    )
+    .unwrap();
+
+    let table = catalog.table(&TableName::new(None, "t".into())).unwrap();
+
+    assert_eq!(table.column("a").unwrap().checks.len(), 1);
+    assert!(table.column("b").unwrap().reference.is_none());
+    assert!(!table.has_constraint("chk_a"));
+}
+
+#[test]
+pub fn catalog_drop_column_removes_checks() {
+    let catalog = build(
+        "CREATE TABLE t (
+            a INTEGER,
+            b INTEGER CHECK (b > a),
+            c INTEGER,
+            CHECK (a < c),
+            CHECK (c > 0)
+        );
+        ALTER TABLE t DROP COLUMN a;",
+    )
+    .unwrap();
+
+    let table = catalog.table(&TableName::new(None, "t".into())).unwrap();
+
+    assert!(table.column("b").unwrap().checks.is_empty());
+    assert_eq!(table.check_constraints.len(), 1);
+}
+
+#[test]
+pub fn catalog_drop_restrict() {
+    let cases = [
+        "DROP TABLE p;",
+        "DROP TABLE p RESTRICT;",
+        "DROP DATABASE d;",
+    ];
+
+    for text in cases {
+        let mut catalog = build(
+            "CREATE DATABASE d;
+            CREATE TABLE p (id INTEGER PRIMARY KEY);
+            CREATE TABLE d.q (id INTEGER PRIMARY KEY);
+            CREATE TABLE c (p_id INTEGER REFERENCES p (id), q_id INTEGER, FOREIGN KEY (q_id) REFERENCES d.q (id));",
+        )
+        .unwrap();
+
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+        let statements = parser.parse(ParserContext::default()).unwrap();
+
+        assert_eq!(
+            catalog.apply(&statements[0]).unwrap_err().code(),
+            "E2015",
+            "{}",
+            text
+        );
+        assert_eq!(catalog.tables.len(), 3, "{}", text);
+    }
+
+    // 참조하는 테이블도 함께 삭제한다면 RESTRICT여도 삭제할 수 있습니다.
+    let catalog = build(
+        "CREATE TABLE p (id INTEGER PRIMARY KEY);
+        CREATE TABLE c (p_id INTEGER REFERENCES p (id));
+        DROP TABLE p, c;",
+    )
+    .unwrap();
+
+    assert!(catalog.tables.is_empty());
+}
+
+#[test]
+pub fn catalog_alter_table_rolls_back_referencing_tables() {
+    let mut catalog = build(
+        "CREATE TABLE p (id INTEGER PRIMARY KEY);
+        CREATE TABLE c (p_id INTEGER REFERENCES p (id));
+        CREATE TABLE other (id INTEGER);",
+    )
+    .unwrap();
+
+    let before = catalog.clone();
+
+    let mut parser = Parser::new(
+        "ALTER TABLE p RENAME COLUMN id TO p_id, RENAME TO q, DROP COLUMN x;".to_owned(),
+    )
+    .unwrap();
+    let statements = parser.parse(ParserContext::default()).unwrap();
+
+    assert_eq!(catalog.apply(&statements[0]).unwrap_err().code(), "E2006");
+    assert_eq!(catalog, before);
+}
+
+#[test]
+pub fn catalog_column_level_primary_key() {
+    let cases = [
+        "CREATE TABLE t (id INTEGER PRIMARY KEY, b INTEGER); ALTER TABLE t ADD PRIMARY KEY (b);",
+        "CREATE TABLE t (id INTEGER PRIMARY KEY); ALTER TABLE t ADD COLUMN b INTEGER PRIMARY KEY;",
+    ];
+
+    for text in cases {
+        assert_eq!(build(text).unwrap_err().code(), "E2009", "{}", text);
+    }
+
+    let catalog = build(
+        "CREATE TABLE t (id INTEGER PRIMARY KEY, b INTEGER);
+        ALTER TABLE t DROP PRIMARY KEY;
+        ALTER TABLE t ADD PRIMARY KEY (b);",
+    )
+    .unwrap();
+
+    let table = catalog.table(&TableName::new(None, "t".into())).unwrap();
+
+    assert!(!table.column("id").unwrap().primary_key);
+    assert_eq!(table.primary_key.as_ref().unwrap().columns, vec!["b"]);
+}
+
+#[test]
+pub fn catalog_column_constraint_names() {
+    let catalog = build(
+        "CREATE TABLE t (id INTEGER CONSTRAINT pk_t PRIMARY KEY, email TEXT CONSTRAINT uq_email UNIQUE);
+        ALTER TABLE t RENAME CONSTRAINT uq_email TO uq_t_email;
+        ALTER TABLE t DROP CONSTRAINT pk_t;",
+    )
+    .unwrap();
+
+    let table = catalog.table(&TableName::new(None, "t".into())).unwrap();
+
+    assert!(!table.has_primary_key());
+    assert_eq!(
+        table.column("email").unwrap().unique_name.as_deref(),
+        Some("uq_t_email")
+    );
+}
+
+#[test]
+pub fn catalog_create_table_index() {
+    let catalog = build("CREATE TABLE t (id INTEGER, name TEXT, KEY idx_name (name));").unwrap();
+
+    let table = catalog.table(&TableName::new(None, "t".into())).unwrap();
+
+    assert_eq!(
+        table.index("idx_name").unwrap().columns[0].column_name,
+        "name"
+    );
+
+    let cases = [
+        ("CREATE TABLE t (id INTEGER, KEY idx (name));", "E2006"),
+        (
+            "CREATE TABLE t (id INTEGER, KEY idx (id), INDEX idx (id));",
+            "E2007",
+        ),
+    ];
+
+    for (text, code) in cases {
+        let error = build(text).unwrap_err();
+
+        assert_eq!(error.code(), code, "{}", text);
+    }
+}
+
+#[test]
+pub fn catalog_index_name_per_table() {
+    let catalog = build(
+        "CREATE TABLE a (id INTEGER);
+        CREATE TABLE b (id INTEGER);
+        CREATE INDEX idx ON a (id);
+        CREATE INDEX idx ON b (id);",
+    )
+    .unwrap();
+
+    assert_eq!(catalog.tables[0].indexes.len(), 1);
+    assert_eq!(catalog.tables[1].indexes.len(), 1);
+
+    let error = build(
+        "CREATE TABLE a (id INTEGER, b INTEGER);
+        CREATE INDEX idx ON a (id);
+        CREATE INDEX idx ON a (b);",
+    )
+    .unwrap_err();
+
+    assert_eq!(error.code(), "E2007");
+}
+
+#[test]
+pub fn catalog_drop_referenced_column() {
+    let cases = [
+        "CREATE TABLE p (id INTEGER PRIMARY KEY);
+        CREATE TABLE c (p_id INTEGER REFERENCES p (id));
+        ALTER TABLE p DROP COLUMN id;",
+        "CREATE TABLE p (id INTEGER PRIMARY KEY);
+        CREATE TABLE c (p_id INTEGER, FOREIGN KEY (p_id) REFERENCES p (id));
+        ALTER TABLE p DROP COLUMN id;",
+        "CREATE TABLE t (id INTEGER PRIMARY KEY, parent_id INTEGER REFERENCES t (id));
+        ALTER TABLE t DROP COLUMN id;",
+    ];
+
+    for text in cases {
+        assert_eq!(build(text).unwrap_err().code(), "E2015", "{}", text);
+    }
+
+    // 참조하는 컬럼 자신은 삭제할 수 있습니다.
+    let catalog = build(
+        "CREATE TABLE t (id INTEGER PRIMARY KEY, parent_id INTEGER REFERENCES t (id));
+        ALTER TABLE t DROP COLUMN parent_id;",
+    )
+    .unwrap();
+
+    assert_eq!(catalog.tables[0].columns.len(), 1);
+}
+
+#[test]
+pub fn catalog_error_without_span() {
+    let mut catalog = Catalog::new();
+
+    let statement = DropTableQuery::builder()
+        .add_table(TableName::new(None, "t".into()))
+        .build();
+
+    let error = catalog.apply(&statement).unwrap_err();
+
+    assert_eq!(error.code(), "E2004");
+    assert!(error.span().is_none());
+}
@@ -0,0 +1,495 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::catalog::predule::{Catalog, DiffOptions};
+use crate::dialect::predule::{MySQLDialect, PostgreSQLDialect, SQLiteDialect};
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+use crate::renderer::predule::ToSql;
+use crate::renderer::test::round_trip::assert_round_trip;
+
+fn build(text: &str) -> Catalog {
+    let mut parser = Parser::new(text.to_owned()).unwrap();
+
+    let statements = parser.parse(ParserContext::default()).unwrap();
+
+    Catalog::from_statements(&statements).unwrap()
+}
+
+fn assert_diff(source: &str, target: &str, expected: &[&str]) {
+    assert_diff_with(source, target, DiffOptions::default(), expected);
+}
+
+// 생성된 문장을 원본 스키마에 적용하면 대상 스키마와 같아져야 합니다.
+// 생성된 문장은 같은 방언으로 다시 파싱할 수 있어야 합니다.
+fn assert_diff_with(source: &str, target: &str, options: DiffOptions, expected: &[&str]) {
+    let dialect = options.dialect.clone();
+
+    let source = build(source);
+    let target = build(target);
+
+    let statements = source.diff(&target, options).unwrap();
+
+    assert_eq!(
+        statements
+            .iter()
+            .map(|statement| statement.to_sql(dialect.as_ref()))
+            .collect::<Vec<_>>(),
+        expected
+    );
+
+    for statement in &statements {
+        assert_round_trip(statement, dialect.clone());
+    }
+
+    let mut migrated = source.clone();
+    migrated.apply_all(&statements).unwrap();
+
+    assert_eq!(sorted(migrated), sorted(target));
+}
+
+// 참조 관계에 따라 생성 순서가 바뀔 수 있으므로 테이블 순서는 비교하지 않습니다.
+fn sorted(mut catalog: Catalog) -> Catalog {
+    catalog
+        .tables
+        .sort_by(|a, b| a.name.table_name.cmp(&b.name.table_name));
+    catalog
+}
+
+fn diff_error(source: &str, target: &str, options: DiffOptions) -> String {
+    build(source)
+        .diff(&build(target), options)
+        .unwrap_err()
+        .code()
+        .to_owned()
+}
+
+#[test]
+pub fn diff_columns() {
+    assert_diff_with(
+        r#"
+        CREATE TABLE person (
+            id INTEGER NOT NULL,
+            name VARCHAR(50),
+            age INTEGER DEFAULT 0,
+            nickname TEXT
+        );
+        "#,
+        r#"
+        CREATE TABLE person (
+            id INTEGER NOT NULL,
+            name VARCHAR(100) NOT NULL,
+            age INTEGER,
+            alias TEXT,
+            email TEXT DEFAULT 'none'
+        );
+        "#,
+        DiffOptions::default().set_detect_renames(true),
+        &[
+            "ALTER TABLE person RENAME COLUMN nickname TO alias;",
+            "ALTER TABLE person ADD COLUMN email text DEFAULT 'none';",
+            "ALTER TABLE person MODIFY COLUMN name varchar(100) NOT NULL;",
+            "ALTER TABLE person ALTER COLUMN age DROP DEFAULT;",
+        ],
+    );
+}
+
+#[test]
+pub fn diff_tables_and_databases() {
+    assert_diff(
+        r#"
+        CREATE DATABASE old_db;
+        CREATE TABLE old_db.log (id INTEGER);
+        CREATE TABLE person (id INTEGER);
+        CREATE TABLE post (id INTEGER);
+        "#,
+        r#"
+        CREATE DATABASE new_db;
+        CREATE TABLE person (id INTEGER);
+        CREATE TABLE new_db.reply (id INTEGER, body TEXT);
+        CREATE INDEX idx_body ON new_db.reply (body);
+        "#,
+        &[
+            "CREATE DATABASE new_db;",
+            "DROP TABLE post;",
+            "CREATE TABLE new_db.reply (\n    id integer,\n    body text\n);",
+            "CREATE INDEX idx_body ON new_db.reply (body);",
+            "DROP DATABASE old_db;",
+        ],
+    );
+}
+
+#[test]
+pub fn diff_constraints_and_indexes() {
+    assert_diff(
+        r#"
+        CREATE TABLE person (id INTEGER, email TEXT, PRIMARY KEY (id));
+        CREATE TABLE post (
+            id INTEGER,
+            writer_id INTEGER,
+            CONSTRAINT uk_writer UNIQUE (writer_id)
+        );
+        CREATE INDEX idx_email ON person (email);
+        CREATE INDEX idx_writer ON post (writer_id);
+        "#,
+        r#"
+        CREATE TABLE person (id INTEGER, email TEXT, CONSTRAINT pk_person PRIMARY KEY (id));
+        CREATE TABLE post (
+            id INTEGER,
+            writer_id INTEGER,
+            CONSTRAINT fk_writer FOREIGN KEY (writer_id) REFERENCES person (id)
+        );
+        CREATE UNIQUE INDEX idx_email ON person (email);
+        CREATE INDEX idx_writer ON post (writer_id);
+        "#,
+        &[
+            "ALTER TABLE person DROP PRIMARY KEY;",
+            "ALTER TABLE person DROP INDEX idx_email;",
+            "ALTER TABLE post DROP CONSTRAINT uk_writer;",
+            "ALTER TABLE person ADD CONSTRAINT pk_person PRIMARY KEY (id);",
+            "CREATE UNIQUE INDEX idx_email ON person (email);",
+            "ALTER TABLE post ADD CONSTRAINT fk_writer FOREIGN KEY (writer_id) REFERENCES person (id);",
+        ],
+    );
+}
+
+#[test]
+pub fn diff_same_schema() {
+    let text = r#"
+        CREATE TABLE person (id INTEGER PRIMARY KEY, name TEXT);
+        CREATE INDEX idx_name ON person (name);
+    "#;
+
+    assert!(build(text)
+        .diff(&build(text), DiffOptions::default())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+pub fn diff_column_comment_identity_collation() {
+    assert_diff(
+        r#"
+        CREATE TABLE person (
+            id INTEGER GENERATED ALWAYS AS IDENTITY,
+            code INTEGER GENERATED ALWAYS AS IDENTITY,
+            seq INTEGER,
+            name TEXT COMMENT 'name',
+            email TEXT
+        );
+        "#,
+        r#"
+        CREATE TABLE person (
+            id INTEGER GENERATED BY DEFAULT AS IDENTITY,
+            code INTEGER,
+            seq INTEGER GENERATED ALWAYS AS IDENTITY (START WITH 100),
+            name TEXT,
+            email TEXT COLLATE "C" COMMENT 'email'
+        );
+        "#,
+        &[
+            "ALTER TABLE person ALTER COLUMN id SET GENERATED BY DEFAULT;",
+            "ALTER TABLE person ALTER COLUMN code DROP IDENTITY;",
+            "ALTER TABLE person ALTER COLUMN seq ADD GENERATED ALWAYS AS IDENTITY (START WITH 100);",
+            "ALTER TABLE person MODIFY COLUMN name text;",
+            "ALTER TABLE person MODIFY COLUMN email text COLLATE C COMMENT 'email';",
+        ],
+    );
+}
+
+#[test]
+pub fn diff_columns_without_rename_detection() {
+    assert_diff(
+        "CREATE TABLE person (id INTEGER, nickname TEXT);",
+        "CREATE TABLE person (id INTEGER, alias TEXT);",
+        &[
+            "ALTER TABLE person DROP COLUMN nickname;",
+            "ALTER TABLE person ADD COLUMN alias text;",
+        ],
+    );
+}
+
+#[test]
+pub fn diff_constraints_and_indexes_postgresql() {
+    assert_diff_with(
+        r#"
+        CREATE TABLE person (id INTEGER, email TEXT, CONSTRAINT pk_person PRIMARY KEY (id));
+        CREATE INDEX idx_email ON person (email);
+        "#,
+        r#"
+        CREATE TABLE person (id INTEGER, email TEXT, CONSTRAINT pk_id PRIMARY KEY (id));
+        CREATE UNIQUE INDEX idx_email ON person (email);
+        "#,
+        DiffOptions::default().set_dialect(Arc::new(PostgreSQLDialect {})),
+        &[
+            "DROP INDEX idx_email;",
+            "ALTER TABLE person DROP CONSTRAINT pk_person;",
+            "ALTER TABLE person ADD CONSTRAINT pk_id PRIMARY KEY (id);",
+            "CREATE UNIQUE INDEX idx_email ON person (email);",
+        ],
+    );
+}
+
+#[test]
+pub fn diff_column_unique() {
+    assert_diff(
+        "CREATE TABLE person (id INTEGER, email TEXT);",
+        "CREATE TABLE person (id INTEGER, email TEXT UNIQUE);",
+        &["ALTER TABLE person MODIFY COLUMN email text UNIQUE;"],
+    );
+}
+
+#[test]
+pub fn diff_column_reference() {
+    assert_diff(
+        r#"
+        CREATE TABLE person (id INTEGER PRIMARY KEY);
+        CREATE TABLE post (id INTEGER, writer_id INTEGER);
+        "#,
+        r#"
+        CREATE TABLE person (id INTEGER PRIMARY KEY);
+        CREATE TABLE post (id INTEGER, writer_id INTEGER REFERENCES person (id));
+        "#,
+        &["ALTER TABLE post MODIFY COLUMN writer_id integer REFERENCES person (id);"],
+    );
+}
+
+#[test]
+pub fn diff_column_check() {
+    assert_diff(
+        "CREATE TABLE person (id INTEGER, age INTEGER CHECK (age >= 0));",
+        "CREATE TABLE person (id INTEGER, age INTEGER CONSTRAINT ck_age CHECK (age > 0));",
+        &["ALTER TABLE person MODIFY COLUMN age integer CONSTRAINT ck_age CHECK (age > 0);"],
+    );
+}
+
+#[test]
+pub fn diff_column_primary_key() {
+    assert_diff(
+        "CREATE TABLE person (id INTEGER, name TEXT);",
+        "CREATE TABLE person (id INTEGER PRIMARY KEY, name TEXT);",
+        &["ALTER TABLE person MODIFY COLUMN id integer PRIMARY KEY;"],
+    );
+}
+
+#[test]
+pub fn diff_column_auto_increment() {
+    assert_diff_with(
+        "CREATE TABLE person (id INTEGER NOT NULL PRIMARY KEY, name TEXT);",
+        "CREATE TABLE person (id INTEGER NOT NULL AUTO_INCREMENT PRIMARY KEY, name TEXT);",
+        DiffOptions::default().set_dialect(Arc::new(MySQLDialect {})),
+        &["ALTER TABLE person MODIFY COLUMN id integer PRIMARY KEY AUTO_INCREMENT;"],
+    );
+}
+
+#[test]
+pub fn diff_column_generated() {
+    assert_diff(
+        "CREATE TABLE person (price INTEGER, total INTEGER);",
+        "CREATE TABLE person (price INTEGER, total INTEGER GENERATED ALWAYS AS (price * 2) STORED);",
+        &["ALTER TABLE person MODIFY COLUMN total integer GENERATED ALWAYS AS (price * 2) STORED;"],
+    );
+}
+
+#[test]
+pub fn diff_column_on_update() {
+    assert_diff(
+        "CREATE TABLE person (id INTEGER, updated_at TIMESTAMP);",
+        "CREATE TABLE person (id INTEGER, updated_at TIMESTAMP ON UPDATE CURRENT_TIMESTAMP);",
+        &["ALTER TABLE person MODIFY COLUMN updated_at timestamp ON UPDATE CURRENT_TIMESTAMP;"],
+    );
+}
+
+#[test]
+pub fn diff_unnamed_unique_key_removed() {
+    assert_diff(
+        "CREATE TABLE person (id INTEGER, email TEXT, CONSTRAINT uk_email UNIQUE (email));",
+        "CREATE TABLE person (id INTEGER, email TEXT);",
+        &["ALTER TABLE person DROP CONSTRAINT uk_email;"],
+    );
+
+    assert_eq!(
+        diff_error(
+            "CREATE TABLE person (id INTEGER, email TEXT, UNIQUE (email));",
+            "CREATE TABLE person (id INTEGER, email TEXT);",
+            DiffOptions::default(),
+        ),
+        "E2016"
+    );
+}
+
+#[test]
+pub fn diff_table_options() {
+    assert_diff_with(
+        "CREATE TABLE person (id INTEGER) ENGINE=MyISAM;",
+        "CREATE TABLE person (id INTEGER) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;",
+        DiffOptions::default().set_dialect(Arc::new(MySQLDialect {})),
+        &[
+            "ALTER TABLE person ENGINE=InnoDB;",
+            "ALTER TABLE person CHARSET=utf8mb4;",
+        ],
+    );
+}
+
+// MODIFY COLUMN이 없는 방언은 ALTER COLUMN으로 바꿉니다.
+#[test]
+pub fn diff_column_postgresql() {
+    assert_diff_with(
+        "CREATE TABLE person (id INTEGER, name VARCHAR(50), email TEXT);",
+        r#"CREATE TABLE person (id BIGINT NOT NULL, name VARCHAR(100), email TEXT COLLATE "C");"#,
+        DiffOptions::default().set_dialect(Arc::new(PostgreSQLDialect {})),
+        &[
+            "ALTER TABLE person ALTER COLUMN id TYPE bigint;",
+            "ALTER TABLE person ALTER COLUMN id SET NOT NULL;",
+            "ALTER TABLE person ALTER COLUMN name TYPE varchar(100);",
+            r#"ALTER TABLE person ALTER COLUMN email SET COLLATION "C";"#,
+        ],
+    );
+
+    // 컬럼 주석은 COMMENT ON 문으로 바꿉니다.
+    assert_diff_with(
+        "CREATE TABLE person (id INTEGER COMMENT 'id', name TEXT);",
+        "CREATE TABLE person (id INTEGER, name TEXT COMMENT 'name');",
+        DiffOptions::default().set_dialect(Arc::new(PostgreSQLDialect {})),
+        &[
+            "COMMENT ON COLUMN person.id IS NULL;",
+            "COMMENT ON COLUMN person.name IS 'name';",
+        ],
+    );
+}
+
+// 기본값만 바뀐 경우에는 MODIFY COLUMN을 지원하는 방언도 ALTER COLUMN을 사용합니다.
+#[test]
+pub fn diff_column_mysql() {
+    assert_diff_with(
+        "CREATE TABLE person (id INTEGER, age INTEGER, name TEXT);",
+        "CREATE TABLE person (id INTEGER, age INTEGER DEFAULT 0, name TEXT NOT NULL);",
+        DiffOptions::default().set_dialect(Arc::new(MySQLDialect {})),
+        &[
+            "ALTER TABLE person ALTER COLUMN age SET DEFAULT 0;",
+            "ALTER TABLE person MODIFY COLUMN name text NOT NULL;",
+        ],
+    );
+}
+
+// 참조하는 테이블을 먼저 삭제하고, 참조되는 테이블을 먼저 생성합니다.
+// 외래키는 모든 테이블을 생성한 뒤에 추가합니다.
+#[test]
+pub fn diff_table_order() {
+    assert_diff(
+        r#"
+        CREATE TABLE parent (id INTEGER PRIMARY KEY);
+        CREATE TABLE child (parent_id INTEGER REFERENCES parent (id));
+        CREATE TABLE person (id INTEGER PRIMARY KEY, team_id INTEGER);
+        "#,
+        r#"
+        CREATE TABLE person (
+            id INTEGER PRIMARY KEY,
+            team_id INTEGER,
+            CONSTRAINT fk_team FOREIGN KEY (team_id) REFERENCES team (id)
+        );
+        CREATE TABLE member (team_id INTEGER REFERENCES team (id));
+        CREATE TABLE team (id INTEGER PRIMARY KEY);
+        "#,
+        &[
+            "DROP TABLE child;",
+            "DROP TABLE parent;",
+            "CREATE TABLE team (\n    id integer PRIMARY KEY\n);",
+            "CREATE TABLE member (\n    team_id integer REFERENCES team (id)\n);",
+            "ALTER TABLE person ADD CONSTRAINT fk_team FOREIGN KEY (team_id) REFERENCES team (id);",
+        ],
+    );
+
+    // 서로 참조하는 테이블
+    assert_diff(
+        r#"
+        CREATE TABLE a (id INTEGER PRIMARY KEY, b_id INTEGER REFERENCES b (id));
+        CREATE TABLE b (id INTEGER PRIMARY KEY, a_id INTEGER REFERENCES a (id));
+        "#,
+        r#"
+        CREATE TABLE c (
+            id INTEGER PRIMARY KEY,
+            d_id INTEGER,
+            CONSTRAINT fk_d FOREIGN KEY (d_id) REFERENCES d (id)
+        );
+        CREATE TABLE d (
+            id INTEGER PRIMARY KEY,
+            c_id INTEGER,
+            CONSTRAINT fk_c FOREIGN KEY (c_id) REFERENCES c (id)
+        );
+        "#,
+        &[
+            "DROP TABLE a, b;",
+            "CREATE TABLE c (\n    id integer PRIMARY KEY,\n    d_id integer\n);",
+            "CREATE TABLE d (\n    id integer PRIMARY KEY,\n    c_id integer,\n    CONSTRAINT fk_c FOREIGN KEY (c_id) REFERENCES c (id)\n);",
+            "ALTER TABLE c ADD CONSTRAINT fk_d FOREIGN KEY (d_id) REFERENCES d (id);",
+        ],
+    );
+}
+
+// 외래키가 참조하는 컬럼은 외래키를 삭제한 뒤에 삭제합니다.
+#[test]
+pub fn diff_drop_referenced_column() {
+    assert_diff(
+        r#"
+        CREATE TABLE person (id INTEGER, code INTEGER);
+        CREATE TABLE post (
+            writer_code INTEGER,
+            CONSTRAINT fk_writer FOREIGN KEY (writer_code) REFERENCES person (code)
+        );
+        "#,
+        r#"
+        CREATE TABLE person (id INTEGER);
+        CREATE TABLE post (writer_code INTEGER);
+        "#,
+        &[
+            "ALTER TABLE post DROP CONSTRAINT fk_writer;",
+            "ALTER TABLE person DROP COLUMN code;",
+        ],
+    );
+}
+
+// 방언의 문장으로 표현할 수 없는 변경은 오류로 알려야 합니다.
+#[test]
+pub fn diff_unsupported_changes() {
+    let postgresql = || DiffOptions::default().set_dialect(Arc::new(PostgreSQLDialect {}));
+    let sqlite = || DiffOptions::default().set_dialect(Arc::new(SQLiteDialect {}));
+
+    let cases = [
+        (
+            "CREATE TABLE person (id INTEGER, PRIMARY KEY (id));",
+            "CREATE TABLE person (id INTEGER);",
+            postgresql(),
+            "E2016",
+        ),
+        (
+            "CREATE TABLE person (id INTEGER, email TEXT);",
+            "CREATE TABLE person (id INTEGER, email TEXT UNIQUE);",
+            postgresql(),
+            "E2017",
+        ),
+        (
+            "CREATE TABLE person (id INTEGER, name VARCHAR(50));",
+            "CREATE TABLE person (id INTEGER, name VARCHAR(100));",
+            sqlite(),
+            "E2017",
+        ),
+        (
+            "CREATE TABLE person (id INTEGER) ENGINE=InnoDB;",
+            "CREATE TABLE person (id INTEGER);",
+            DiffOptions::default(),
+            "E2018",
+        ),
+        (
+            "CREATE TABLE person (id INTEGER);",
+            "CREATE TABLE person (id INTEGER) ENGINE=InnoDB;",
+            postgresql(),
+            "E2018",
+        ),
+    ];
+
+    for (source, target, options, code) in cases {
+        assert_eq!(diff_error(source, target, options), code, "{}", target);
+    }
+}
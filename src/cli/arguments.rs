@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use ddl_parser::dialect::predule::{dialect_from_name, Dialect, GenericDialect};
+
+pub const USAGE: &str = "usage: ddl-parser <command> [options] [path ...]
+
+commands:
+    parse [--format json|debug]     print the AST
+    check                           report diagnostics, exit 1 on errors
+    fmt                             print normalized SQL
+    convert --from <dialect> --to <dialect>
+                                    print DDL for another database
+
+options:
+    --dialect <dialect>             input dialect (generic, mysql, postgresql, sqlite)
+    -h, --help                      print this message
+
+paths may be files or directories (*.sql). reads stdin when omitted or '-'.";
+
+// parse 명령의 출력 형태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Json,
+    Debug,
+}
+
+#[derive(Debug, Clone)]
+pub enum Command {
+    Parse(OutputFormat),
+    Check,
+    Fmt,
+    Convert(Arc<dyn Dialect>),
+    Help,
+}
+
+// 명령행 인자 분석 결과
+#[derive(Debug, Clone)]
+pub struct Arguments {
+    pub command: Command,
+    pub dialect: Arc<dyn Dialect>,
+    pub paths: Vec<String>,
+}
+
+impl Arguments {
+    // 실행 파일 이름을 제외한 인자 목록을 분석합니다.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut args = args.into_iter();
+
+        let command_name = match args.next() {
+            Some(name) => name,
+            None => return Err("missing command".into()),
+        };
+
+        let mut dialect: Arc<dyn Dialect> = Arc::new(GenericDialect {});
+        let mut format = OutputFormat::default();
+        let mut target: Option<Arc<dyn Dialect>> = None;
+        let mut paths = vec![];
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => {
+                    return Ok(Arguments {
+                        command: Command::Help,
+                        dialect,
+                        paths,
+                    })
+                }
+                "--dialect" | "--from" => {
+                    dialect = parse_dialect(&arg, args.next())?;
+                }
+                "--to" => {
+                    target = Some(parse_dialect(&arg, args.next())?);
+                }
+                "--format" => {
+                    format = match args.next().as_deref() {
+                        Some("json") => OutputFormat::Json,
+                        Some("debug") => OutputFormat::Debug,
+                        Some(other) => return Err(format!("unknown format '{}'", other)),
+                        None => return Err("missing value for --format".into()),
+                    };
+                }
+                "-" => paths.push(arg),
+                _ if arg.starts_with('-') => return Err(format!("unknown option '{}'", arg)),
+                _ => paths.push(arg),
+            }
+        }
+
+        let command = match command_name.as_str() {
+            "parse" => Command::Parse(format),
+            "check" => Command::Check,
+            "fmt" => Command::Fmt,
+            "convert" => match target {
+                Some(target) => Command::Convert(target),
+                None => return Err("convert requires --to <dialect>".into()),
+            },
+            "help" | "-h" | "--help" => Command::Help,
+            _ => return Err(format!("unknown command '{}'", command_name)),
+        };
+
+        Ok(Arguments {
+            command,
+            dialect,
+            paths,
+        })
+    }
+}
+
+fn parse_dialect(option: &str, value: Option<String>) -> Result<Arc<dyn Dialect>, String> {
+    let value = value.ok_or_else(|| format!("missing value for {}", option))?;
+
+    dialect_from_name(&value).ok_or_else(|| format!("unknown dialect '{}'", value))
+}
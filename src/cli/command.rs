@@ -0,0 +1,140 @@
+use std::io::Write;
+use std::sync::Arc;
+
+use crate::cli::predule::{Arguments, Command, OutputFormat, Source, USAGE};
+use ddl_parser::ast::predule::SQLStatement;
+use ddl_parser::dialect::predule::Dialect;
+use ddl_parser::errors::predule::Error;
+use ddl_parser::parser::predule::{Parser, ParserContext};
+use ddl_parser::renderer::predule::ToSql;
+
+// 종료 코드
+pub const EXIT_SUCCESS: i32 = 0;
+pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+
+// 명령을 실행하고 종료 코드를 반환합니다.
+// 결과는 out에, 오류는 err에 출력합니다.
+pub fn run(
+    arguments: &Arguments,
+    sources: &[Source],
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> std::io::Result<i32> {
+    match &arguments.command {
+        Command::Help => {
+            writeln!(out, "{}", USAGE)?;
+            Ok(EXIT_SUCCESS)
+        }
+        Command::Check => check(arguments, sources, err),
+        Command::Parse(format) => {
+            let statements = match parse_all(arguments, sources, err)? {
+                Some(statements) => statements,
+                None => return Ok(EXIT_FAILURE),
+            };
+
+            match format {
+                OutputFormat::Json => writeln!(
+                    out,
+                    "{}",
+                    serde_json::to_string_pretty(&statements).map_err(std::io::Error::other)?
+                )?,
+                OutputFormat::Debug => writeln!(out, "{:#?}", statements)?,
+            }
+
+            Ok(EXIT_SUCCESS)
+        }
+        Command::Fmt => render(arguments, sources, arguments.dialect.as_ref(), out, err),
+        Command::Convert(target) => render(arguments, sources, target.as_ref(), out, err),
+    }
+}
+
+// 모든 입력의 오류를 출력합니다.
+fn check(arguments: &Arguments, sources: &[Source], err: &mut dyn Write) -> std::io::Result<i32> {
+    let mut error_count = 0;
+
+    for source in sources {
+        let mut parser = match Parser::with_dialect(source.text.clone(), arguments.dialect.clone())
+        {
+            Ok(parser) => parser,
+            Err(error) => {
+                report(err, source, &error)?;
+                error_count += 1;
+                continue;
+            }
+        };
+
+        for error in parser
+            .parse_with_diagnostics(ParserContext::default())
+            .diagnostics
+        {
+            report(err, source, &error)?;
+            error_count += 1;
+        }
+    }
+
+    if error_count > 0 {
+        writeln!(err, "{} error(s) found", error_count)?;
+        return Ok(EXIT_FAILURE);
+    }
+
+    Ok(EXIT_SUCCESS)
+}
+
+fn render(
+    arguments: &Arguments,
+    sources: &[Source],
+    dialect: &dyn Dialect,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> std::io::Result<i32> {
+    let statements = match parse_all(arguments, sources, err)? {
+        Some(statements) => statements,
+        None => return Ok(EXIT_FAILURE),
+    };
+
+    for statement in statements {
+        writeln!(out, "{}", statement.to_sql(dialect))?;
+    }
+
+    Ok(EXIT_SUCCESS)
+}
+
+// 모든 입력을 순서대로 분석합니다.
+// 오류가 있다면 오류를 출력하고 None을 반환합니다.
+fn parse_all(
+    arguments: &Arguments,
+    sources: &[Source],
+    err: &mut dyn Write,
+) -> std::io::Result<Option<Vec<SQLStatement>>> {
+    let mut statements = vec![];
+    let mut failed = false;
+
+    for source in sources {
+        match parse_source(source, arguments.dialect.clone()) {
+            Ok(parsed) => statements.extend(parsed),
+            Err(error) => {
+                report(err, source, &error)?;
+                failed = true;
+            }
+        }
+    }
+
+    Ok(if failed { None } else { Some(statements) })
+}
+
+fn parse_source(source: &Source, dialect: Arc<dyn Dialect>) -> Result<Vec<SQLStatement>, Error> {
+    Parser::with_dialect(source.text.clone(), dialect)?.parse(ParserContext::default())
+}
+
+// file:line:column: error 형태로 출력합니다.
+fn report(err: &mut dyn Write, source: &Source, error: &Error) -> std::io::Result<()> {
+    match error.span() {
+        Some(span) if !span.is_empty() => writeln!(
+            err,
+            "{}:{}:{}: {}",
+            source.name, span.start.line, span.start.column, error
+        ),
+        _ => writeln!(err, "{}: {}", source.name, error),
+    }
+}
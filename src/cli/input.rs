@@ -0,0 +1,76 @@
+use std::io::Read;
+use std::path::Path;
+
+// 분석할 SQL 입력
+// name은 오류 출력에 사용하는 파일 경로입니다. 표준 입력은 "<stdin>"입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    pub name: String,
+    pub text: String,
+}
+
+impl Source {
+    pub fn new(name: impl Into<String>, text: impl Into<String>) -> Self {
+        Source {
+            name: name.into(),
+            text: text.into(),
+        }
+    }
+}
+
+// 경로 목록에서 입력을 읽습니다.
+// 디렉터리는 하위의 .sql 파일을 경로 순서대로 읽고, 경로가 없거나 "-"라면 표준 입력을 읽습니다.
+pub fn read_sources(paths: &[String]) -> std::io::Result<Vec<Source>> {
+    read_sources_from(paths, &mut std::io::stdin())
+}
+
+// 표준 입력 대신 stdin에서 읽는 read_sources
+pub fn read_sources_from(paths: &[String], stdin: &mut impl Read) -> std::io::Result<Vec<Source>> {
+    if paths.is_empty() {
+        return Ok(vec![read_stdin(stdin)?]);
+    }
+
+    let mut sources = vec![];
+
+    for path in paths {
+        if path == "-" {
+            sources.push(read_stdin(stdin)?);
+        } else {
+            read_path(Path::new(path), &mut sources)?;
+        }
+    }
+
+    Ok(sources)
+}
+
+fn read_stdin(stdin: &mut impl Read) -> std::io::Result<Source> {
+    let mut text = String::new();
+    stdin.read_to_string(&mut text)?;
+
+    Ok(Source::new("<stdin>", text))
+}
+
+fn read_path(path: &Path, sources: &mut Vec<Source>) -> std::io::Result<()> {
+    if !path.is_dir() {
+        let text = std::fs::read_to_string(path)?;
+        sources.push(Source::new(path.display().to_string(), text));
+        return Ok(());
+    }
+
+    let mut entries = std::fs::read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort();
+
+    for entry in entries {
+        let is_sql = entry
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("sql"));
+
+        if entry.is_dir() || is_sql {
+            read_path(&entry, sources)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+pub mod arguments;
+pub mod command;
+pub mod input;
+pub mod predule;
+
+pub(crate) mod test;
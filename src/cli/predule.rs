@@ -0,0 +1,3 @@
+pub use super::arguments::*;
+pub use super::command::*;
+pub use super::input::*;
@@ -0,0 +1,57 @@
+#![cfg(test)]
+
+use crate::cli::predule::{Arguments, Command, OutputFormat};
+
+fn parse(args: &[&str]) -> Result<Arguments, String> {
+    Arguments::parse(args.iter().map(|arg| arg.to_string()))
+}
+
+#[test]
+pub fn arguments_parse() {
+    let arguments = parse(&[
+        "parse",
+        "--format",
+        "debug",
+        "--dialect",
+        "mysql",
+        "a.sql",
+        "-",
+    ])
+    .unwrap();
+
+    assert!(matches!(
+        arguments.command,
+        Command::Parse(OutputFormat::Debug)
+    ));
+    assert_eq!(arguments.dialect.name(), "mysql");
+    assert_eq!(arguments.paths, vec!["a.sql", "-"]);
+}
+
+#[test]
+pub fn arguments_convert() {
+    let arguments = parse(&[
+        "convert",
+        "--from",
+        "mysql",
+        "--to",
+        "postgres",
+        "migrations",
+    ])
+    .unwrap();
+
+    match arguments.command {
+        Command::Convert(target) => assert_eq!(target.name(), "postgresql"),
+        _ => panic!("expected convert command"),
+    }
+    assert_eq!(arguments.dialect.name(), "mysql");
+}
+
+#[test]
+pub fn arguments_errors() {
+    assert!(parse(&[]).is_err());
+    assert!(parse(&["lint"]).is_err());
+    assert!(parse(&["convert", "--from", "mysql"]).is_err());
+    assert!(parse(&["fmt", "--dialect", "oracle"]).is_err());
+    assert!(parse(&["parse", "--format", "yaml"]).is_err());
+    assert!(parse(&["check", "--verbose"]).is_err());
+}
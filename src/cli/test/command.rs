@@ -0,0 +1,106 @@
+#![cfg(test)]
+
+use crate::cli::predule::{run, Arguments, Source, EXIT_FAILURE, EXIT_SUCCESS};
+
+// 명령을 실행하고 (종료 코드, 표준 출력, 표준 오류)를 반환합니다.
+fn execute(args: &[&str], sources: &[Source]) -> (i32, String, String) {
+    let arguments = Arguments::parse(args.iter().map(|arg| arg.to_string())).unwrap();
+
+    let mut out = vec![];
+    let mut err = vec![];
+
+    let code = run(&arguments, sources, &mut out, &mut err).unwrap();
+
+    (
+        code,
+        String::from_utf8(out).unwrap(),
+        String::from_utf8(err).unwrap(),
+    )
+}
+
+#[test]
+pub fn command_check() {
+    let sources = [
+        Source::new("ok.sql", "CREATE TABLE foo (id INTEGER);"),
+        Source::new(
+            "bad.sql",
+            "CREATE TABLE foo (id INTEGER BOGUS);\nDROP TABLE;",
+        ),
+    ];
+
+    let (code, out, err) = execute(&["check"], &sources);
+
+    assert_eq!(code, EXIT_FAILURE);
+    assert!(out.is_empty());
+    assert!(err.starts_with("bad.sql:1:30: parsing error: E0046"));
+    assert!(err.contains("\nbad.sql:2:"));
+    assert!(err.ends_with("2 error(s) found\n"));
+
+    let (code, _, err) = execute(&["check"], &sources[..1]);
+
+    assert_eq!(code, EXIT_SUCCESS);
+    assert!(err.is_empty());
+}
+
+#[test]
+pub fn command_parse() {
+    let sources = [Source::new("a.sql", "DROP TABLE foo;")];
+
+    let (code, out, _) = execute(&["parse"], &sources);
+
+    assert_eq!(code, EXIT_SUCCESS);
+
+    let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(value[0]["value"]["type"], "DropTableQuery");
+
+    let (code, out, _) = execute(&["parse", "--format", "debug"], &sources);
+
+    assert_eq!(code, EXIT_SUCCESS);
+    assert!(out.contains("DropTableQuery"));
+}
+
+#[test]
+pub fn command_fmt() {
+    let sources = [
+        Source::new("1.sql", "create table foo(id integer not null);"),
+        Source::new("2.sql", "drop table if exists foo"),
+    ];
+
+    let (code, out, _) = execute(&["fmt"], &sources);
+
+    assert_eq!(code, EXIT_SUCCESS);
+    assert_eq!(
+        out,
+        "CREATE TABLE foo (\n    id integer NOT NULL\n);\nDROP TABLE IF EXISTS foo;\n"
+    );
+}
+
+#[test]
+pub fn command_convert() {
+    let sources = [Source::new(
+        "schema.sql",
+        "CREATE TABLE `account` (id INT UNSIGNED NOT NULL AUTO_INCREMENT, payload LONGBLOB);",
+    )];
+
+    let (code, out, err) = execute(
+        &["convert", "--from", "mysql", "--to", "postgres"],
+        &sources,
+    );
+
+    assert_eq!(code, EXIT_SUCCESS, "{}", err);
+    assert_eq!(
+        out,
+        "CREATE TABLE account (\n    id integer NOT NULL GENERATED BY DEFAULT AS IDENTITY,\n    payload bytea\n);\n"
+    );
+}
+
+#[test]
+pub fn command_parse_error() {
+    let sources = [Source::new("a.sql", "DROP TABLE;")];
+
+    let (code, out, err) = execute(&["fmt"], &sources);
+
+    assert_eq!(code, EXIT_FAILURE);
+    assert!(out.is_empty());
+    assert!(err.starts_with("a.sql:1:"));
+}
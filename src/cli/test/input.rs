@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use crate::cli::predule::{read_sources_from, Source};
+
+// 테스트마다 비어있는 임시 디렉터리를 만듭니다.
+fn temp_dir(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("ddl-parser-{}-{}", name, std::process::id()));
+
+    let _ = std::fs::remove_dir_all(&path);
+    std::fs::create_dir_all(&path).unwrap();
+
+    path
+}
+
+fn write(path: &PathBuf, text: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, text).unwrap();
+}
+
+#[test]
+pub fn input_directory() {
+    let dir = temp_dir("input-directory");
+
+    write(&dir.join("b.sql"), "CREATE TABLE b (id INTEGER);");
+    write(&dir.join("a.SQL"), "CREATE TABLE a (id INTEGER);");
+    write(&dir.join("notes.txt"), "not sql");
+    write(&dir.join("c/d.sql"), "CREATE TABLE d (id INTEGER);");
+    write(&dir.join("c/e.md"), "not sql");
+
+    let paths = [dir.display().to_string()];
+    let sources = read_sources_from(&paths, &mut Cursor::new("")).unwrap();
+
+    assert_eq!(
+        sources,
+        vec![
+            Source::new(
+                dir.join("a.SQL").display().to_string(),
+                "CREATE TABLE a (id INTEGER);"
+            ),
+            Source::new(
+                dir.join("b.sql").display().to_string(),
+                "CREATE TABLE b (id INTEGER);"
+            ),
+            Source::new(
+                dir.join("c/d.sql").display().to_string(),
+                "CREATE TABLE d (id INTEGER);"
+            ),
+        ]
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+pub fn input_file_and_stdin() {
+    let dir = temp_dir("input-file-and-stdin");
+
+    // 디렉터리가 아닌 경로는 확장자와 관계없이 읽습니다.
+    write(&dir.join("schema.txt"), "CREATE TABLE a (id INTEGER);");
+
+    let paths = ["-".to_owned(), dir.join("schema.txt").display().to_string()];
+    let sources =
+        read_sources_from(&paths, &mut Cursor::new("CREATE TABLE b (id INTEGER);")).unwrap();
+
+    assert_eq!(
+        sources,
+        vec![
+            Source::new("<stdin>", "CREATE TABLE b (id INTEGER);"),
+            Source::new(
+                dir.join("schema.txt").display().to_string(),
+                "CREATE TABLE a (id INTEGER);"
+            ),
+        ]
+    );
+
+    let sources = read_sources_from(&[], &mut Cursor::new("DROP TABLE a;")).unwrap();
+
+    assert_eq!(sources, vec![Source::new("<stdin>", "DROP TABLE a;")]);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+pub fn input_missing_path() {
+    let dir = temp_dir("input-missing-path");
+
+    let paths = [dir.join("missing.sql").display().to_string()];
+
+    assert!(read_sources_from(&paths, &mut Cursor::new("")).is_err());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
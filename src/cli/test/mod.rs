@@ -0,0 +1,3 @@
+pub(crate) mod arguments;
+pub(crate) mod command;
+pub(crate) mod input;
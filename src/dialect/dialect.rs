@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use crate::ast::predule::{AlterColumnAction, DataType, TableOption};
+use crate::dialect::predule::{GenericDialect, MySQLDialect, PostgreSQLDialect, SQLiteDialect};
+use crate::lexer::predule::Token;
+
+// SQL 방언
+// 데이터베이스마다 다른 인용 규칙, 예약어, 타입명, 대소문자 처리, 고유 구문을 정의합니다.
+pub trait Dialect: std::fmt::Debug + Send + Sync {
+    // 방언 이름
+    fn name(&self) -> &'static str;
+
+    // 식별자를 감싸는 따옴표인지
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        ch == '"'
+    }
+
+    // 문자열 리터럴을 감싸는 따옴표인지
+    fn is_string_quote(&self, ch: char) -> bool {
+        ch == '\''
+    }
+
+    // 문자열 안의 백슬래시 이스케이프 지원 여부 (MySQL)
+    fn supports_backslash_escapes(&self) -> bool {
+        false
+    }
+
+    // E'...' 이스케이프 문자열 지원 여부 (PostgreSQL)
+    fn supports_escape_strings(&self) -> bool {
+        false
+    }
+
+    // $$...$$, $tag$...$tag$ 달러 인용 문자열 지원 여부 (PostgreSQL)
+    fn supports_dollar_quoted_strings(&self) -> bool {
+        false
+    }
+
+    // /* /* */ */ 중첩 블록 주석 지원 여부 (PostgreSQL)
+    fn supports_nested_comments(&self) -> bool {
+        false
+    }
+
+    // # 행 단위 주석 지원 여부 (MySQL)
+    fn supports_hash_comments(&self) -> bool {
+        false
+    }
+
+    // SQL 생성 시 식별자를 감쌀 따옴표
+    fn identifier_quote(&self) -> char {
+        '"'
+    }
+
+    // 예약어 토큰 변환
+    // 대문자로 변환된 단어를 받습니다.
+    fn keyword(&self, word: &str) -> Option<Token> {
+        Token::from_keyword(word)
+    }
+
+    // 토큰으로 구분하지 않지만 방언에서 식별자로 쓸 수 없는 예약어인지
+    // 대문자로 변환된 단어를 받으며, SQL 생성 시 따옴표로 감쌀지 결정합니다.
+    fn is_reserved_word(&self, _word: &str) -> bool {
+        false
+    }
+
+    // 따옴표로 감싸지 않은 식별자의 대소문자 변환
+    fn fold_identifier(&self, identifier: String) -> String {
+        identifier
+    }
+
+    // 방언 고유의 인자 없는 타입명 변환
+    // 대문자로 변환된 타입명을 받으며, None일 경우 공통 규칙을 따릅니다.
+    fn data_type(&self, _type_name: &str) -> Option<DataType> {
+        None
+    }
+
+    // 테이블 정의 뒤의 옵션 지원 여부
+    // ENGINE=InnoDB (MySQL), WITHOUT ROWID (SQLite) 등
+    fn supports_table_options(&self) -> bool {
+        false
+    }
+
+    // SQL 생성 시 출력할 수 있는 테이블 옵션인지
+    // 다른 방언의 옵션은 생략합니다.
+    fn supports_table_option(&self, _option: &TableOption) -> bool {
+        true
+    }
+
+    // ALTER TABLE ... option_name [=] value 로 테이블 옵션을 바꿀 수 있는지 (MySQL)
+    fn supports_alter_table_options(&self) -> bool {
+        false
+    }
+
+    // ALTER TABLE ... MODIFY [COLUMN] column_definition 지원 여부 (MySQL)
+    fn supports_modify_column(&self) -> bool {
+        false
+    }
+
+    // ALTER TABLE ... DROP INDEX index_name 지원 여부 (MySQL)
+    // 지원하지 않는 방언은 DROP INDEX 문을 사용합니다.
+    fn supports_alter_table_drop_index(&self) -> bool {
+        false
+    }
+
+    // CREATE TABLE 안의 {INDEX | KEY} index_name (column_name, ...) 정의 지원 여부 (MySQL)
+    // 지원하지 않는 방언은 CREATE INDEX 문을 사용합니다.
+    fn supports_inline_index(&self) -> bool {
+        false
+    }
+
+    // CREATE INDEX ... ON table_name USING method 지원 여부 (PostgreSQL)
+    fn supports_index_method(&self) -> bool {
+        false
+    }
+
+    // ALTER TABLE ONLY table_name 지원 여부 (PostgreSQL)
+    // 상속받은 테이블을 제외하고 해당 테이블만 바꿉니다.
+    fn supports_alter_table_only(&self) -> bool {
+        false
+    }
+
+    // 배열 타입(text[] 등) 지원 여부 (PostgreSQL)
+    // 지원하지 않는 방언은 json 타입으로 출력합니다.
+    fn supports_array_types(&self) -> bool {
+        false
+    }
+
+    // ALTER TABLE ... DROP PRIMARY KEY 지원 여부 (MySQL)
+    // 지원하지 않는 방언은 DROP CONSTRAINT constraint_name을 사용합니다.
+    fn supports_alter_table_drop_primary_key(&self) -> bool {
+        false
+    }
+
+    // ALTER TABLE ... ALTER COLUMN column_name action 으로 표현할 수 있는 변경인지
+    // MODIFY COLUMN을 지원하는 방언은 표현할 수 없는 변경을 MODIFY COLUMN으로 대신합니다.
+    fn supports_alter_column_action(&self, _action: &AlterColumnAction) -> bool {
+        true
+    }
+
+    // 컬럼의 ON UPDATE expression 지원 여부 (MySQL)
+    fn supports_on_update(&self) -> bool {
+        false
+    }
+
+    // 컬럼 정의 안의 CHARACTER SET charset_name, CHARSET charset_name 지원 여부 (MySQL)
+    fn supports_column_charset(&self) -> bool {
+        false
+    }
+
+    // 컬럼 정의 안의 COMMENT 'comment' 지원 여부 (MySQL)
+    fn supports_column_comments(&self) -> bool {
+        false
+    }
+
+    // COMMENT ON COLUMN table_name.column_name IS 'comment' 지원 여부 (PostgreSQL)
+    // 컬럼 정의 안에 주석을 쓸 수 없는 방언은 별도의 문장으로 출력합니다.
+    fn supports_comment_on(&self) -> bool {
+        false
+    }
+
+    // GENERATED ... AS IDENTITY 컬럼 지원 여부
+    fn supports_identity_columns(&self) -> bool {
+        true
+    }
+
+    // SQL 생성 시 사용할 자동 증가 키워드
+    // None일 경우 IDENTITY 컬럼으로 대신합니다.
+    fn auto_increment_keyword(&self) -> Option<&'static str> {
+        Some("AUTO_INCREMENT")
+    }
+
+    // 자동 증가 키워드를 INTEGER PRIMARY KEY 바로 뒤에만 쓸 수 있는지 (SQLite)
+    fn auto_increment_requires_integer_primary_key(&self) -> bool {
+        false
+    }
+
+    // SQL 생성 시 사용할 타입명
+    // 방언에 없는 타입은 가장 가까운 타입으로 대체합니다.
+    fn data_type_to_sql(&self, data_type: &DataType) -> String {
+        match data_type {
+            DataType::Array(_) if !self.supports_array_types() => "json".into(),
+            _ => String::from(data_type.clone()),
+        }
+    }
+}
+
+// 이름으로 방언 객체를 찾습니다. (대소문자 무시)
+pub fn dialect_from_name(name: &str) -> Option<Arc<dyn Dialect>> {
+    match name.to_lowercase().as_str() {
+        "generic" => Some(Arc::new(GenericDialect {})),
+        "mysql" | "mariadb" => Some(Arc::new(MySQLDialect {})),
+        "postgresql" | "postgres" | "pg" => Some(Arc::new(PostgreSQLDialect {})),
+        "sqlite" => Some(Arc::new(SQLiteDialect {})),
+        _ => None,
+    }
+}
@@ -0,0 +1,72 @@
+use crate::dialect::predule::Dialect;
+use crate::lexer::predule::Token;
+
+// 특정 데이터베이스에 한정되지 않는 방언
+// 여러 방언의 문법을 최대한 허용합니다.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GenericDialect {}
+
+impl Dialect for GenericDialect {
+    fn name(&self) -> &'static str {
+        "generic"
+    }
+
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        ch == '"' || ch == '`' || ch == '['
+    }
+
+    fn supports_escape_strings(&self) -> bool {
+        true
+    }
+
+    fn supports_dollar_quoted_strings(&self) -> bool {
+        true
+    }
+
+    fn keyword(&self, word: &str) -> Option<Token> {
+        match word {
+            "AUTO_INCREMENT" | "AUTOINCREMENT" => Some(Token::AutoIncrement),
+            _ => Token::from_keyword(word),
+        }
+    }
+
+    fn supports_table_options(&self) -> bool {
+        true
+    }
+
+    fn supports_alter_table_options(&self) -> bool {
+        true
+    }
+
+    fn supports_modify_column(&self) -> bool {
+        true
+    }
+
+    fn supports_alter_table_drop_index(&self) -> bool {
+        true
+    }
+
+    fn supports_inline_index(&self) -> bool {
+        true
+    }
+
+    fn supports_index_method(&self) -> bool {
+        true
+    }
+
+    fn supports_alter_table_drop_primary_key(&self) -> bool {
+        true
+    }
+
+    fn supports_on_update(&self) -> bool {
+        true
+    }
+
+    fn supports_column_charset(&self) -> bool {
+        true
+    }
+
+    fn supports_column_comments(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,7 @@
+#[allow(clippy::module_inception)]
+pub mod dialect;
+pub mod generic;
+pub mod mysql;
+pub mod postgresql;
+pub mod predule;
+pub mod sqlite;
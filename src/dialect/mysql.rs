@@ -0,0 +1,387 @@
+use crate::ast::predule::{AlterColumnAction, DataType};
+use crate::dialect::predule::Dialect;
+use crate::lexer::predule::Token;
+
+// MySQL 예약어
+// https://dev.mysql.com/doc/refman/8.0/en/keywords.html
+const RESERVED_WORDS: &[&str] = &[
+    "ACCESSIBLE",
+    "ADD",
+    "ALL",
+    "ALTER",
+    "ANALYZE",
+    "AND",
+    "AS",
+    "ASC",
+    "ASENSITIVE",
+    "BEFORE",
+    "BETWEEN",
+    "BIGINT",
+    "BINARY",
+    "BLOB",
+    "BOTH",
+    "BY",
+    "CALL",
+    "CASCADE",
+    "CASE",
+    "CHANGE",
+    "CHAR",
+    "CHARACTER",
+    "CHECK",
+    "COLLATE",
+    "COLUMN",
+    "CONDITION",
+    "CONSTRAINT",
+    "CONTINUE",
+    "CONVERT",
+    "CREATE",
+    "CROSS",
+    "CUBE",
+    "CUME_DIST",
+    "CURRENT_DATE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "CURSOR",
+    "DATABASE",
+    "DATABASES",
+    "DAY_HOUR",
+    "DAY_MICROSECOND",
+    "DAY_MINUTE",
+    "DAY_SECOND",
+    "DEC",
+    "DECIMAL",
+    "DECLARE",
+    "DEFAULT",
+    "DELAYED",
+    "DELETE",
+    "DENSE_RANK",
+    "DESC",
+    "DESCRIBE",
+    "DETERMINISTIC",
+    "DISTINCT",
+    "DISTINCTROW",
+    "DIV",
+    "DOUBLE",
+    "DROP",
+    "DUAL",
+    "EACH",
+    "ELSE",
+    "ELSEIF",
+    "EMPTY",
+    "ENCLOSED",
+    "ESCAPED",
+    "EXCEPT",
+    "EXISTS",
+    "EXIT",
+    "EXPLAIN",
+    "FALSE",
+    "FETCH",
+    "FIRST_VALUE",
+    "FLOAT",
+    "FLOAT4",
+    "FLOAT8",
+    "FOR",
+    "FORCE",
+    "FOREIGN",
+    "FROM",
+    "FULLTEXT",
+    "FUNCTION",
+    "GENERATED",
+    "GET",
+    "GRANT",
+    "GROUP",
+    "GROUPING",
+    "GROUPS",
+    "HAVING",
+    "HIGH_PRIORITY",
+    "HOUR_MICROSECOND",
+    "HOUR_MINUTE",
+    "HOUR_SECOND",
+    "IF",
+    "IGNORE",
+    "IN",
+    "INDEX",
+    "INFILE",
+    "INNER",
+    "INOUT",
+    "INSENSITIVE",
+    "INSERT",
+    "INT",
+    "INT1",
+    "INT2",
+    "INT3",
+    "INT4",
+    "INT8",
+    "INTEGER",
+    "INTERSECT",
+    "INTERVAL",
+    "INTO",
+    "IO_AFTER_GTIDS",
+    "IO_BEFORE_GTIDS",
+    "IS",
+    "ITERATE",
+    "JOIN",
+    "JSON_TABLE",
+    "KEY",
+    "KEYS",
+    "KILL",
+    "LAG",
+    "LAST_VALUE",
+    "LATERAL",
+    "LEAD",
+    "LEADING",
+    "LEAVE",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "LINEAR",
+    "LINES",
+    "LOAD",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "LOCK",
+    "LONG",
+    "LONGBLOB",
+    "LONGTEXT",
+    "LOOP",
+    "LOW_PRIORITY",
+    "MASTER_BIND",
+    "MASTER_SSL_VERIFY_SERVER_CERT",
+    "MATCH",
+    "MAXVALUE",
+    "MEDIUMBLOB",
+    "MEDIUMINT",
+    "MEDIUMTEXT",
+    "MIDDLEINT",
+    "MINUTE_MICROSECOND",
+    "MINUTE_SECOND",
+    "MOD",
+    "MODIFIES",
+    "NATURAL",
+    "NOT",
+    "NO_WRITE_TO_BINLOG",
+    "NTH_VALUE",
+    "NTILE",
+    "NULL",
+    "NUMERIC",
+    "OF",
+    "ON",
+    "OPTIMIZE",
+    "OPTIMIZER_COSTS",
+    "OPTION",
+    "OPTIONALLY",
+    "OR",
+    "ORDER",
+    "OUT",
+    "OUTER",
+    "OUTFILE",
+    "OVER",
+    "PARTITION",
+    "PERCENT_RANK",
+    "PRECISION",
+    "PRIMARY",
+    "PROCEDURE",
+    "PURGE",
+    "RANGE",
+    "RANK",
+    "READ",
+    "READS",
+    "READ_WRITE",
+    "REAL",
+    "RECURSIVE",
+    "REFERENCES",
+    "REGEXP",
+    "RELEASE",
+    "RENAME",
+    "REPEAT",
+    "REPLACE",
+    "REQUIRE",
+    "RESIGNAL",
+    "RESTRICT",
+    "RETURN",
+    "REVOKE",
+    "RIGHT",
+    "RLIKE",
+    "ROW",
+    "ROWS",
+    "ROW_NUMBER",
+    "SCHEMA",
+    "SCHEMAS",
+    "SECOND_MICROSECOND",
+    "SELECT",
+    "SENSITIVE",
+    "SEPARATOR",
+    "SET",
+    "SHOW",
+    "SIGNAL",
+    "SMALLINT",
+    "SPATIAL",
+    "SPECIFIC",
+    "SQL",
+    "SQLEXCEPTION",
+    "SQLSTATE",
+    "SQLWARNING",
+    "SQL_BIG_RESULT",
+    "SQL_CALC_FOUND_ROWS",
+    "SQL_SMALL_RESULT",
+    "SSL",
+    "STARTING",
+    "STORED",
+    "STRAIGHT_JOIN",
+    "SYSTEM",
+    "TABLE",
+    "TERMINATED",
+    "THEN",
+    "TINYBLOB",
+    "TINYINT",
+    "TINYTEXT",
+    "TO",
+    "TRAILING",
+    "TRIGGER",
+    "TRUE",
+    "UNDO",
+    "UNION",
+    "UNIQUE",
+    "UNLOCK",
+    "UNSIGNED",
+    "UPDATE",
+    "USAGE",
+    "USE",
+    "USING",
+    "UTC_DATE",
+    "UTC_TIME",
+    "UTC_TIMESTAMP",
+    "VALUES",
+    "VARBINARY",
+    "VARCHAR",
+    "VARCHARACTER",
+    "VARYING",
+    "VIRTUAL",
+    "WHEN",
+    "WHERE",
+    "WHILE",
+    "WINDOW",
+    "WITH",
+    "WRITE",
+    "XOR",
+    "YEAR_MONTH",
+    "ZEROFILL",
+];
+
+// MySQL, MariaDB 방언
+// 식별자는 `로 감싸며, 큰따옴표는 문자열로 취급합니다.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MySQLDialect {}
+
+impl Dialect for MySQLDialect {
+    fn name(&self) -> &'static str {
+        "mysql"
+    }
+
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        ch == '`'
+    }
+
+    fn is_string_quote(&self, ch: char) -> bool {
+        ch == '\'' || ch == '"'
+    }
+
+    fn supports_backslash_escapes(&self) -> bool {
+        true
+    }
+
+    fn supports_hash_comments(&self) -> bool {
+        true
+    }
+
+    fn identifier_quote(&self) -> char {
+        '`'
+    }
+
+    fn keyword(&self, word: &str) -> Option<Token> {
+        match word {
+            "AUTO_INCREMENT" => Some(Token::AutoIncrement),
+            _ => Token::from_keyword(word),
+        }
+    }
+
+    fn data_type(&self, type_name: &str) -> Option<DataType> {
+        match type_name {
+            // SERIAL은 BIGINT UNSIGNED의 별칭
+            "SERIAL" => Some(DataType::Unsigned(Box::new(DataType::BigInt(None)))),
+            _ => None,
+        }
+    }
+
+    fn supports_table_options(&self) -> bool {
+        true
+    }
+
+    fn supports_alter_table_options(&self) -> bool {
+        true
+    }
+
+    fn supports_modify_column(&self) -> bool {
+        true
+    }
+
+    fn supports_alter_table_drop_index(&self) -> bool {
+        true
+    }
+
+    fn supports_inline_index(&self) -> bool {
+        true
+    }
+
+    fn supports_alter_table_drop_primary_key(&self) -> bool {
+        true
+    }
+
+    // ALTER COLUMN으로는 기본값만 바꿀 수 있습니다.
+    fn supports_alter_column_action(&self, action: &AlterColumnAction) -> bool {
+        matches!(
+            action,
+            AlterColumnAction::AlterColumnSetDefault(_)
+                | AlterColumnAction::AlterColumnDropDefault(_)
+        )
+    }
+
+    fn supports_on_update(&self) -> bool {
+        true
+    }
+
+    fn supports_column_charset(&self) -> bool {
+        true
+    }
+
+    fn supports_column_comments(&self) -> bool {
+        true
+    }
+
+    fn is_reserved_word(&self, word: &str) -> bool {
+        RESERVED_WORDS.contains(&word)
+    }
+
+    fn supports_identity_columns(&self) -> bool {
+        false
+    }
+
+    fn data_type_to_sql(&self, data_type: &DataType) -> String {
+        match data_type {
+            DataType::Bytea => "longblob".into(),
+            DataType::Uuid => "char(36)".into(),
+            DataType::Jsonb | DataType::Array(_) => "json".into(),
+            DataType::Xml => "text".into(),
+            DataType::Time { precision, .. } => String::from(DataType::Time {
+                precision: *precision,
+                with_time_zone: false,
+            }),
+            DataType::Timestamp { precision, .. } => String::from(DataType::Timestamp {
+                precision: *precision,
+                with_time_zone: false,
+            }),
+            _ => String::from(data_type.clone()),
+        }
+    }
+}
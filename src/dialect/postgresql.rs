@@ -0,0 +1,183 @@
+use crate::ast::predule::DataType;
+use crate::dialect::predule::Dialect;
+
+// PostgreSQL 예약어
+// https://www.postgresql.org/docs/current/sql-keywords-appendix.html
+const RESERVED_WORDS: &[&str] = &[
+    "ALL",
+    "ANALYSE",
+    "ANALYZE",
+    "AND",
+    "ANY",
+    "ARRAY",
+    "AS",
+    "ASC",
+    "ASYMMETRIC",
+    "AUTHORIZATION",
+    "BINARY",
+    "BOTH",
+    "CASE",
+    "CAST",
+    "CHECK",
+    "COLLATE",
+    "COLLATION",
+    "COLUMN",
+    "CONCURRENTLY",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "CURRENT_CATALOG",
+    "CURRENT_DATE",
+    "CURRENT_ROLE",
+    "CURRENT_SCHEMA",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "DEFAULT",
+    "DEFERRABLE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FOREIGN",
+    "FREEZE",
+    "FROM",
+    "FULL",
+    "GRANT",
+    "GROUP",
+    "HAVING",
+    "ILIKE",
+    "IN",
+    "INITIALLY",
+    "INNER",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "ISNULL",
+    "JOIN",
+    "LATERAL",
+    "LEADING",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "NATURAL",
+    "NOT",
+    "NOTNULL",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "ONLY",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVERLAPS",
+    "PLACING",
+    "PRIMARY",
+    "REFERENCES",
+    "RETURNING",
+    "RIGHT",
+    "SELECT",
+    "SESSION_USER",
+    "SIMILAR",
+    "SOME",
+    "SYMMETRIC",
+    "SYSTEM_USER",
+    "TABLE",
+    "TABLESAMPLE",
+    "THEN",
+    "TO",
+    "TRAILING",
+    "TRUE",
+    "UNION",
+    "UNIQUE",
+    "USER",
+    "USING",
+    "VARIADIC",
+    "VERBOSE",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+// PostgreSQL 방언
+// 따옴표로 감싸지 않은 식별자는 소문자로 변환됩니다.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PostgreSQLDialect {}
+
+impl Dialect for PostgreSQLDialect {
+    fn name(&self) -> &'static str {
+        "postgresql"
+    }
+
+    fn fold_identifier(&self, identifier: String) -> String {
+        identifier.to_lowercase()
+    }
+
+    fn supports_escape_strings(&self) -> bool {
+        true
+    }
+
+    fn supports_dollar_quoted_strings(&self) -> bool {
+        true
+    }
+
+    fn supports_nested_comments(&self) -> bool {
+        true
+    }
+
+    fn auto_increment_keyword(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn supports_comment_on(&self) -> bool {
+        true
+    }
+
+    fn supports_index_method(&self) -> bool {
+        true
+    }
+
+    fn supports_alter_table_only(&self) -> bool {
+        true
+    }
+
+    fn supports_array_types(&self) -> bool {
+        true
+    }
+
+    fn is_reserved_word(&self, word: &str) -> bool {
+        RESERVED_WORDS.contains(&word)
+    }
+
+    fn data_type_to_sql(&self, data_type: &DataType) -> String {
+        match data_type {
+            // 정수 타입의 표시 너비는 지원하지 않음
+            DataType::TinyInt(_) | DataType::SmallInt(_) | DataType::Year(_) => "smallint".into(),
+            DataType::MediumInt(_) | DataType::Int(_) => "integer".into(),
+            DataType::BigInt(_) => "bigint".into(),
+            // 부호 없는 정수와 ZEROFILL은 지원하지 않음
+            DataType::Unsigned(data_type)
+            | DataType::Signed(data_type)
+            | DataType::Zerofill(data_type) => self.data_type_to_sql(data_type),
+            DataType::TinyText | DataType::MediumText | DataType::LongText => "text".into(),
+            DataType::Enum(_) => "text".into(),
+            DataType::Binary(_) | DataType::Varbinary(_) | DataType::Blob | DataType::LongBlob => {
+                "bytea".into()
+            }
+            DataType::DateTime(precision) => String::from(DataType::Timestamp {
+                precision: *precision,
+                with_time_zone: false,
+            }),
+            DataType::Array(data_type) => format!("{}[]", self.data_type_to_sql(data_type)),
+            _ => String::from(data_type.clone()),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub use super::dialect::*;
+pub use super::generic::*;
+pub use super::mysql::*;
+pub use super::postgresql::*;
+pub use super::sqlite::*;
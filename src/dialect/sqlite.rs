@@ -0,0 +1,206 @@
+use crate::ast::predule::{AlterColumnAction, TableOption};
+use crate::dialect::predule::Dialect;
+use crate::lexer::predule::Token;
+
+// SQLite 키워드
+// https://www.sqlite.org/lang_keywords.html
+const RESERVED_WORDS: &[&str] = &[
+    "ABORT",
+    "ACTION",
+    "ADD",
+    "AFTER",
+    "ALL",
+    "ALTER",
+    "ALWAYS",
+    "ANALYZE",
+    "AND",
+    "AS",
+    "ASC",
+    "ATTACH",
+    "AUTOINCREMENT",
+    "BEFORE",
+    "BEGIN",
+    "BETWEEN",
+    "BY",
+    "CASCADE",
+    "CASE",
+    "CAST",
+    "CHECK",
+    "COLLATE",
+    "COLUMN",
+    "COMMIT",
+    "CONFLICT",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "CURRENT",
+    "CURRENT_DATE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "DATABASE",
+    "DEFAULT",
+    "DEFERRABLE",
+    "DEFERRED",
+    "DELETE",
+    "DESC",
+    "DETACH",
+    "DISTINCT",
+    "DO",
+    "DROP",
+    "EACH",
+    "ELSE",
+    "END",
+    "ESCAPE",
+    "EXCEPT",
+    "EXCLUDE",
+    "EXCLUSIVE",
+    "EXISTS",
+    "EXPLAIN",
+    "FAIL",
+    "FILTER",
+    "FIRST",
+    "FOLLOWING",
+    "FOR",
+    "FOREIGN",
+    "FROM",
+    "FULL",
+    "GENERATED",
+    "GLOB",
+    "GROUP",
+    "GROUPS",
+    "HAVING",
+    "IF",
+    "IGNORE",
+    "IMMEDIATE",
+    "IN",
+    "INDEX",
+    "INDEXED",
+    "INITIALLY",
+    "INNER",
+    "INSERT",
+    "INSTEAD",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "ISNULL",
+    "JOIN",
+    "KEY",
+    "LAST",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "MATCH",
+    "MATERIALIZED",
+    "NATURAL",
+    "NO",
+    "NOT",
+    "NOTHING",
+    "NOTNULL",
+    "NULL",
+    "NULLS",
+    "OF",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OTHERS",
+    "OUTER",
+    "OVER",
+    "PARTITION",
+    "PLAN",
+    "PRAGMA",
+    "PRECEDING",
+    "PRIMARY",
+    "QUERY",
+    "RAISE",
+    "RANGE",
+    "RECURSIVE",
+    "REFERENCES",
+    "REGEXP",
+    "REINDEX",
+    "RELEASE",
+    "RENAME",
+    "REPLACE",
+    "RESTRICT",
+    "RETURNING",
+    "RIGHT",
+    "ROLLBACK",
+    "ROW",
+    "ROWS",
+    "SAVEPOINT",
+    "SELECT",
+    "SET",
+    "TABLE",
+    "TEMP",
+    "TEMPORARY",
+    "THEN",
+    "TIES",
+    "TO",
+    "TRANSACTION",
+    "TRIGGER",
+    "UNBOUNDED",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USING",
+    "VACUUM",
+    "VALUES",
+    "VIEW",
+    "VIRTUAL",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+    "WITHOUT",
+];
+
+// SQLite 방언
+// 식별자는 ", ` 혹은 []로 감쌀 수 있습니다.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SQLiteDialect {}
+
+impl Dialect for SQLiteDialect {
+    fn name(&self) -> &'static str {
+        "sqlite"
+    }
+
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        ch == '"' || ch == '`' || ch == '['
+    }
+
+    fn keyword(&self, word: &str) -> Option<Token> {
+        match word {
+            "AUTOINCREMENT" => Some(Token::AutoIncrement),
+            _ => Token::from_keyword(word),
+        }
+    }
+
+    fn supports_identity_columns(&self) -> bool {
+        false
+    }
+
+    // ALTER COLUMN 구문이 없습니다.
+    fn supports_alter_column_action(&self, _action: &AlterColumnAction) -> bool {
+        false
+    }
+
+    fn supports_table_options(&self) -> bool {
+        true
+    }
+
+    fn supports_table_option(&self, option: &TableOption) -> bool {
+        option.value.is_none() && matches!(option.name.as_str(), "WITHOUT ROWID" | "STRICT")
+    }
+
+    fn auto_increment_keyword(&self) -> Option<&'static str> {
+        Some("AUTOINCREMENT")
+    }
+
+    fn auto_increment_requires_integer_primary_key(&self) -> bool {
+        true
+    }
+
+    fn is_reserved_word(&self, word: &str) -> bool {
+        RESERVED_WORDS.contains(&word)
+    }
+}
@@ -0,0 +1,120 @@
+use crate::lexer::predule::{Span, Token};
+
+// 라이브러리 전체에서 사용하는 오류 타입
+// 어느 단계에서 실패했는지에 따라 구분되며, 상세 정보는 ErrorKind에 담깁니다.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    Lexing(Box<ErrorKind>),
+    Parsing(Box<ErrorKind>),
+    Into(Box<ErrorKind>),
+    Type(Box<ErrorKind>),
+    Execute(Box<ErrorKind>),
+}
+
+// 오류 상세 정보
+// code는 E0000 형태의 고정된 오류 코드입니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorKind {
+    pub code: &'static str,
+    pub message: String,
+    pub expected: Vec<String>,
+    pub found: Option<Token>,
+    pub span: Option<Span>,
+}
+
+impl ErrorKind {
+    pub fn new<T: ToString>(code: &'static str, message: T) -> Self {
+        ErrorKind {
+            code,
+            message: message.to_string(),
+            expected: vec![],
+            found: None,
+            span: None,
+        }
+    }
+
+    pub fn with_expected(mut self, expected: &[&str]) -> Self {
+        self.expected = expected.iter().map(|word| word.to_string()).collect();
+        self
+    }
+
+    pub fn with_found(mut self, found: Token) -> Self {
+        self.found = Some(found);
+        self
+    }
+
+    // 오류가 발생한 위치를 지정합니다.
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+}
+
+impl Error {
+    pub fn lexing<T: ToString>(code: &'static str, message: T) -> Self {
+        Error::Lexing(Box::new(ErrorKind::new(code, message)))
+    }
+
+    pub fn parsing<T: ToString>(code: &'static str, message: T) -> Self {
+        Error::Parsing(Box::new(ErrorKind::new(code, message)))
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        match self {
+            Error::Lexing(kind)
+            | Error::Parsing(kind)
+            | Error::Into(kind)
+            | Error::Type(kind)
+            | Error::Execute(kind) => kind,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.kind().code
+    }
+
+    pub fn span(&self) -> Option<Span> {
+        self.kind().span
+    }
+
+    // 위치 정보가 없을 경우에만 지정합니다. 빈 구간은 지정하지 않습니다.
+    pub fn or_span(mut self, span: Span) -> Self {
+        let kind = match &mut self {
+            Error::Lexing(kind)
+            | Error::Parsing(kind)
+            | Error::Into(kind)
+            | Error::Type(kind)
+            | Error::Execute(kind) => kind,
+        };
+
+        if kind.span.is_none() && !span.is_empty() {
+            kind.span = Some(span);
+        }
+
+        self
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let stage = match self {
+            Error::Lexing(_) => "lexing",
+            Error::Parsing(_) => "parsing",
+            Error::Into(_) => "conversion",
+            Error::Type(_) => "type",
+            Error::Execute(_) => "execute",
+        };
+
+        let kind = self.kind();
+
+        write!(formatter, "{} error: {} {}", stage, kind.code, kind.message)?;
+
+        if let Some(span) = &kind.span {
+            write!(formatter, " (at {})", span)?;
+        }
+
+        Ok(())
+    }
+}
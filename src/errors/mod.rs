@@ -1,7 +1,3 @@
-pub mod execute_error;
-pub mod into_error;
-pub mod lexing_error;
-pub mod parsing_error;
+#[allow(clippy::module_inception)]
+pub mod error;
 pub mod predule;
-pub mod server_error;
-pub mod type_error;
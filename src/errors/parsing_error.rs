@@ -1,26 +0,0 @@
-use std::string::ToString;
-
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct ParsingError {
-    pub message: String,
-}
-
-impl ParsingError {
-    pub fn new<T: ToString>(message: T) -> Self {
-        Self {
-            message: message.to_string(),
-        }
-    }
-
-    pub fn boxed<T: ToString>(message: T) -> Box<Self> {
-        Box::new(Self::new(message))
-    }
-}
-
-impl std::error::Error for ParsingError {}
-
-impl std::fmt::Display for ParsingError {
-    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(formatter, "parsing error: {}", self.message)
-    }
-}
@@ -1,6 +1 @@
-pub use super::execute_error::*;
-pub use super::into_error::*;
-pub use super::lexing_error::*;
-pub use super::parsing_error::*;
-pub use super::server_error::*;
-pub use super::type_error::*;
+pub use super::error::*;
@@ -1,26 +0,0 @@
-use std::string::ToString;
-
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct ServerError {
-    pub message: String,
-}
-
-impl ServerError {
-    pub fn new<T: ToString>(message: T) -> Self {
-        Self {
-            message: message.to_string(),
-        }
-    }
-
-    pub fn boxed<T: ToString>(message: T) -> Box<Self> {
-        Box::new(Self::new(message))
-    }
-}
-
-impl std::error::Error for ServerError {}
-
-impl std::fmt::Display for ServerError {
-    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(formatter, "server error: {}", self.message)
-    }
-}
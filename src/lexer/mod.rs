@@ -1,5 +1,6 @@
 pub mod operator_token;
 pub mod predule;
+pub mod span;
 pub mod tokenizer;
 pub mod tokens;
 
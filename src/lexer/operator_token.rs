@@ -1,21 +1,30 @@
-use std::{convert::TryInto, error::Error};
+use std::convert::TryInto;
 
 use crate::ast::predule::{BinaryOperator, UnaryOperator};
-use crate::errors::predule::IntoError;
+use crate::errors::predule::{Error, ErrorKind};
+use crate::lexer::predule::Token;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OperatorToken {
-    Plus,     // +
-    Minus,    // -
-    Asterisk, // *
-    Slash,    // /
-    Lt,       // A < B
-    Gt,       // A > B
-    Lte,      // A <= B
-    Gte,      // A >= B
-    Eq,       // A = B
-    Neq,      // A != B, A <> B
-    Not,      // !A
+    Plus,       // +
+    Minus,      // -
+    Asterisk,   // *
+    Slash,      // /
+    Lt,         // A < B
+    Gt,         // A > B
+    Lte,        // A <= B
+    Gte,        // A >= B
+    Eq,         // A = B
+    Neq,        // A != B, A <> B
+    Not,        // !A
+    Percent,    // A % B
+    Caret,      // A ^ B
+    Ampersand,  // A & B
+    Pipe,       // A | B
+    Concat,     // A || B
+    ShiftLeft,  // A << B
+    ShiftRight, // A >> B
+    Tilde,      // ~A
 }
 
 impl OperatorToken {
@@ -31,17 +40,24 @@ impl OperatorToken {
             Self::Gte,
             Self::Eq,
             Self::Neq,
+            Self::Percent,
+            Self::Caret,
+            Self::Ampersand,
+            Self::Pipe,
+            Self::Concat,
+            Self::ShiftLeft,
+            Self::ShiftRight,
         ]
         .contains(self)
     }
 
     pub fn is_unary_operator(&self) -> bool {
-        [Self::Plus, Self::Minus, Self::Not].contains(self)
+        [Self::Plus, Self::Minus, Self::Not, Self::Tilde].contains(self)
     }
 }
 
 impl TryInto<BinaryOperator> for OperatorToken {
-    type Error = Box<dyn Error + Send>;
+    type Error = Error;
 
     fn try_into(self) -> Result<BinaryOperator, Self::Error> {
         match self {
@@ -55,20 +71,34 @@ impl TryInto<BinaryOperator> for OperatorToken {
             Self::Gte => Ok(BinaryOperator::Gte),
             Self::Eq => Ok(BinaryOperator::Eq),
             Self::Neq => Ok(BinaryOperator::Neq),
-            _ => Err(IntoError::boxed("BinaryOperator Cast Error")),
+            Self::Percent => Ok(BinaryOperator::Mod),
+            Self::Caret => Ok(BinaryOperator::Caret),
+            Self::Ampersand => Ok(BinaryOperator::BitAnd),
+            Self::Pipe => Ok(BinaryOperator::BitOr),
+            Self::Concat => Ok(BinaryOperator::Concat),
+            Self::ShiftLeft => Ok(BinaryOperator::ShiftLeft),
+            Self::ShiftRight => Ok(BinaryOperator::ShiftRight),
+            _ => Err(Error::Into(Box::new(
+                ErrorKind::new("E0901", "BinaryOperator Cast Error")
+                    .with_found(Token::Operator(self)),
+            ))),
         }
     }
 }
 
 impl TryInto<UnaryOperator> for OperatorToken {
-    type Error = Box<dyn Error + Send>;
+    type Error = Error;
 
     fn try_into(self) -> Result<UnaryOperator, Self::Error> {
         match self {
             Self::Plus => Ok(UnaryOperator::Pos),
             Self::Minus => Ok(UnaryOperator::Neg),
             Self::Not => Ok(UnaryOperator::Not),
-            _ => Err(IntoError::boxed("UnaryOperator Cast Error")),
+            Self::Tilde => Ok(UnaryOperator::BitNot),
+            _ => Err(Error::Into(Box::new(
+                ErrorKind::new("E0902", "UnaryOperator Cast Error")
+                    .with_found(Token::Operator(self)),
+            ))),
         }
     }
 }
@@ -87,6 +117,14 @@ impl std::fmt::Display for OperatorToken {
             Self::Eq => "=",
             Self::Neq => "!=",
             Self::Not => "!",
+            Self::Percent => "%",
+            Self::Caret => "^",
+            Self::Ampersand => "&",
+            Self::Pipe => "|",
+            Self::Concat => "||",
+            Self::ShiftLeft => "<<",
+            Self::ShiftRight => ">>",
+            Self::Tilde => "~",
         };
         write!(f, "{}", text)
     }
@@ -1,3 +1,4 @@
 pub use super::operator_token::*;
+pub use super::span::*;
 pub use super::tokenizer::*;
 pub use super::tokens::*;
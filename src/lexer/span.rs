@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+
+use crate::lexer::predule::Token;
+
+// 원본 SQL 내의 위치
+// offset은 바이트 단위, line과 column은 1부터 시작합니다.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Location {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub fn new(offset: usize, line: usize, column: usize) -> Self {
+        Location {
+            offset,
+            line,
+            column,
+        }
+    }
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "line {}, column {}", self.line, self.column)
+    }
+}
+
+// 원본 SQL 내의 구간 [start, end)
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub start: Location,
+    pub end: Location,
+}
+
+impl Span {
+    pub fn new(start: Location, end: Location) -> Self {
+        Span { start, end }
+    }
+
+    // 두 구간을 모두 포함하는 구간
+    pub fn union(&self, other: &Span) -> Span {
+        if self.is_empty() {
+            return *other;
+        }
+
+        if other.is_empty() {
+            return *self;
+        }
+
+        Span {
+            start: if self.start.offset <= other.start.offset {
+                self.start
+            } else {
+                other.start
+            },
+            end: if self.end.offset >= other.end.offset {
+                self.end
+            } else {
+                other.end
+            },
+        }
+    }
+
+    // 위치 정보가 없는 구간인지 (직접 생성한 AST 등)
+    pub fn is_empty(&self) -> bool {
+        self.start.line == 0
+    }
+}
+
+impl std::fmt::Display for Span {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "{}", self.start)
+    }
+}
+
+// AST 노드에 붙는 원본 SQL 내의 구간
+// 같은 구문이라도 위치는 다를 수 있으므로, AST 노드끼리 비교할 때는 위치가 달라도 같은 노드로 취급합니다.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default)]
+#[serde(transparent)]
+pub struct NodeSpan(pub Span);
+
+impl PartialEq for NodeSpan {
+    fn eq(&self, _other: &NodeSpan) -> bool {
+        true
+    }
+}
+
+impl Eq for NodeSpan {}
+
+impl std::hash::Hash for NodeSpan {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
+impl std::ops::Deref for NodeSpan {
+    type Target = Span;
+
+    fn deref(&self) -> &Span {
+        &self.0
+    }
+}
+
+impl From<Span> for NodeSpan {
+    fn from(span: Span) -> NodeSpan {
+        NodeSpan(span)
+    }
+}
+
+impl From<NodeSpan> for Span {
+    fn from(span: NodeSpan) -> Span {
+        span.0
+    }
+}
+
+// 위치 정보가 붙은 토큰
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenWithSpan {
+    pub token: Token,
+    pub span: Span,
+}
+
+impl TokenWithSpan {
+    pub fn new(token: Token, span: Span) -> Self {
+        TokenWithSpan { token, span }
+    }
+}
+
+impl From<Token> for TokenWithSpan {
+    fn from(token: Token) -> TokenWithSpan {
+        TokenWithSpan::new(token, Span::default())
+    }
+}
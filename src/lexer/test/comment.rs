@@ -1,5 +1,10 @@
-#[cfg(test)]
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::dialect::predule::{GenericDialect, MySQLDialect, PostgreSQLDialect};
 use crate::lexer::predule::{Token, Tokenizer};
+use crate::lexer::test::{error_code, tokens};
 
 #[test]
 pub fn comment_1() {
@@ -32,3 +37,104 @@ pub fn comment_2() {
         ]
     );
 }
+
+#[test]
+pub fn nested_block_comment() {
+    let text = "/* a /* b */ c */ 1 /* x * y / z **/";
+
+    assert_eq!(
+        tokens(text, Arc::new(PostgreSQLDialect {})),
+        vec![
+            Token::CodeComment(" a /* b */ c ".to_owned()),
+            Token::Integer(1),
+            Token::CodeComment(" x * y / z *".to_owned()),
+        ]
+    );
+
+    // 중첩을 지원하지 않는 방언에서는 첫번째 */에서 끝납니다.
+    assert_eq!(
+        tokens("/* a /* b */ 1", Arc::new(MySQLDialect {})),
+        vec![Token::CodeComment(" a /* b ".to_owned()), Token::Integer(1),]
+    );
+    assert_eq!(
+        tokens("/* a /* b */ 1", Arc::new(GenericDialect {})),
+        vec![Token::CodeComment(" a /* b ".to_owned()), Token::Integer(1),]
+    );
+}
+
+#[test]
+pub fn unterminated_block_comment() {
+    assert_eq!(
+        error_code("SELECT /* a", Arc::new(GenericDialect {})),
+        "E0310"
+    );
+    assert_eq!(
+        error_code("SELECT /* a *", Arc::new(MySQLDialect {})),
+        "E0310"
+    );
+    assert_eq!(
+        error_code("/* a /* b */", Arc::new(PostgreSQLDialect {})),
+        "E0310"
+    );
+}
+
+#[test]
+pub fn hash_comment() {
+    assert_eq!(
+        tokens("# dump\nSELECT 1 # one", Arc::new(MySQLDialect {})),
+        vec![
+            Token::CodeComment(" dump".to_owned()),
+            Token::Select,
+            Token::Integer(1),
+            Token::CodeComment(" one".to_owned()),
+        ]
+    );
+
+    assert_eq!(
+        error_code("# dump", Arc::new(PostgreSQLDialect {})),
+        "E0304"
+    );
+    assert_eq!(error_code("# dump", Arc::new(GenericDialect {})), "E0304");
+}
+
+#[test]
+pub fn conditional_comment() {
+    let text = "SELECT /*!40101 1, */ 2 /*!*/;";
+
+    // 기본적으로는 일반 주석
+    assert_eq!(
+        tokens(text, Arc::new(MySQLDialect {})),
+        vec![
+            Token::Select,
+            Token::CodeComment("!40101 1, ".to_owned()),
+            Token::Integer(2),
+            Token::CodeComment("!".to_owned()),
+            Token::SemiColon,
+        ]
+    );
+
+    let mut tokenizer = Tokenizer::with_dialect(text.to_owned(), Arc::new(MySQLDialect {}))
+        .set_conditional_comments(true);
+
+    assert_eq!(
+        tokenizer
+            .tokenize()
+            .unwrap()
+            .into_iter()
+            .map(|token| token.token)
+            .collect::<Vec<_>>(),
+        vec![
+            Token::Select,
+            Token::Integer(1),
+            Token::Comma,
+            Token::Integer(2),
+            Token::SemiColon,
+        ]
+    );
+
+    let mut tokenizer =
+        Tokenizer::with_dialect("/*!40101 SELECT 1".to_owned(), Arc::new(MySQLDialect {}))
+            .set_conditional_comments(true);
+
+    assert_eq!(tokenizer.tokenize().unwrap_err().code(), "E0310");
+}
@@ -1,2 +1,25 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::dialect::predule::Dialect;
+use crate::lexer::predule::{Token, Tokenizer};
+
 pub(crate) mod comment;
+pub(crate) mod number;
+pub(crate) mod operator;
 pub(crate) mod select;
+pub(crate) mod span;
+pub(crate) mod string;
+
+// 주어진 방언으로 토큰 목록을 가져옵니다.
+pub(crate) fn tokens(text: &str, dialect: Arc<dyn Dialect>) -> Vec<Token> {
+    Tokenizer::string_to_tokens_with_dialect(text.to_owned(), dialect).unwrap()
+}
+
+// 토큰화에 실패한 오류 코드를 가져옵니다.
+pub(crate) fn error_code(text: &str, dialect: Arc<dyn Dialect>) -> &'static str {
+    Tokenizer::string_to_tokens_with_dialect(text.to_owned(), dialect)
+        .unwrap_err()
+        .code()
+}
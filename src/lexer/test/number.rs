@@ -0,0 +1,59 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::dialect::predule::GenericDialect;
+use crate::lexer::predule::Token;
+use crate::lexer::test::{error_code, tokens};
+
+#[test]
+pub fn number_literals() {
+    assert_eq!(
+        tokens(
+            "1e10 1.5E-3 2e+2 .5 1. 0x1F 0XfF 0b101 b'101' B'0'",
+            Arc::new(GenericDialect {})
+        ),
+        vec![
+            Token::Float(1e10),
+            Token::Float(1.5e-3),
+            Token::Float(2e2),
+            Token::Float(0.5),
+            Token::Float(1.0),
+            Token::Integer(31),
+            Token::Integer(255),
+            Token::Integer(5),
+            Token::Integer(5),
+            Token::Integer(0),
+        ]
+    );
+}
+
+#[test]
+pub fn number_followed_by_symbols() {
+    assert_eq!(
+        tokens("(1,.5);", Arc::new(GenericDialect {})),
+        vec![
+            Token::LeftParentheses,
+            Token::Integer(1),
+            Token::Comma,
+            Token::Float(0.5),
+            Token::RightParentheses,
+            Token::SemiColon,
+        ]
+    );
+}
+
+#[test]
+pub fn number_malformed() {
+    assert_eq!(error_code("1.2.3", Arc::new(GenericDialect {})), "E0305");
+    assert_eq!(error_code("1e", Arc::new(GenericDialect {})), "E0305");
+    assert_eq!(error_code("1e+", Arc::new(GenericDialect {})), "E0305");
+    assert_eq!(error_code("123abc", Arc::new(GenericDialect {})), "E0305");
+    assert_eq!(error_code("0x", Arc::new(GenericDialect {})), "E0305");
+    assert_eq!(error_code("0xZZ", Arc::new(GenericDialect {})), "E0305");
+    assert_eq!(error_code("b'102'", Arc::new(GenericDialect {})), "E0305");
+    assert_eq!(
+        error_code("99999999999999999999", Arc::new(GenericDialect {})),
+        "E0302"
+    );
+}
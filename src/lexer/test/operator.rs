@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use crate::lexer::predule::{OperatorToken, Token, Tokenizer};
+
+fn identifier(name: &str) -> Token {
+    Token::Identifier(name.to_owned())
+}
+
+#[test]
+pub fn operator_comparison() {
+    let text = r#"a != b <> c <= d >= e == f < g > h = i"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            identifier("a"),
+            Token::Operator(OperatorToken::Neq),
+            identifier("b"),
+            Token::Operator(OperatorToken::Neq),
+            identifier("c"),
+            Token::Operator(OperatorToken::Lte),
+            identifier("d"),
+            Token::Operator(OperatorToken::Gte),
+            identifier("e"),
+            Token::Operator(OperatorToken::Eq),
+            identifier("f"),
+            Token::Operator(OperatorToken::Lt),
+            identifier("g"),
+            Token::Operator(OperatorToken::Gt),
+            identifier("h"),
+            Token::Operator(OperatorToken::Eq),
+            identifier("i"),
+        ]
+    );
+}
+
+#[test]
+pub fn operator_others() {
+    let text = r#"a || b % c ^ d & e | f << g >> ~h::int !i"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            identifier("a"),
+            Token::Operator(OperatorToken::Concat),
+            identifier("b"),
+            Token::Operator(OperatorToken::Percent),
+            identifier("c"),
+            Token::Operator(OperatorToken::Caret),
+            identifier("d"),
+            Token::Operator(OperatorToken::Ampersand),
+            identifier("e"),
+            Token::Operator(OperatorToken::Pipe),
+            identifier("f"),
+            Token::Operator(OperatorToken::ShiftLeft),
+            identifier("g"),
+            Token::Operator(OperatorToken::ShiftRight),
+            Token::Operator(OperatorToken::Tilde),
+            identifier("h"),
+            Token::DoubleColon,
+            identifier("int"),
+            Token::Operator(OperatorToken::Not),
+            identifier("i"),
+        ]
+    );
+}
+
+#[test]
+pub fn operator_single_colon() {
+    let error = Tokenizer::string_to_tokens("a : b".to_owned()).unwrap_err();
+
+    assert_eq!(error.code(), "E0303");
+}
@@ -1,3 +1,6 @@
+// 3.14는 원주율이 아니라 실수 리터럴 토큰화를 확인하기 위한 입력값입니다.
+#![allow(clippy::approx_constant)]
+
 #[cfg(test)]
 use crate::lexer::predule::{Token, Tokenizer};
 
@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::dialect::predule::GenericDialect;
+use crate::errors::predule::Error;
+use crate::lexer::predule::{Location, NodeSpan, Span, Token, Tokenizer};
+
+#[test]
+pub fn span_1() {
+    let text = "CREATE TABLE foo\n(\n    id INT,\n    \"이름\" TEXT\n);".to_owned();
+
+    let tokens = Tokenizer::string_to_tokens_with_span(text, Arc::new(GenericDialect {})).unwrap();
+
+    let spans: Vec<_> = tokens
+        .iter()
+        .map(|token| (token.token.clone(), token.span.start, token.span.end))
+        .collect();
+
+    assert_eq!(
+        spans[0],
+        (
+            Token::Create,
+            Location::new(0, 1, 1),
+            Location::new(6, 1, 7)
+        )
+    );
+    assert_eq!(
+        spans[2],
+        (
+            Token::Identifier("foo".to_owned()),
+            Location::new(13, 1, 14),
+            Location::new(16, 1, 17)
+        )
+    );
+    assert_eq!(
+        spans[4],
+        (
+            Token::Identifier("id".to_owned()),
+            Location::new(23, 3, 5),
+            Location::new(25, 3, 7)
+        )
+    );
+    // 오프셋은 바이트 단위, 컬럼은 문자 단위
+    assert_eq!(
+        spans[7],
+        (
+            Token::Identifier("이름".to_owned()),
+            Location::new(35, 4, 5),
+            Location::new(43, 4, 9)
+        )
+    );
+    assert_eq!(
+        spans[8],
+        (
+            Token::Identifier("TEXT".to_owned()),
+            Location::new(44, 4, 10),
+            Location::new(48, 4, 14)
+        )
+    );
+    assert_eq!(
+        spans[10],
+        (
+            Token::SemiColon,
+            Location::new(50, 5, 2),
+            Location::new(51, 5, 3)
+        )
+    );
+}
+
+#[test]
+pub fn span_lexing_error() {
+    let text = "SELECT 1\nFROM @foo".to_owned();
+
+    let error = Tokenizer::string_to_tokens(text).unwrap_err();
+    assert!(matches!(error, Error::Lexing(_)));
+    assert_eq!(error.code(), "E0304");
+    assert_eq!(error.span().unwrap().start, Location::new(14, 2, 6));
+}
+
+#[test]
+pub fn span_equality() {
+    let first = Span::new(Location::new(0, 1, 1), Location::new(6, 1, 7));
+    let second = Span::new(Location::new(7, 1, 8), Location::new(12, 1, 13));
+
+    assert_eq!(first, first);
+    assert_ne!(first, second);
+
+    // AST 노드의 위치는 비교하지 않음
+    assert_eq!(NodeSpan::from(first), NodeSpan::from(second));
+}
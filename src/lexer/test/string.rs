@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::dialect::predule::{GenericDialect, MySQLDialect, PostgreSQLDialect, SQLiteDialect};
+use crate::lexer::predule::Token;
+use crate::lexer::test::{error_code, tokens};
+
+#[test]
+pub fn unterminated_literals() {
+    assert_eq!(
+        error_code("SELECT 'abc", Arc::new(GenericDialect {})),
+        "E0306"
+    );
+    assert_eq!(
+        error_code("SELECT 'it''s", Arc::new(GenericDialect {})),
+        "E0306"
+    );
+    assert_eq!(
+        error_code("SELECT \"abc", Arc::new(GenericDialect {})),
+        "E0307"
+    );
+    assert_eq!(
+        error_code("SELECT `abc", Arc::new(MySQLDialect {})),
+        "E0307"
+    );
+    assert_eq!(
+        error_code("SELECT [abc", Arc::new(SQLiteDialect {})),
+        "E0307"
+    );
+    assert_eq!(
+        error_code("SELECT 'abc\\", Arc::new(MySQLDialect {})),
+        "E0306"
+    );
+    assert_eq!(
+        error_code("SELECT $$abc$", Arc::new(PostgreSQLDialect {})),
+        "E0306"
+    );
+    assert_eq!(
+        error_code("SELECT b'101", Arc::new(GenericDialect {})),
+        "E0306"
+    );
+}
+
+#[test]
+pub fn escape_strings() {
+    assert_eq!(
+        tokens(
+            r"E'a\nb' e'it\'s' E'\x41\101é\\' 'a\nb'",
+            Arc::new(PostgreSQLDialect {})
+        ),
+        vec![
+            Token::String("a\nb".into()),
+            Token::String("it's".into()),
+            Token::String("AAé\\".into()),
+            Token::String("a\\nb".into()),
+        ]
+    );
+
+    assert_eq!(
+        error_code(r"E'\u00'", Arc::new(PostgreSQLDialect {})),
+        "E0308"
+    );
+    assert_eq!(error_code(r"E'\x00'", Arc::new(GenericDialect {})), "E0308");
+
+    // E'' 문자열이 없는 방언에서는 식별자 뒤의 문자열
+    assert_eq!(
+        tokens("E'a'", Arc::new(MySQLDialect {})),
+        vec![Token::Identifier("E".into()), Token::String("a".into())]
+    );
+}
+
+#[test]
+pub fn dollar_quoted_strings() {
+    assert_eq!(
+        tokens(
+            "$$it's $x$$ $body$SELECT $$1$$;$body$ $a1$$a1$",
+            Arc::new(PostgreSQLDialect {})
+        ),
+        vec![
+            Token::String("it's $x".into()),
+            Token::String("SELECT $$1$$;".into()),
+            Token::String("".into()),
+        ]
+    );
+
+    assert_eq!(error_code("$1", Arc::new(PostgreSQLDialect {})), "E0304");
+    assert_eq!(error_code("$$a$$", Arc::new(MySQLDialect {})), "E0304");
+}
+
+#[test]
+pub fn prefixed_strings() {
+    assert_eq!(
+        tokens("N'이름' n'it''s' X'1F' x'' X", Arc::new(GenericDialect {})),
+        vec![
+            Token::String("이름".into()),
+            Token::String("it's".into()),
+            Token::HexString("1F".into()),
+            Token::HexString("".into()),
+            Token::Identifier("X".into()),
+        ]
+    );
+
+    assert_eq!(error_code("X'1G'", Arc::new(GenericDialect {})), "E0309");
+    assert_eq!(error_code("X'123'", Arc::new(GenericDialect {})), "E0309");
+}
+
+#[test]
+pub fn mysql_backslash_escapes() {
+    assert_eq!(
+        tokens(
+            r#"'it\'s' "a\"b" 'a\tb\\' '100\%' 'x\qy' N'\n'"#,
+            Arc::new(MySQLDialect {})
+        ),
+        vec![
+            Token::String("it's".into()),
+            Token::String("a\"b".into()),
+            Token::String("a\tb\\".into()),
+            Token::String("100\\%".into()),
+            Token::String("xqy".into()),
+            Token::String("\n".into()),
+        ]
+    );
+}
+
+#[test]
+pub fn bracketed_identifiers() {
+    assert_eq!(
+        tokens(
+            "[order] [a]]b] [공백 있는 이름]",
+            Arc::new(GenericDialect {})
+        ),
+        vec![
+            Token::Identifier("order".into()),
+            Token::Identifier("a]b".into()),
+            Token::Identifier("공백 있는 이름".into()),
+        ]
+    );
+
+    // [를 식별자 인용에 쓰지 않는 방언은 대괄호로 분석 (배열 타입)
+    assert_eq!(
+        tokens("[order]", Arc::new(PostgreSQLDialect {})),
+        vec![Token::LeftBracket, Token::Order, Token::RightBracket]
+    );
+}
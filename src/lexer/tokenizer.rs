@@ -1,25 +1,80 @@
-use crate::errors::predule::LexingError;
-use crate::lexer::predule::{OperatorToken, Token};
-use crate::logger::predule::Logger;
-use std::error::Error;
+use crate::dialect::predule::{Dialect, GenericDialect};
+use crate::errors::predule::{Error, ErrorKind};
+use crate::lexer::predule::{Location, OperatorToken, Span, Token, TokenWithSpan};
+use crate::logger::predule::{log_debug, log_trace};
+use std::sync::Arc;
 
 #[derive(Debug)]
 pub struct Tokenizer {
     buffer: Vec<char>,
     buffer_index: usize,
     last_char: char,
+    dialect: Arc<dyn Dialect>,
+    // 문자 인덱스별 바이트 오프셋
+    byte_offsets: Vec<usize>,
+    // 각 행이 시작되는 문자 인덱스
+    line_starts: Vec<usize>,
+    // 현재 읽고 있는 토큰의 시작 문자 인덱스
+    token_start: usize,
+    // MySQL의 /*!40101 ... */ 조건부 주석 안쪽을 SQL로 읽을지 여부
+    conditional_comments: bool,
+    // 조건부 주석 안쪽을 읽고 있는지
+    in_conditional_comment: bool,
 }
 
 impl Tokenizer {
     pub fn new(text: String) -> Self {
-        Logger::info(format!("SQL echo: {:?}", text));
+        Self::with_dialect(text, Arc::new(GenericDialect {}))
+    }
+
+    pub fn with_dialect(text: String, dialect: Arc<dyn Dialect>) -> Self {
+        log_debug!(
+            "tokenizing {} bytes with {} dialect",
+            text.len(),
+            dialect.name()
+        );
+        log_trace!("SQL: {:?}", text);
+
+        let buffer: Vec<char> = text.chars().collect();
+
+        let mut byte_offsets = Vec::with_capacity(buffer.len() + 1);
+        let mut line_starts = vec![0];
+        let mut offset = 0;
+
+        for (index, ch) in buffer.iter().enumerate() {
+            byte_offsets.push(offset);
+            offset += ch.len_utf8();
+
+            if *ch == '\n' {
+                line_starts.push(index + 1);
+            }
+        }
+        byte_offsets.push(offset);
+
         Self {
             last_char: ' ',
-            buffer: text.chars().collect(),
+            buffer,
             buffer_index: 0,
+            dialect,
+            byte_offsets,
+            line_starts,
+            token_start: 0,
+            conditional_comments: false,
+            in_conditional_comment: false,
         }
     }
 
+    // /*!40101 ... */ 형태의 조건부 주석을 주석 대신 SQL로 읽도록 설정합니다.
+    // 설정하지 않으면 일반 블록 주석으로 취급합니다.
+    pub fn set_conditional_comments(mut self, conditional_comments: bool) -> Self {
+        self.conditional_comments = conditional_comments;
+        self
+    }
+
+    pub fn dialect(&self) -> Arc<dyn Dialect> {
+        self.dialect.clone()
+    }
+
     pub fn is_whitespace(&self) -> bool {
         self.last_char == ' ' || self.last_char == '\n' || self.last_char == '\t'
     }
@@ -45,7 +100,10 @@ impl Tokenizer {
     }
 
     pub fn is_special_character(&self) -> bool {
-        ['+', '-', '*', '/', ',', '>', '<', '=', '!', '\\'].contains(&self.last_char)
+        [
+            '+', '-', '*', '/', ',', '>', '<', '=', '!', '\\', '%', '^', '&', '|', '~', ':',
+        ]
+        .contains(&self.last_char)
     }
 
     pub fn is_quote(&self) -> bool {
@@ -68,18 +126,19 @@ impl Tokenizer {
         self.last_char == '(' || self.last_char == ')'
     }
 
+    pub fn is_bracket(&self) -> bool {
+        self.last_char == '[' || self.last_char == ']'
+    }
+
     pub fn is_eof(&self) -> bool {
         self.buffer_index >= self.buffer.len()
     }
 
     // 버퍼에서 문자 하나를 읽어서 last_char에 보관합니다.
+    // 버퍼 끝을 넘어서 읽을 경우 공백으로 취급합니다.
     pub fn read_char(&mut self) {
-        if self.buffer_index >= self.buffer.len() {
-            self.last_char = ' ';
-        } else {
-            self.last_char = self.buffer[self.buffer_index];
-            self.buffer_index += 1;
-        }
+        self.last_char = self.buffer.get(self.buffer_index).copied().unwrap_or(' ');
+        self.buffer_index += 1;
     }
 
     // 보관했던 문자 하나를 다시 버퍼에 돌려놓습니다.
@@ -88,144 +147,521 @@ impl Tokenizer {
             self.last_char = ' ';
         } else {
             self.buffer_index -= 1;
-            self.last_char = self.buffer[self.buffer_index];
+            self.last_char = self.buffer.get(self.buffer_index).copied().unwrap_or(' ');
         }
     }
 
-    // 주어진 텍스트에서 토큰을 순서대로 획득해 반환합니다.
-    // 끝을 만날 경우 Token::EOF를 반환합니다.
-    pub fn get_token(&mut self) -> Result<Token, Box<dyn Error + Send>> {
-        // 화이트 스페이스 삼킴
-        while self.is_whitespace() && !self.is_eof() {
+    // 다음에 읽을 문자를 읽지 않고 확인합니다.
+    fn peek_char(&self) -> Option<char> {
+        self.buffer.get(self.buffer_index).copied()
+    }
+
+    // 다음 문자가 expected라면 읽고 true를 반환합니다.
+    fn next_char_is(&mut self, expected: char) -> bool {
+        if self.peek_char() == Some(expected) {
             self.read_char();
+            true
+        } else {
+            false
         }
+    }
 
-        // 첫번째 글짜가 알파벳일 경우 식별자 및 키워드로 인식
-        let token = if self.is_alphabet() || self.is_underscore() {
-            let mut identifier = vec![self.last_char];
+    // 마지막으로 읽은 문자가 버퍼 끝을 넘어섰는지
+    fn is_past_eof(&self) -> bool {
+        self.buffer_index > self.buffer.len()
+    }
 
-            self.read_char();
-            while self.is_alphabet_or_number() || self.is_underscore() {
-                identifier.push(self.last_char);
+    // 문자 인덱스를 원본 SQL 내의 위치로 변환합니다.
+    fn location(&self, index: usize) -> Location {
+        let index = index.min(self.buffer.len());
+        let line = self.line_starts.partition_point(|start| *start <= index);
+
+        Location::new(
+            self.byte_offsets[index],
+            line,
+            index - self.line_starts[line - 1] + 1,
+        )
+    }
+
+    // 현재 읽고 있는 토큰의 구간
+    fn current_span(&self) -> Span {
+        Span::new(
+            self.location(self.token_start),
+            self.location(self.buffer_index),
+        )
+    }
+
+    // 현재 읽고 있는 토큰 위치를 담은 어휘 분석 오류
+    fn lexing_error(&self, code: &'static str, message: String) -> Error {
+        Error::Lexing(Box::new(
+            ErrorKind::new(code, message).with_span(self.current_span()),
+        ))
+    }
+
+    // 숫자 리터럴을 읽습니다.
+    // 123, 3.14, .5, 1e10, 1.5E-3, 0x1F, 0b101
+    fn read_number(&mut self) -> Result<Token, Error> {
+        // 16진수, 2진수 정수
+        if self.last_char == '0' {
+            let radix = match self.peek_char() {
+                Some('x' | 'X') => Some(16),
+                Some('b' | 'B') => Some(2),
+                _ => None,
+            };
+
+            if let Some(radix) = radix {
                 self.read_char();
+                let prefix = format!("0{}", self.last_char);
+                let digits = self.read_number_tail();
+
+                return self.radix_integer(&digits, radix, &format!("{}{}", prefix, digits));
             }
+        }
 
-            let identifier: String = identifier.into_iter().collect::<String>();
+        let mut number = String::from(self.last_char);
+        let mut is_float = self.is_dot();
+        let mut has_exponent = false;
 
-            let token = match identifier.to_uppercase().as_str() {
-                "SELECT" => Token::Select,
-                "FROM" => Token::From,
-                "WHERE" => Token::Where,
-                "AS" => Token::As,
-                "ORDER" => Token::Order,
-                "BY" => Token::By,
-                "ASC" => Token::Asc,
-                "DESC" => Token::Desc,
-                "GROUP" => Token::Group,
-                "HAVING" => Token::Having,
-                "LIMIT" => Token::Limit,
-                "OFFSET" => Token::Offset,
-                "INSERT" => Token::Insert,
-                "INTO" => Token::Into,
-                "VALUES" => Token::Values,
-                "UPDATE" => Token::Update,
-                "SET" => Token::Set,
-                "DELETE" => Token::Delete,
-                "JOIN" => Token::Join,
-                "INNER" => Token::Inner,
-                "LEFT" => Token::Left,
-                "RIGHT" => Token::Right,
-                "FULL" => Token::Full,
-                "OUTER" => Token::Outer,
-                "CREATE" => Token::Create,
-                "ALTER" => Token::Alter,
-                "DROP" => Token::Drop,
-                "DATABASE" => Token::Database,
-                "TABLE" => Token::Table,
-                "COLUMN" => Token::Column,
-                "COMMENT" => Token::Comment,
-                "PRIMARY" => Token::Primary,
-                "FOREIGN" => Token::Foreign,
-                "KEY" => Token::Key,
-                "ADD" => Token::Add,
-                "RENAME" => Token::Rename,
-                "TO" => Token::To,
-                "SHOW" => Token::Show,
-                "DATABASES" => Token::Databases,
-                "TABLES" => Token::Tables,
-                "AND" => Token::And,
-                "OR" => Token::Or,
-                "NOT" => Token::Not,
-                "BETWEEN" => Token::Between,
-                "LIKE" => Token::Like,
-                "IN" => Token::In,
-                "IS" => Token::Is,
-                "TRUE" => Token::Boolean(true),
-                "FALSE" => Token::Boolean(false),
-                "NULL" => Token::Null,
-                "DEFAULT" => Token::Default,
-                "IF" => Token::If,
-                "EXISTS" => Token::Exists,
-                "ON" => Token::On,
-                "USE" => Token::Use,
-                "DATA" => Token::Data,
-                "TYPE" => Token::Type,
-                "NULLS" => Token::Nulls,
-                "FIRST" => Token::First,
-                "LAST" => Token::Last,
-                _ => Token::Identifier(identifier),
-            };
+        while let Some(ch) = self.peek_char() {
+            if ch.is_ascii_digit() {
+                self.read_char();
+                number.push(ch);
+            } else if ch == '.' && !is_float && !has_exponent {
+                self.read_char();
+                number.push(ch);
+                is_float = true;
+            } else if matches!(ch, 'e' | 'E') && !has_exponent {
+                self.read_char();
+                number.push(ch);
+                is_float = true;
+                has_exponent = true;
+
+                if let Some(sign @ ('+' | '-')) = self.peek_char() {
+                    self.read_char();
+                    number.push(sign);
+                }
 
-            return Ok(token);
+                // 지수 부분에는 숫자가 하나 이상 있어야 합니다.
+                if !self.peek_char().is_some_and(|ch| ch.is_ascii_digit()) {
+                    number.push_str(&self.read_number_tail());
+                    return Err(self.malformed_number(&number));
+                }
+            } else if ch.is_alphanumeric() || ch == '_' || ch == '.' {
+                // 1.2.3, 123abc
+                number.push_str(&self.read_number_tail());
+                return Err(self.malformed_number(&number));
+            } else {
+                break;
+            }
         }
-        // 첫번째 글자가 숫자일 경우 정수 및 실수값으로 인식
-        else if self.is_digit() {
-            let mut number_string = vec![self.last_char];
 
-            // 숫자나 .이 나올 때까지만 버퍼에서 읽어서 number_string에 저장
-            loop {
+        if is_float {
+            match number.parse::<f64>() {
+                Ok(number) => Ok(Token::Float(number)),
+                Err(_) => Err(self.lexing_error(
+                    "E0301",
+                    format!("invalid floating point number format: {}", number),
+                )),
+            }
+        } else {
+            match number.parse::<i64>() {
+                Ok(number) => Ok(Token::Integer(number)),
+                Err(_) => Err(self.lexing_error(
+                    "E0302",
+                    format!("invalid integer number format: {}", number),
+                )),
+            }
+        }
+    }
+
+    // 숫자 뒤에 붙은 문자들을 읽습니다. (오류 메시지와 진법 변환용)
+    fn read_number_tail(&mut self) -> String {
+        let mut tail = String::new();
+
+        while let Some(ch) = self.peek_char() {
+            if ch.is_alphanumeric() || ch == '_' || ch == '.' {
                 self.read_char();
-                if self.is_digit() || self.is_dot() {
-                    number_string.push(self.last_char);
-                    continue;
-                } else if self.is_eof() {
-                    break;
+                tail.push(ch);
+            } else {
+                break;
+            }
+        }
+
+        tail
+    }
+
+    // 진법에 맞는 정수로 변환합니다.
+    fn radix_integer(&self, digits: &str, radix: u32, text: &str) -> Result<Token, Error> {
+        if digits.is_empty() || !digits.chars().all(|ch| ch.is_digit(radix)) {
+            return Err(self.malformed_number(text));
+        }
+
+        match i64::from_str_radix(digits, radix) {
+            Ok(number) => Ok(Token::Integer(number)),
+            Err(_) => {
+                Err(self.lexing_error("E0302", format!("invalid integer number format: {}", text)))
+            }
+        }
+    }
+
+    fn malformed_number(&self, text: &str) -> Error {
+        self.lexing_error("E0305", format!("malformed number: {}", text))
+    }
+
+    // 따옴표로 감싼 값을 읽습니다.
+    // 닫는 따옴표가 두번 연속될 경우 따옴표 하나로 이스케이프합니다.
+    // 닫는 따옴표 없이 끝날 경우 None을 반환합니다.
+    fn read_quoted(&mut self, close: char, escape: Escape) -> Result<Option<String>, Error> {
+        let mut string = String::new();
+
+        loop {
+            self.read_char();
+
+            if self.is_past_eof() {
+                return Ok(None);
+            }
+
+            if self.last_char == '\\' && escape != Escape::None {
+                self.read_char();
+
+                if self.is_past_eof() {
+                    return Ok(None);
+                }
+
+                self.read_escape(escape, &mut string)?;
+            } else if self.last_char == close {
+                // 따옴표가 연속될 경우 이스케이프, 아닐 경우 종료
+                if self.next_char_is(close) {
+                    string.push(close);
                 } else {
-                    self.unread_char();
                     break;
                 }
+            } else {
+                string.push(self.last_char);
             }
+        }
 
-            let number_string: String =
-                number_string.into_iter().collect::<String>().to_uppercase();
+        Ok(Some(string))
+    }
 
-            // .이 있을 경우 실수, 아닌 경우 정수로 인식
-            if number_string.contains('.') {
-                let number = number_string.parse::<f64>();
+    // 따옴표로 감싼 문자열을 읽습니다.
+    fn read_string(&mut self, quote: char, escape: Escape) -> Result<String, Error> {
+        match self.read_quoted(quote, escape)? {
+            Some(string) => Ok(string),
+            None => Err(self.unterminated_string()),
+        }
+    }
 
-                match number {
-                    Ok(number) => Token::Float(number),
-                    Err(_) => {
-                        return Err(LexingError::boxed(format!(
-                            "invalid floating point number format: {}",
-                            number_string
-                        )))
-                    }
+    // 백슬래시 뒤의 문자를 해석해 string에 덧붙입니다.
+    fn read_escape(&mut self, escape: Escape, string: &mut String) -> Result<(), Error> {
+        let ch = self.last_char;
+
+        match (escape, ch) {
+            (_, 'b') => string.push('\u{8}'),
+            (_, 'n') => string.push('\n'),
+            (_, 'r') => string.push('\r'),
+            (_, 't') => string.push('\t'),
+            (Escape::MySQL, '0') => string.push('\0'),
+            (Escape::MySQL, 'Z') => string.push('\u{1a}'),
+            // LIKE 패턴용 이스케이프는 백슬래시를 유지합니다.
+            (Escape::MySQL, '%' | '_') => {
+                string.push('\\');
+                string.push(ch);
+            }
+            (Escape::PostgreSQL, 'f') => string.push('\u{c}'),
+            (Escape::PostgreSQL, 'x') => {
+                let digits = self.read_escape_digits(16, 2);
+
+                if digits.is_empty() {
+                    string.push(ch);
+                } else {
+                    string.push(self.escaped_char("\\x", &digits, 16)?);
+                }
+            }
+            (Escape::PostgreSQL, 'u' | 'U') => {
+                let length = if ch == 'u' { 4 } else { 8 };
+                let digits = self.read_escape_digits(16, length);
+
+                if digits.len() != length {
+                    return Err(self.invalid_escape(&format!("\\{}{}", ch, digits)));
+                }
+
+                string.push(self.escaped_char(&format!("\\{}", ch), &digits, 16)?);
+            }
+            (Escape::PostgreSQL, '0'..='7') => {
+                let digits = format!("{}{}", ch, self.read_escape_digits(8, 2));
+
+                string.push(self.escaped_char("\\", &digits, 8)?);
+            }
+            _ => string.push(ch),
+        }
+
+        Ok(())
+    }
+
+    // 이스케이프 뒤에 오는 숫자를 최대 length개까지 읽습니다.
+    fn read_escape_digits(&mut self, radix: u32, length: usize) -> String {
+        let mut digits = String::new();
+
+        while digits.len() < length {
+            match self.peek_char() {
+                Some(ch) if ch.is_digit(radix) => {
+                    self.read_char();
+                    digits.push(ch);
                 }
+                _ => break,
+            }
+        }
+
+        digits
+    }
+
+    // 숫자로 표현된 이스케이프를 문자로 변환합니다.
+    fn escaped_char(&self, prefix: &str, digits: &str, radix: u32) -> Result<char, Error> {
+        u32::from_str_radix(digits, radix)
+            .ok()
+            .filter(|code| *code != 0)
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.invalid_escape(&format!("{}{}", prefix, digits)))
+    }
+
+    // $$...$$, $tag$...$tag$ 형태의 달러 인용 문자열을 읽습니다.
+    // 태그가 같은 구분자를 만날 때까지 이스케이프 없이 그대로 읽습니다.
+    fn read_dollar_quoted(&mut self) -> Result<Token, Error> {
+        let mut delimiter = vec!['$'];
+
+        while let Some(ch) = self.peek_char() {
+            let is_tag_char =
+                ch.is_alphabetic() || ch == '_' || (ch.is_ascii_digit() && delimiter.len() > 1);
+
+            if ch == '$' {
+                self.read_char();
+                delimiter.push(ch);
+                break;
+            } else if is_tag_char {
+                self.read_char();
+                delimiter.push(ch);
             } else {
-                let number = number_string.parse::<i64>();
-
-                match number {
-                    Ok(number) => Token::Integer(number),
-                    Err(_) => {
-                        return Err(LexingError::boxed(format!(
-                            "invalid integer number format: {}",
-                            number_string
-                        )))
-                    }
+                break;
+            }
+        }
+
+        if delimiter.len() < 2 || delimiter.last() != Some(&'$') {
+            return Err(self.lexing_error("E0304", "unexpected character: '$'".to_owned()));
+        }
+
+        let mut string = vec![];
+
+        loop {
+            self.read_char();
+
+            if self.is_past_eof() {
+                return Err(self.unterminated_string());
+            }
+
+            string.push(self.last_char);
+
+            if string.ends_with(&delimiter) {
+                string.truncate(string.len() - delimiter.len());
+                break;
+            }
+        }
+
+        Ok(Token::String(string.into_iter().collect()))
+    }
+
+    // X'1F' 형태의 16진수 문자열을 읽습니다.
+    fn read_hex_string(&mut self) -> Result<Token, Error> {
+        let digits = self.read_string('\'', Escape::None)?;
+
+        if digits.len() % 2 != 0 || !digits.chars().all(|ch| ch.is_ascii_hexdigit()) {
+            return Err(self.lexing_error(
+                "E0309",
+                format!("invalid hexadecimal string: X'{}'", digits),
+            ));
+        }
+
+        Ok(Token::HexString(digits))
+    }
+
+    fn unterminated_string(&self) -> Error {
+        self.lexing_error("E0306", "unterminated string literal".to_owned())
+    }
+
+    fn invalid_escape(&self, escape: &str) -> Error {
+        self.lexing_error("E0308", format!("invalid escape sequence: {}", escape))
+    }
+
+    // 방언 규칙에 따른 일반 문자열의 이스케이프 방식
+    fn string_escape(&self) -> Escape {
+        if self.dialect.supports_backslash_escapes() {
+            Escape::MySQL
+        } else {
+            Escape::None
+        }
+    }
+
+    // 줄 끝까지 행 단위 주석을 읽습니다. (--, #)
+    fn read_line_comment(&mut self) -> Token {
+        let mut comment = vec![];
+
+        while !self.is_eof() {
+            self.read_char();
+
+            if self.last_char == '\n' {
+                break;
+            } else {
+                comment.push(self.last_char);
+            }
+        }
+
+        Token::CodeComment(comment.into_iter().collect())
+    }
+
+    // */까지 블록 단위 주석을 읽습니다. (/*는 이미 읽은 상태)
+    // 방언에 따라 /* /* */ */ 형태의 중첩 주석을 허용합니다.
+    fn read_block_comment(&mut self) -> Result<Token, Error> {
+        let is_nested = self.dialect.supports_nested_comments();
+        let mut depth = 1;
+        let mut comment = String::new();
+
+        loop {
+            self.read_char();
+
+            if self.is_past_eof() {
+                return Err(self.lexing_error("E0310", "unterminated block comment".to_owned()));
+            }
+
+            if self.last_char == '*' && self.next_char_is('/') {
+                depth -= 1;
+
+                if depth == 0 {
+                    break;
+                }
+
+                comment.push_str("*/");
+            } else if is_nested && self.last_char == '/' && self.next_char_is('*') {
+                depth += 1;
+                comment.push_str("/*");
+            } else {
+                comment.push(self.last_char);
+            }
+        }
+
+        Ok(Token::CodeComment(comment))
+    }
+
+    // 조건부 주석을 SQL로 읽는 경우, 여는 기호(/*!버전)와 닫는 기호(*/)를 건너뜁니다.
+    // 기호를 건너뛰었다면 true를 반환합니다.
+    fn skip_conditional_comment_marker(&mut self) -> bool {
+        if !self.conditional_comments || self.is_past_eof() {
+            return false;
+        }
+
+        let is_open = self.last_char == '/'
+            && self.peek_char() == Some('*')
+            && self.buffer.get(self.buffer_index + 1) == Some(&'!');
+        let is_close =
+            self.in_conditional_comment && self.last_char == '*' && self.peek_char() == Some('/');
+
+        if is_open {
+            self.read_char();
+            self.read_char();
+
+            // 버전 번호
+            while self.peek_char().is_some_and(|ch| ch.is_ascii_digit()) {
+                self.read_char();
+            }
+
+            self.in_conditional_comment = true;
+        } else if is_close {
+            self.read_char();
+            self.in_conditional_comment = false;
+        } else {
+            return false;
+        }
+
+        self.read_char();
+        true
+    }
+
+    // 주어진 텍스트에서 토큰을 순서대로 획득해 반환합니다.
+    // 끝을 만날 경우 Token::EOF를 반환합니다.
+    pub fn get_token(&mut self) -> Result<Token, Error> {
+        Ok(self.get_token_with_span()?.token)
+    }
+
+    // 토큰과 함께 원본 SQL 내의 위치를 반환합니다.
+    pub fn get_token_with_span(&mut self) -> Result<TokenWithSpan, Error> {
+        // 화이트 스페이스와 조건부 주석 기호 삼킴
+        loop {
+            while self.is_whitespace() && !self.is_eof() {
+                self.read_char();
+            }
+
+            if !self.skip_conditional_comment_marker() {
+                break;
+            }
+        }
+
+        self.token_start = self.buffer_index.saturating_sub(1);
+
+        let token = self.read_token()?;
+
+        Ok(TokenWithSpan::new(token, self.current_span()))
+    }
+
+    fn read_token(&mut self) -> Result<Token, Error> {
+        // 첫번째 글짜가 알파벳일 경우 식별자 및 키워드로 인식
+        // b'101' 형태의 비트 문자열
+        // b'101', X'1F', N'...', E'...' 형태의 접두사가 붙은 문자열
+        let prefix = self.last_char.to_ascii_uppercase();
+        let is_prefixed_string = self.peek_char() == Some('\'')
+            && match prefix {
+                'B' | 'X' | 'N' => true,
+                'E' => self.dialect.supports_escape_strings(),
+                _ => false,
+            };
+
+        let token = if is_prefixed_string {
+            self.read_char();
+
+            match prefix {
+                'B' => {
+                    let digits = self.read_string('\'', Escape::None)?;
+
+                    self.radix_integer(&digits, 2, &format!("b'{}'", digits))?
                 }
+                'X' => self.read_hex_string()?,
+                'E' => Token::String(self.read_string('\'', Escape::PostgreSQL)?),
+                _ => Token::String(self.read_string('\'', self.string_escape())?),
+            }
+        } else if self.is_alphabet() || self.is_underscore() {
+            let mut identifier = vec![self.last_char];
+
+            self.read_char();
+            while self.is_alphabet_or_number() || self.is_underscore() {
+                identifier.push(self.last_char);
+                self.read_char();
+            }
+
+            let identifier: String = identifier.into_iter().collect::<String>();
+
+            // 식별자 뒤의 문자는 다음 토큰의 몫
+            self.unread_char();
+
+            // 예약어가 아닐 경우 방언 규칙에 따라 식별자로 변환
+            match self.dialect.keyword(identifier.to_uppercase().as_str()) {
+                Some(token) => token,
+                None => Token::Identifier(self.dialect.fold_identifier(identifier)),
             }
         }
+        // 첫번째 글자가 숫자이거나, .5처럼 .뒤에 숫자가 올 경우 숫자로 인식
+        else if self.is_digit()
+            || (self.is_dot() && self.peek_char().is_some_and(|ch| ch.is_ascii_digit()))
+        {
+            self.read_number()?
+        }
         // 특수문자일 경우
         else if self.is_special_character() {
             match self.last_char {
@@ -233,140 +669,104 @@ impl Tokenizer {
                 '\\' => Token::Backslash,
                 '-' => {
                     // 다음 문자가 또 -일 경우 행 단위 주석으로 처리
-                    self.read_char();
-
-                    if self.last_char == '-' {
-                        let mut comment = vec![];
-
-                        while !self.is_eof() {
-                            self.read_char();
-
-                            if self.last_char == '\n' {
-                                break;
-                            } else {
-                                comment.push(self.last_char);
-                            }
-                        }
-
-                        let comment: String = comment.into_iter().collect();
-                        Token::CodeComment(comment)
+                    if self.next_char_is('-') {
+                        self.read_line_comment()
                     } else {
-                        self.unread_char();
                         Token::Operator(OperatorToken::Minus)
                     }
                 }
                 '/' => {
                     // 다음 문자가 *일 경우 블록 단위 주석으로 처리
-
-                    self.read_char();
-
-                    if self.last_char == '*' {
-                        let mut comment = vec![];
-
-                        self.read_char();
-                        while !self.is_eof() {
-                            if self.last_char == '*' {
-                                self.read_char();
-                                if self.last_char == '/' {
-                                    break;
-                                }
-                            } else {
-                                comment.push(self.last_char);
-                            }
-
-                            self.read_char();
-                        }
-
-                        let comment: String = comment.into_iter().collect();
-                        Token::CodeComment(comment)
+                    if self.next_char_is('*') {
+                        self.read_block_comment()?
                     } else {
-                        self.unread_char();
                         Token::Operator(OperatorToken::Slash)
                     }
                 }
                 '+' => Token::Operator(OperatorToken::Plus),
                 '*' => Token::Operator(OperatorToken::Asterisk),
-                '!' => Token::Operator(OperatorToken::Not), // TODO: != 연산자 처리
-                '=' => Token::Operator(OperatorToken::Eq),
-                '<' => Token::Operator(OperatorToken::Lt), // TODO: <= 연산자 처리
-                '>' => Token::Operator(OperatorToken::Gt), // TODO: >= 연산자 처리
-                _ => {
-                    return Err(LexingError::boxed(format!(
-                        "unexpected operator: {:?}",
-                        self.last_char
-                    )))
+                '%' => Token::Operator(OperatorToken::Percent),
+                '^' => Token::Operator(OperatorToken::Caret),
+                '&' => Token::Operator(OperatorToken::Ampersand),
+                '~' => Token::Operator(OperatorToken::Tilde),
+                '!' => {
+                    if self.next_char_is('=') {
+                        Token::Operator(OperatorToken::Neq)
+                    } else {
+                        Token::Operator(OperatorToken::Not)
+                    }
                 }
-            }
-        }
-        // 따옴표일 경우 처리
-        else if self.is_quote() {
-            if self.last_char == '"' {
-                let mut identifier = vec![];
-
-                self.read_char();
-                while self.last_char != '"' {
-                    identifier.push(self.last_char);
-                    self.read_char();
+                '=' => {
+                    // == (SQLite)
+                    self.next_char_is('=');
+                    Token::Operator(OperatorToken::Eq)
                 }
-
-                let identifier: String = identifier.into_iter().collect::<String>();
-
-                Token::Identifier(identifier)
-            } else if self.last_char == '\'' {
-                let mut string = vec![];
-
-                self.read_char();
-                while !self.is_eof() {
-                    if self.last_char == '\'' {
-                        self.read_char();
-
-                        // '' 의 형태일 경우 '로 이스케이프
-                        // 아닐 경우 문자열 종료
-                        if self.last_char == '\'' {
-                            string.push(self.last_char);
-                        } else {
-                            self.unread_char();
-                            break;
-                        }
+                '<' => {
+                    if self.next_char_is('=') {
+                        Token::Operator(OperatorToken::Lte)
+                    } else if self.next_char_is('>') {
+                        Token::Operator(OperatorToken::Neq)
+                    } else if self.next_char_is('<') {
+                        Token::Operator(OperatorToken::ShiftLeft)
                     } else {
-                        string.push(self.last_char);
+                        Token::Operator(OperatorToken::Lt)
                     }
-
-                    self.read_char();
                 }
-
-                let string: String = string.into_iter().collect::<String>();
-
-                Token::String(string)
-            } else {
-                Token::UnknownCharacter(self.last_char)
-            }
-        } else if self.is_backtick() {
-            let mut string = vec![];
-
-            self.read_char();
-            while !self.is_eof() {
-                if self.last_char == '`' {
-                    self.read_char();
-
-                    // `` 의 형태일 경우 `로 이스케이프
-                    // 아닐 경우 문자열 종료
-                    if self.last_char == '`' {
-                        string.push(self.last_char);
+                '>' => {
+                    if self.next_char_is('=') {
+                        Token::Operator(OperatorToken::Gte)
+                    } else if self.next_char_is('>') {
+                        Token::Operator(OperatorToken::ShiftRight)
                     } else {
-                        self.unread_char();
-                        break;
+                        Token::Operator(OperatorToken::Gt)
                     }
-                } else {
-                    string.push(self.last_char);
                 }
-
-                self.read_char();
+                '|' => {
+                    if self.next_char_is('|') {
+                        Token::Operator(OperatorToken::Concat)
+                    } else {
+                        Token::Operator(OperatorToken::Pipe)
+                    }
+                }
+                ':' if self.peek_char() == Some(':') => {
+                    self.read_char();
+                    Token::DoubleColon
+                }
+                _ => {
+                    return Err(self.lexing_error(
+                        "E0303",
+                        format!("unexpected operator: {:?}", self.last_char),
+                    ))
+                }
             }
+        }
+        // 방언에 따라 따옴표로 감싼 식별자 혹은 문자열로 처리
+        else if self.dialect.is_delimited_identifier_start(self.last_char) {
+            // [bracketed] 식별자는 ]로 닫습니다.
+            let close = if self.last_char == '[' {
+                ']'
+            } else {
+                self.last_char
+            };
 
-            let string: String = string.into_iter().collect::<String>();
-
-            Token::Identifier(string)
+            match self.read_quoted(close, Escape::None)? {
+                Some(identifier) => Token::Identifier(identifier),
+                None => {
+                    return Err(
+                        self.lexing_error("E0307", "unterminated quoted identifier".to_owned())
+                    )
+                }
+            }
+        } else if self.dialect.is_string_quote(self.last_char) {
+            Token::String(self.read_string(self.last_char, self.string_escape())?)
+        }
+        // # 행 단위 주석 (MySQL)
+        else if self.last_char == '#' && self.dialect.supports_hash_comments() {
+            self.read_line_comment()
+        }
+        // 달러 인용 문자열
+        else if self.last_char == '$' && self.dialect.supports_dollar_quoted_strings() {
+            self.read_dollar_quoted()?
         }
         // 세미콜론
         else if self.is_semicolon() {
@@ -384,14 +784,29 @@ impl Tokenizer {
                 Token::RightParentheses
             }
         }
+        // 대괄호 (배열 타입)
+        // [를 식별자 인용에 쓰는 방언은 앞에서 식별자로 분석합니다.
+        else if self.is_bracket() {
+            if self.last_char == '[' {
+                Token::LeftBracket
+            } else {
+                Token::RightBracket
+            }
+        }
         // 아무것도 해당되지 않을 경우 예외처리
         else if self.is_eof() {
+            if self.in_conditional_comment {
+                return Err(
+                    self.lexing_error("E0310", "unterminated conditional comment".to_owned())
+                );
+            }
+
             Token::EOF
         } else {
-            return Err(LexingError::boxed(format!(
-                "unexpected character: {:?}",
-                self.last_char
-            )));
+            return Err(self.lexing_error(
+                "E0304",
+                format!("unexpected character: {:?}", self.last_char),
+            ));
         };
 
         self.last_char = ' ';
@@ -400,17 +815,102 @@ impl Tokenizer {
     }
 
     // Tokenizer 생성 없이 토큰 목록을 가져올 수 있는 유틸 함수입니다.
-    pub fn string_to_tokens(text: String) -> Result<Vec<Token>, Box<dyn Error + Send>> {
-        let mut tokenizer = Tokenizer::new(text);
+    pub fn string_to_tokens(text: String) -> Result<Vec<Token>, Error> {
+        Self::string_to_tokens_with_dialect(text, Arc::new(GenericDialect {}))
+    }
+
+    // 주어진 방언의 규칙으로 토큰 목록을 가져오는 유틸 함수입니다.
+    pub fn string_to_tokens_with_dialect(
+        text: String,
+        dialect: Arc<dyn Dialect>,
+    ) -> Result<Vec<Token>, Error> {
+        Ok(Self::string_to_tokens_with_span(text, dialect)?
+            .into_iter()
+            .map(|token| token.token)
+            .collect())
+    }
 
+    // 위치 정보가 붙은 토큰 목록을 가져오는 유틸 함수입니다.
+    pub fn string_to_tokens_with_span(
+        text: String,
+        dialect: Arc<dyn Dialect>,
+    ) -> Result<Vec<TokenWithSpan>, Error> {
+        Tokenizer::with_dialect(text, dialect).tokenize()
+    }
+
+    // 남은 텍스트 전체를 위치 정보가 붙은 토큰 목록으로 변환합니다.
+    pub fn tokenize(&mut self) -> Result<Vec<TokenWithSpan>, Error> {
         let mut tokens = vec![];
 
-        while !tokenizer.is_eof() {
-            tokens.push(tokenizer.get_token()?);
+        // 버퍼 끝에 걸린 마지막 문자까지 읽도록 EOF 토큰이 나올 때까지 반복
+        loop {
+            let token = self.get_token_with_span()?;
+
+            if token.token.is_eof() {
+                break;
+            }
+
+            tokens.push(token);
         }
 
         Ok(tokens)
     }
+
+    // 토큰 목록을 가져오면서 어휘 분석 오류가 난 문장을 건너뜁니다.
+    // 오류가 난 문장의 토큰은 버리고 다음 ; 직후부터 다시 읽으며, 오류는 따로 모아서 반환합니다.
+    pub fn tokenize_with_diagnostics(&mut self) -> (Vec<TokenWithSpan>, Vec<Error>) {
+        let mut tokens = vec![];
+        let mut diagnostics = vec![];
+
+        // 현재 문장의 첫 토큰 위치
+        let mut statement_start = 0;
+
+        loop {
+            match self.get_token_with_span() {
+                Ok(token) if token.token.is_eof() => break,
+                Ok(token) => {
+                    let is_semicolon = token.token == Token::SemiColon;
+
+                    tokens.push(token);
+
+                    if is_semicolon {
+                        statement_start = tokens.len();
+                    }
+                }
+                Err(error) => {
+                    log_debug!("skipping statement: {}", error);
+                    diagnostics.push(error);
+
+                    tokens.truncate(statement_start);
+                    self.skip_statement();
+                }
+            }
+        }
+
+        (tokens, diagnostics)
+    }
+
+    // 다음 ; 직후까지 건너뜁니다.
+    fn skip_statement(&mut self) {
+        while !self.is_semicolon() && !self.is_past_eof() {
+            self.read_char();
+        }
+
+        if self.is_semicolon() {
+            self.read_char();
+        }
+    }
+}
+
+// 문자열 안의 백슬래시 이스케이프 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Escape {
+    // 이스케이프 없음 (표준 SQL)
+    None,
+    // \', \n, \0, \Z 등 (MySQL)
+    MySQL,
+    // E'...' 안의 \n, \x1F, \u0041, \101 등 (PostgreSQL)
+    PostgreSQL,
 }
 
 impl std::fmt::Display for Tokenizer {
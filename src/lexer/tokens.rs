@@ -1,9 +1,8 @@
 use std::convert::TryInto;
-use std::error::Error;
 
 use super::predule::OperatorToken;
 use crate::ast::predule::BinaryOperator;
-use crate::errors::predule::IntoError;
+use crate::errors::predule::{Error, ErrorKind};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Token {
@@ -63,6 +62,15 @@ pub enum Token {
     Type,
     Default,
     Data,
+    Index,
+    Unique,
+    Constraint,
+    References,
+    Check,
+    Cascade,
+    Restrict,
+    Collate,
+    AutoIncrement,
 
     // ETC
     // Analyze,
@@ -83,6 +91,8 @@ pub enum Token {
     Float(f64),
     Boolean(bool),
     String(String),
+    // X'1F' 형태의 16진수 문자열
+    HexString(String),
     Null,
 
     Operator(OperatorToken),
@@ -94,8 +104,11 @@ pub enum Token {
     Comma,
     Period,
     SemiColon,
+    DoubleColon,
     LeftParentheses,
     RightParentheses,
+    LeftBracket,
+    RightBracket,
     Backslash,
 
     // exception handling
@@ -105,6 +118,84 @@ pub enum Token {
 }
 
 impl Token {
+    // 방언에 관계없이 공통으로 쓰이는 예약어 변환
+    // 대문자로 변환된 단어를 받습니다.
+    pub fn from_keyword(word: &str) -> Option<Token> {
+        let token = match word {
+            "SELECT" => Token::Select,
+            "FROM" => Token::From,
+            "WHERE" => Token::Where,
+            "AS" => Token::As,
+            "ORDER" => Token::Order,
+            "BY" => Token::By,
+            "ASC" => Token::Asc,
+            "DESC" => Token::Desc,
+            "GROUP" => Token::Group,
+            "HAVING" => Token::Having,
+            "LIMIT" => Token::Limit,
+            "OFFSET" => Token::Offset,
+            "INSERT" => Token::Insert,
+            "INTO" => Token::Into,
+            "VALUES" => Token::Values,
+            "UPDATE" => Token::Update,
+            "SET" => Token::Set,
+            "DELETE" => Token::Delete,
+            "JOIN" => Token::Join,
+            "INNER" => Token::Inner,
+            "LEFT" => Token::Left,
+            "RIGHT" => Token::Right,
+            "FULL" => Token::Full,
+            "OUTER" => Token::Outer,
+            "CREATE" => Token::Create,
+            "ALTER" => Token::Alter,
+            "DROP" => Token::Drop,
+            "DATABASE" => Token::Database,
+            "TABLE" => Token::Table,
+            "COLUMN" => Token::Column,
+            "COMMENT" => Token::Comment,
+            "PRIMARY" => Token::Primary,
+            "FOREIGN" => Token::Foreign,
+            "KEY" => Token::Key,
+            "ADD" => Token::Add,
+            "RENAME" => Token::Rename,
+            "TO" => Token::To,
+            "SHOW" => Token::Show,
+            "DATABASES" => Token::Databases,
+            "TABLES" => Token::Tables,
+            "AND" => Token::And,
+            "OR" => Token::Or,
+            "NOT" => Token::Not,
+            "BETWEEN" => Token::Between,
+            "LIKE" => Token::Like,
+            "IN" => Token::In,
+            "IS" => Token::Is,
+            "TRUE" => Token::Boolean(true),
+            "FALSE" => Token::Boolean(false),
+            "NULL" => Token::Null,
+            "DEFAULT" => Token::Default,
+            "IF" => Token::If,
+            "EXISTS" => Token::Exists,
+            "ON" => Token::On,
+            "USE" => Token::Use,
+            "DATA" => Token::Data,
+            "TYPE" => Token::Type,
+            "NULLS" => Token::Nulls,
+            "FIRST" => Token::First,
+            "LAST" => Token::Last,
+            "INDEX" => Token::Index,
+            "UNIQUE" => Token::Unique,
+            "CONSTRAINT" => Token::Constraint,
+            "REFERENCES" => Token::References,
+            "CHECK" => Token::Check,
+            "CASCADE" => Token::Cascade,
+            "RESTRICT" => Token::Restrict,
+            "COLLATE" => Token::Collate,
+            _ => return None,
+        };
+
+        Some(token)
+    }
+
     pub fn is_eof(&self) -> bool {
         #[allow(clippy::match_like_matches_macro)]
         match self {
@@ -113,6 +204,15 @@ impl Token {
         }
     }
 
+    // 키워드로 분리되지 않은 단어인지 대소문자 구분 없이 확인
+    // NO ACTION, GENERATED ALWAYS 등 컬럼명으로도 쓰이는 단어에 사용
+    pub fn is_word(&self, word: &str) -> bool {
+        match self {
+            Token::Identifier(identifier) => identifier.eq_ignore_ascii_case(word),
+            _ => false,
+        }
+    }
+
     pub fn is_unary_operator(&self) -> bool {
         match self {
             Token::Operator(operator) => operator.is_unary_operator(),
@@ -133,18 +233,22 @@ impl Token {
     pub fn try_into_multi_token_operator(
         self,
         second_token: Self,
-    ) -> Result<BinaryOperator, Box<dyn Error + Send>> {
+    ) -> Result<BinaryOperator, Error> {
         match self {
             Token::Not => match second_token {
                 Token::Like => Ok(BinaryOperator::NotLike),
                 Token::In => Ok(BinaryOperator::NotIn),
-                _ => Err(IntoError::boxed("BinaryOperator Cast Error")),
+                _ => Err(Error::Into(Box::new(
+                    ErrorKind::new("E0901", "BinaryOperator Cast Error").with_found(second_token),
+                ))),
             },
             Token::Is => match second_token {
                 Token::Not => Ok(BinaryOperator::IsNot),
                 _ => Ok(BinaryOperator::Is),
             },
-            _ => Err(IntoError::boxed("BinaryOperator Cast Error")),
+            _ => Err(Error::Into(Box::new(
+                ErrorKind::new("E0901", "BinaryOperator Cast Error").with_found(self),
+            ))),
         }
     }
 
@@ -155,6 +259,7 @@ impl Token {
             | Token::Float(_)
             | Token::Boolean(_)
             | Token::String(_)
+            | Token::HexString(_)
             | Token::Null
             | Token::LeftParentheses
             | Token::Not => true,
@@ -164,10 +269,105 @@ impl Token {
     }
 }
 
+// 오류 메시지에 출력할 원본 SQL 형태
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Token::Select => "SELECT",
+            Token::From => "FROM",
+            Token::Where => "WHERE",
+            Token::As => "AS",
+            Token::Order => "ORDER",
+            Token::By => "BY",
+            Token::Asc => "ASC",
+            Token::Desc => "DESC",
+            Token::Group => "GROUP",
+            Token::Having => "HAVING",
+            Token::Limit => "LIMIT",
+            Token::Offset => "OFFSET",
+            Token::Insert => "INSERT",
+            Token::Into => "INTO",
+            Token::Values => "VALUES",
+            Token::Update => "UPDATE",
+            Token::Set => "SET",
+            Token::Delete => "DELETE",
+            Token::Join => "JOIN",
+            Token::Inner => "INNER",
+            Token::Left => "LEFT",
+            Token::Right => "RIGHT",
+            Token::Full => "FULL",
+            Token::Outer => "OUTER",
+            Token::On => "ON",
+            Token::Nulls => "NULLS",
+            Token::First => "FIRST",
+            Token::Last => "LAST",
+            Token::Create => "CREATE",
+            Token::Alter => "ALTER",
+            Token::Drop => "DROP",
+            Token::Database => "DATABASE",
+            Token::Table => "TABLE",
+            Token::Column => "COLUMN",
+            Token::Comment => "COMMENT",
+            Token::Primary => "PRIMARY",
+            Token::Foreign => "FOREIGN",
+            Token::Key => "KEY",
+            Token::Add => "ADD",
+            Token::If => "IF",
+            Token::Rename => "RENAME",
+            Token::To => "TO",
+            Token::Show => "SHOW",
+            Token::Databases => "DATABASES",
+            Token::Tables => "TABLES",
+            Token::Use => "USE",
+            Token::Type => "TYPE",
+            Token::Default => "DEFAULT",
+            Token::Data => "DATA",
+            Token::Index => "INDEX",
+            Token::Unique => "UNIQUE",
+            Token::Constraint => "CONSTRAINT",
+            Token::References => "REFERENCES",
+            Token::Check => "CHECK",
+            Token::Cascade => "CASCADE",
+            Token::Restrict => "RESTRICT",
+            Token::Collate => "COLLATE",
+            Token::AutoIncrement => "AUTO_INCREMENT",
+            Token::And => "AND",
+            Token::Or => "OR",
+            Token::Not => "NOT",
+            Token::Between => "BETWEEN",
+            Token::Like => "LIKE",
+            Token::In => "IN",
+            Token::Is => "IS",
+            Token::Null => "NULL",
+            Token::Exists => "EXISTS",
+            Token::Comma => ",",
+            Token::Period => ".",
+            Token::SemiColon => ";",
+            Token::DoubleColon => "::",
+            Token::LeftParentheses => "(",
+            Token::RightParentheses => ")",
+            Token::LeftBracket => "[",
+            Token::RightBracket => "]",
+            Token::Backslash => "\\",
+            Token::EOF => "end of input",
+            Token::CodeComment(text) | Token::Identifier(text) | Token::Error(text) => text,
+            Token::Integer(value) => return write!(f, "{}", value),
+            Token::Float(value) => return write!(f, "{}", value),
+            Token::Boolean(true) => "TRUE",
+            Token::Boolean(false) => "FALSE",
+            Token::String(value) => return write!(f, "'{}'", value),
+            Token::HexString(value) => return write!(f, "X'{}'", value),
+            Token::Operator(operator) => return write!(f, "{}", operator),
+            Token::UnknownCharacter(ch) => return write!(f, "{}", ch),
+        };
+        write!(f, "{}", text)
+    }
+}
+
 impl TryInto<BinaryOperator> for Token {
-    type Error = Box<dyn Error + Send>;
+    type Error = Error;
 
-    fn try_into(self) -> Result<BinaryOperator, Box<dyn Error + Send>> {
+    fn try_into(self) -> Result<BinaryOperator, Error> {
         match self {
             Token::Operator(operator) => operator.try_into(),
             Token::And => Ok(BinaryOperator::And),
@@ -175,7 +375,9 @@ impl TryInto<BinaryOperator> for Token {
             Token::Like => Ok(BinaryOperator::Like),
             Token::In => Ok(BinaryOperator::In),
             Token::Is => Ok(BinaryOperator::Is),
-            _ => Err(IntoError::boxed("BinaryOperator Cast Error")),
+            _ => Err(Error::Into(Box::new(
+                ErrorKind::new("E0901", "BinaryOperator Cast Error").with_found(self),
+            ))),
         }
     }
 }
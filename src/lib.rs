@@ -1,9 +1,15 @@
 #[path = "./ast/mod.rs"]
 pub mod ast;
 
+#[path = "./catalog/mod.rs"]
+pub mod catalog;
+
 #[path = "./constants/mod.rs"]
 pub mod constants;
 
+#[path = "./dialect/mod.rs"]
+pub mod dialect;
+
 #[path = "./errors/mod.rs"]
 pub mod errors;
 
@@ -16,5 +22,19 @@ pub mod logger;
 #[path = "./parser/mod.rs"]
 pub mod parser;
 
+#[path = "./renderer/mod.rs"]
+pub mod renderer;
+
 #[path = "./utils/mod.rs"]
 pub mod utils;
+
+#[path = "./visitor/mod.rs"]
+pub mod visitor;
+
+use crate::errors::predule::Error;
+use crate::parser::predule::{Parser, ParserContext};
+
+// SQL 문자열을 분석해서 AST를 JSON 문자열로 반환합니다.
+pub fn parse_to_json(text: &str) -> Result<String, Error> {
+    Parser::new(text.to_owned())?.parse_to_json(ParserContext::default())
+}
@@ -1,11 +1,44 @@
-pub struct Logger {}
+// 라이브러리 내부 로그 매크로
+// `log` 기능을 켜면 log 크레이트로 전달되고, 출력 여부와 위치는 사용하는 쪽의 로거 설정을 따릅니다.
+// 기능을 끄면(기본값) 아무것도 출력하지 않으며, 인자도 평가하지 않습니다.
 
-impl Logger {
-    pub fn error(text: impl Into<String>) {
-        println!("{}", format!("!![ERROR] {}", text.into()));
-    }
+#[cfg(feature = "log")]
+macro_rules! log_at {
+    ($level:ident, $($arg:tt)+) => {
+        log::$level!(target: "ddl_parser", $($arg)+)
+    };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! log_at {
+    ($level:ident, $($arg:tt)+) => {
+        if false {
+            let _ = format!($($arg)+);
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! log_error {
+    ($($arg:tt)+) => { $crate::logger::predule::log_at!(error, $($arg)+) };
+}
+
+macro_rules! log_warn {
+    ($($arg:tt)+) => { $crate::logger::predule::log_at!(warn, $($arg)+) };
+}
+
+#[allow(unused_macros)]
+macro_rules! log_info {
+    ($($arg:tt)+) => { $crate::logger::predule::log_at!(info, $($arg)+) };
+}
 
-    pub fn info(text: impl Into<String>) {
-        println!("{}", format!("@@[INFO] {}", text.into()));
-    }
+macro_rules! log_debug {
+    ($($arg:tt)+) => { $crate::logger::predule::log_at!(debug, $($arg)+) };
 }
+
+macro_rules! log_trace {
+    ($($arg:tt)+) => { $crate::logger::predule::log_at!(trace, $($arg)+) };
+}
+
+#[allow(unused_imports)]
+pub(crate) use {log_at, log_debug, log_error, log_info, log_trace, log_warn};
@@ -1 +1 @@
-pub use super::logger::*;
+pub(crate) use super::logger::*;
@@ -1,3 +1,35 @@
+#[path = "./cli/mod.rs"]
+mod cli;
+
+use crate::cli::predule::{read_sources, run, Arguments, EXIT_FAILURE, EXIT_USAGE, USAGE};
+
 fn main() {
-    println!("Hello, world!");
+    let arguments = match Arguments::parse(std::env::args().skip(1)) {
+        Ok(arguments) => arguments,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            std::process::exit(EXIT_USAGE);
+        }
+    };
+
+    let sources = match read_sources(&arguments.paths) {
+        Ok(sources) => sources,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            std::process::exit(EXIT_FAILURE);
+        }
+    };
+
+    let code = run(
+        &arguments,
+        &sources,
+        &mut std::io::stdout().lock(),
+        &mut std::io::stderr().lock(),
+    )
+    .unwrap_or_else(|error| {
+        eprintln!("error: {}", error);
+        EXIT_FAILURE
+    });
+
+    std::process::exit(code);
 }
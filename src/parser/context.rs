@@ -3,6 +3,8 @@ pub struct ParserContext {
     pub in_between_clause: bool,
     pub in_parentheses: bool,
     pub default_database: Option<String>,
+    // 첫 오류에서 분석을 중단할지 여부
+    pub strict: bool,
 }
 
 impl ParserContext {
@@ -20,4 +22,9 @@ impl ParserContext {
         self.default_database = Some(default_database);
         self
     }
+
+    pub fn set_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
 }
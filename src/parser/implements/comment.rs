@@ -0,0 +1,72 @@
+use crate::lexer::predule::{Span, Token};
+use crate::parser::predule::Parser;
+
+impl Parser {
+    // 노드 앞에 붙은 코드 주석을 가져옵니다.
+    // 직전에 읽은 토큰과 노드 시작 사이의 주석이 대상이며, 그보다 앞의 주석은 버립니다.
+    pub(crate) fn take_leading_comments(&mut self, start: Span) -> Vec<String> {
+        let previous_end = self.last_span().end.offset;
+        let mut comments = vec![];
+
+        while let Some(comment) = self.comments.front() {
+            if comment.span.end.offset > start.start.offset {
+                break;
+            }
+
+            let comment = self.comments.pop_front().unwrap();
+
+            if comment.span.start.offset >= previous_end {
+                comments.extend(comment_text(comment.token));
+            }
+        }
+
+        comments
+    }
+
+    // 노드가 끝나는 줄에 이어서 붙은 코드 주석을 가져옵니다.
+    // 노드 뒤의 구분자(separators)는 건너뛰고, 다음 토큰보다 앞에 있는 주석만 대상입니다.
+    pub(crate) fn take_trailing_comments(
+        &mut self,
+        end: Span,
+        separators: &[Token],
+    ) -> Vec<String> {
+        let next_start = self
+            .tokens
+            .iter()
+            .find(|token| !separators.contains(&token.token))
+            .map(|token| token.span.start.offset);
+
+        // 노드 안쪽의 주석은 어느 노드에도 붙이지 않습니다.
+        while self
+            .comments
+            .front()
+            .is_some_and(|comment| comment.span.start.offset < end.end.offset)
+        {
+            self.comments.pop_front();
+        }
+
+        let mut comments = vec![];
+
+        while let Some(comment) = self.comments.front() {
+            let is_trailing = comment.span.start.line == end.end.line
+                && next_start.is_none_or(|next_start| comment.span.start.offset < next_start);
+
+            if !is_trailing {
+                break;
+            }
+
+            let comment = self.comments.pop_front().unwrap();
+            comments.extend(comment_text(comment.token));
+        }
+
+        comments
+    }
+}
+
+// 주석 기호를 뺀 내용의 앞뒤 공백을 제거합니다.
+fn comment_text(token: Token) -> Option<String> {
+    match token {
+        Token::CodeComment(comment) => Some(comment.trim().to_owned()),
+        _ => None,
+    }
+}
@@ -1,34 +1,47 @@
-use std::error::Error;
+use crate::errors::predule::Error;
 
-use crate::ast::predule::{Column, DataType, TableName};
-use crate::errors::predule::ParsingError;
-use crate::lexer::predule::Token;
+use crate::ast::predule::{
+    CheckConstraint, CodeComments, Column, DataType, DropBehavior, GeneratedColumn, Identity,
+    IdentityKind, TableName,
+};
+use crate::lexer::predule::{OperatorToken, Token};
 use crate::parser::predule::{Parser, ParserContext};
 
 impl Parser {
     // 테이블 컬럼 정의 분석
-    pub(crate) fn parse_table_column(&mut self) -> Result<Column, Box<dyn Error + Send>> {
+    pub(crate) fn parse_table_column(&mut self, context: ParserContext) -> Result<Column, Error> {
         let mut builder = Column::builder();
 
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0001 need more tokens"));
+            return Err(self.unexpected_eof("E0001"));
         }
 
+        let start = self.next_span();
+        let mut end = start;
+
+        let leading = self.take_leading_comments(start);
+
         let current_token = self.get_next_token();
 
         if let Token::Identifier(name) = current_token {
             builder = builder.set_name(name);
         } else {
-            return Err(ParsingError::boxed(format!(
-                "E0028 expected identifier. but your input word is '{:?}'",
-                current_token
-            )));
+            return Err(self.unexpected_token("E0028", &["identifier"], current_token));
         }
 
         let data_type = self.parse_data_type()?;
         builder = builder.set_data_type(data_type);
 
+        // CONSTRAINT constraint_name 으로 지정된 이름은 바로 뒤의 제약조건에 적용
+        let mut constraint_name = None;
+        let mut constraint_start = None;
+        // 같은 컬럼에 PRIMARY KEY가 중복으로 지정되었는지
+        let mut primary_key = false;
+
         loop {
+            // 컬럼 정의는 구분자(, 혹은 괄호) 직전 토큰에서 끝남
+            end = end.union(&self.last_span());
+
             if !self.has_next_token() {
                 break;
             }
@@ -36,59 +49,81 @@ impl Parser {
             let current_token = self.get_next_token();
 
             match current_token {
-                Token::Comma => {
-                    // , 만나면 종료
+                Token::Comma | Token::RightParentheses | Token::SemiColon => {
+                    // ,  ), ; 만나면 종료 (구분자는 호출한 쪽에서 처리)
+                    self.unget_next_token(current_token);
+                    break;
+                }
+                // 컬럼 위치 지정(FIRST | AFTER)은 ALTER TABLE 쪽에서 처리
+                Token::First => {
+                    self.unget_next_token(current_token);
                     break;
                 }
-                Token::RightParentheses => {
-                    // ) 만나면 종료
+                Token::Identifier(_) if current_token.is_word("AFTER") => {
                     self.unget_next_token(current_token);
                     break;
                 }
                 Token::Primary => {
                     if !self.has_next_token() {
-                        return Err(ParsingError::boxed("E0003 need more tokens"));
+                        return Err(self.unexpected_eof("E0003"));
                     }
 
                     let current_token = self.get_next_token();
 
                     match current_token {
                         Token::Key => {
+                            if primary_key {
+                                return Err(self.parsing_error(
+                                    "E0083",
+                                    "PRIMARY KEY specified more than once for column",
+                                ));
+                            }
+
+                            primary_key = true;
                             builder = builder.set_primary_key(true).set_not_null(true);
+
+                            if let Some(name) = constraint_name.take() {
+                                builder = builder.set_primary_key_name(name);
+                            }
                         }
                         _ => {
-                            return Err(ParsingError::boxed(format!(
-                                "expected 'PRIMARY KEY'. but your input word is '{:?}'",
-                                current_token
-                            )));
+                            return Err(self.unexpected_token(
+                                "E0075",
+                                &["PRIMARY KEY"],
+                                current_token,
+                            ));
                         }
                     }
                 }
                 Token::Not => {
                     if !self.has_next_token() {
-                        return Err(ParsingError::boxed("E0004 need more tokens"));
+                        return Err(self.unexpected_eof("E0004"));
                     }
 
                     let current_token = self.get_next_token();
 
                     match current_token {
+                        // NOT NULL 제약조건의 이름은 보관하지 않습니다.
                         Token::Null => {
+                            constraint_name = None;
                             builder = builder.set_not_null(true);
                         }
                         _ => {
-                            return Err(ParsingError::boxed(format!(
-                                "expected 'NOT NULL'. but your input word is '{:?}'",
-                                current_token
-                            )));
+                            return Err(self.unexpected_token(
+                                "E0076",
+                                &["NOT NULL"],
+                                current_token,
+                            ));
                         }
                     }
                 }
                 Token::Null => {
+                    constraint_name = None;
                     builder = builder.set_not_null(false);
                 }
                 Token::Comment => {
                     if !self.has_next_token() {
-                        return Err(ParsingError::boxed("E0005 need more tokens"));
+                        return Err(self.unexpected_eof("E0005"));
                     }
 
                     let current_token = self.get_next_token();
@@ -96,102 +131,710 @@ impl Parser {
                     if let Token::String(comment) = current_token {
                         builder = builder.set_comment(comment);
                     } else {
-                        return Err(ParsingError::boxed(format!(
-                            "expected comment string. but your input word is '{:?}'",
-                            current_token
-                        )));
+                        return Err(self.unexpected_token("E0077", &["string"], current_token));
                     }
                 }
                 Token::Default => {
-                    let default_value = self.parse_expression(ParserContext::default())?;
+                    let default_value = self.parse_expression(context.clone())?;
                     builder = builder.set_default(default_value);
                 }
-                _ => {}
+                Token::Constraint => {
+                    if !self.has_next_token() {
+                        return Err(self.unexpected_eof("E0038"));
+                    }
+
+                    let current_token = self.get_next_token();
+
+                    if let Token::Identifier(name) = current_token {
+                        constraint_name = Some(name);
+                        constraint_start = Some(self.last_span());
+                    } else {
+                        return Err(self.unexpected_token("E0039", &["identifier"], current_token));
+                    }
+
+                    // 제약조건에만 이름을 붙일 수 있음
+                    if !self.has_next_token() {
+                        return Err(self.unexpected_eof("E0081"));
+                    }
+
+                    let current_token = self.get_next_token();
+
+                    if !matches!(
+                        current_token,
+                        Token::Check
+                            | Token::References
+                            | Token::Primary
+                            | Token::Unique
+                            | Token::Not
+                            | Token::Null
+                    ) {
+                        return Err(self.unexpected_token(
+                            "E0082",
+                            &[
+                                "CHECK",
+                                "REFERENCES",
+                                "PRIMARY KEY",
+                                "UNIQUE",
+                                "NOT NULL",
+                                "NULL",
+                            ],
+                            current_token,
+                        ));
+                    }
+
+                    self.unget_next_token(current_token);
+                }
+                Token::Unique => {
+                    // UNIQUE KEY 형태 허용
+                    if self.has_next_token() {
+                        let current_token = self.get_next_token();
+
+                        if Token::Key != current_token {
+                            self.unget_next_token(current_token);
+                        }
+                    }
+
+                    builder = builder.set_unique(true);
+
+                    if let Some(name) = constraint_name.take() {
+                        builder = builder.set_unique_name(name);
+                    }
+                }
+                Token::References => {
+                    self.unget_next_token(current_token);
+                    constraint_start = None;
+
+                    let reference =
+                        self.parse_references(context.clone(), constraint_name.take())?;
+                    builder = builder.set_reference(reference);
+                }
+                Token::Check => {
+                    let start = constraint_start.take().unwrap_or(self.last_span());
+                    let expression = self.parse_expression_in_parentheses(context.clone())?;
+
+                    builder = builder.add_check(CheckConstraint {
+                        constraint_name: constraint_name.take(),
+                        expression,
+                        span: start.union(&self.last_span()).into(),
+                    });
+                }
+                Token::AutoIncrement => {
+                    builder = builder.set_auto_increment(true);
+                }
+                // CHARACTER SET charset_name | CHARSET charset_name (MySQL)
+                Token::Identifier(_)
+                    if self.dialect.supports_column_charset()
+                        && (current_token.is_word("CHARACTER")
+                            || current_token.is_word("CHARSET")) =>
+                {
+                    if current_token.is_word("CHARACTER") {
+                        if !self.has_next_token() {
+                            return Err(self.unexpected_eof("E0084"));
+                        }
+
+                        let current_token = self.get_next_token();
+
+                        if Token::Set != current_token {
+                            return Err(self.unexpected_token("E0085", &["SET"], current_token));
+                        }
+                    }
+
+                    if !self.has_next_token() {
+                        return Err(self.unexpected_eof("E0086"));
+                    }
+
+                    let current_token = self.get_next_token();
+
+                    match current_token {
+                        Token::Identifier(charset) | Token::String(charset) => {
+                            builder = builder.set_charset(charset);
+                        }
+                        _ => {
+                            return Err(self.unexpected_token(
+                                "E0087",
+                                &["character set name"],
+                                current_token,
+                            ));
+                        }
+                    }
+                }
+                Token::Collate => {
+                    if !self.has_next_token() {
+                        return Err(self.unexpected_eof("E0040"));
+                    }
+
+                    let current_token = self.get_next_token();
+
+                    match current_token {
+                        Token::Identifier(collation) | Token::String(collation) => {
+                            builder = builder.set_collation(collation);
+                        }
+                        _ => {
+                            return Err(self.unexpected_token(
+                                "E0041",
+                                &["collation name"],
+                                current_token,
+                            ));
+                        }
+                    }
+                }
+                Token::Identifier(_) if current_token.is_word("GENERATED") => {
+                    let kind = self.parse_generated_kind()?;
+
+                    if !self.has_next_token() {
+                        return Err(self.unexpected_eof("E0042"));
+                    }
+
+                    let current_token = self.get_next_token();
+
+                    if Token::As != current_token {
+                        return Err(self.unexpected_token("E0043", &["AS"], current_token));
+                    }
+
+                    if !self.has_next_token() {
+                        return Err(self.unexpected_eof("E0044"));
+                    }
+
+                    let current_token = self.get_next_token();
+
+                    match current_token {
+                        Token::Identifier(_) if current_token.is_word("IDENTITY") => {
+                            let identity = self.parse_identity_options(kind)?;
+                            builder = builder.set_identity(identity);
+                        }
+                        Token::LeftParentheses if kind == IdentityKind::Always => {
+                            self.unget_next_token(current_token);
+
+                            let generated = self.parse_generated_column(context.clone())?;
+                            builder = builder.set_generated(generated);
+                        }
+                        _ => {
+                            return Err(self.unexpected_token(
+                                "E0045",
+                                &["IDENTITY", "("],
+                                current_token,
+                            ));
+                        }
+                    }
+                }
+                // ON UPDATE expression (MySQL)
+                Token::On if self.dialect.supports_on_update() => {
+                    if !self.has_next_token() {
+                        return Err(self.unexpected_eof("E0073"));
+                    }
+
+                    let current_token = self.get_next_token();
+
+                    if Token::Update != current_token {
+                        return Err(self.unexpected_token("E0074", &["UPDATE"], current_token));
+                    }
+
+                    let expression = self.parse_expression(context.clone())?;
+                    builder = builder.set_on_update(expression);
+                }
+                // GENERATED ALWAYS 가 생략된 MySQL 형태
+                Token::As => {
+                    let generated = self.parse_generated_column(context.clone())?;
+                    builder = builder.set_generated(generated);
+                }
+                _ => {
+                    return Err(self.parsing_error(
+                        "E0046",
+                        format!("unexpected column attribute '{}'", current_token),
+                    ));
+                }
             }
         }
 
-        Ok(builder.build())
+        let code_comments = CodeComments {
+            leading,
+            trailing: self.take_trailing_comments(end, &[Token::Comma]),
+        };
+
+        Ok(builder
+            .set_span(start.union(&end))
+            .set_code_comments(code_comments)
+            .build())
     }
 
-    // 데이터 타입 분석
-    pub(crate) fn parse_data_type(&mut self) -> Result<DataType, Box<dyn Error + Send>> {
+    // ALWAYS | BY DEFAULT
+    pub(crate) fn parse_generated_kind(&mut self) -> Result<IdentityKind, Error> {
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0006 need more tokens"));
+            return Err(self.unexpected_eof("E0047"));
         }
 
         let current_token = self.get_next_token();
 
-        if let Token::Identifier(type_name) = current_token {
-            match type_name.to_uppercase().as_str() {
-                "INTEGER" | "INT" => Ok(DataType::Int),
-                "FLOAT" => Ok(DataType::Float),
-                "BOOLEAN" | "BOOL" => Ok(DataType::Boolean),
-                "VARCHAR" => {
-                    // 여는 괄호 체크
-                    if !self.has_next_token() {
-                        return Err(ParsingError::boxed("E0007 need more tokens"));
-                    }
+        match current_token {
+            Token::Identifier(_) if current_token.is_word("ALWAYS") => Ok(IdentityKind::Always),
+            Token::By => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E0048"));
+                }
 
-                    let current_token = self.get_next_token();
+                let current_token = self.get_next_token();
+
+                if Token::Default == current_token {
+                    Ok(IdentityKind::ByDefault)
+                } else {
+                    Err(self.unexpected_token("E0049", &["DEFAULT"], current_token))
+                }
+            }
+            _ => Err(self.unexpected_token("E0050", &["ALWAYS", "BY DEFAULT"], current_token)),
+        }
+    }
+
+    // [ ( [START [WITH] n] [INCREMENT [BY] n] ) ]
+    pub(crate) fn parse_identity_options(&mut self, kind: IdentityKind) -> Result<Identity, Error> {
+        let mut identity = Identity {
+            kind,
+            start: None,
+            increment: None,
+        };
+
+        if !self.next_token_is_left_parentheses() {
+            return Ok(identity);
+        }
 
-                    if Token::LeftParentheses != current_token {
-                        return Err(ParsingError::boxed(format!(
-                            "expected '('. but your input word is '{:?}'",
-                            current_token
-                        )));
+        // ( 삼킴
+        self.get_next_token();
+
+        loop {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0051"));
+            }
+
+            let current_token = self.get_next_token();
+
+            match current_token {
+                Token::RightParentheses => break,
+                Token::Identifier(_) if current_token.is_word("START") => {
+                    if self.has_next_token() {
+                        let current_token = self.get_next_token();
+
+                        if !current_token.is_word("WITH") {
+                            self.unget_next_token(current_token);
+                        }
                     }
 
-                    // 문자열 길이 체크
-                    if !self.has_next_token() {
-                        return Err(ParsingError::boxed("E0008 need more tokens"));
+                    identity.start = Some(self.parse_signed_integer()?);
+                }
+                Token::Identifier(_) if current_token.is_word("INCREMENT") => {
+                    if self.has_next_token() {
+                        let current_token = self.get_next_token();
+
+                        if Token::By != current_token {
+                            self.unget_next_token(current_token);
+                        }
                     }
 
+                    identity.increment = Some(self.parse_signed_integer()?);
+                }
+                _ => {
+                    return Err(self.parsing_error(
+                        "E0052",
+                        format!("unexpected identity option '{:?}'", current_token),
+                    ));
+                }
+            }
+        }
+
+        Ok(identity)
+    }
+
+    // (expression) [STORED | VIRTUAL]
+    fn parse_generated_column(&mut self, context: ParserContext) -> Result<GeneratedColumn, Error> {
+        let expression = self.parse_expression_in_parentheses(context)?;
+
+        let mut stored = false;
+
+        if self.has_next_token() {
+            let current_token = self.get_next_token();
+
+            if current_token.is_word("STORED") {
+                stored = true;
+            } else if !current_token.is_word("VIRTUAL") {
+                self.unget_next_token(current_token);
+            }
+        }
+
+        Ok(GeneratedColumn { expression, stored })
+    }
+
+    // [-]integer
+    pub(crate) fn parse_signed_integer(&mut self) -> Result<i64, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E0053"));
+        }
+
+        let mut current_token = self.get_next_token();
+        let mut sign = 1;
+
+        if let Token::Operator(OperatorToken::Minus) = current_token {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0054"));
+            }
+
+            sign = -1;
+            current_token = self.get_next_token();
+        }
+
+        if let Token::Integer(integer) = current_token {
+            Ok(sign * integer)
+        } else {
+            Err(self.unexpected_token("E0055", &["integer number"], current_token))
+        }
+    }
+
+    // 데이터 타입 분석
+    pub(crate) fn parse_data_type(&mut self) -> Result<DataType, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E0006"));
+        }
+
+        let current_token = self.get_next_token();
+
+        let type_name = if let Token::Identifier(type_name) = current_token {
+            type_name
+        } else {
+            return Err(self.unexpected_token("E0029", &["identifier"], current_token));
+        };
+
+        let data_type = self.parse_type_name(type_name)?;
+
+        // 배열 타입 (PostgreSQL)
+        // text[][]처럼 여러 번 지정하면 다차원 배열입니다.
+        let mut data_type = data_type;
+
+        while self.dialect.supports_array_types()
+            && self.has_next_token()
+            && self.tokens.front().unwrap().token == Token::LeftBracket
+        {
+            self.get_next_token();
+
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0088"));
+            }
+
+            let current_token = self.get_next_token();
+
+            if Token::RightBracket != current_token {
+                return Err(self.unexpected_token("E0089", &["]"], current_token));
+            }
+
+            data_type = DataType::Array(Box::new(data_type));
+        }
+
+        Ok(data_type)
+    }
+
+    // 타입명과 타입명 뒤의 인자, 수식어 분석
+    fn parse_type_name(&mut self, type_name: String) -> Result<DataType, Error> {
+        // 방언 고유의 타입명 우선 적용
+        if let Some(data_type) = self.dialect.data_type(type_name.to_uppercase().as_str()) {
+            return Ok(data_type);
+        }
+
+        let data_type = match type_name.to_uppercase().as_str() {
+            "TINYINT" | "INT1" => DataType::TinyInt(self.parse_optional_length()?),
+            "SMALLINT" | "INT2" => DataType::SmallInt(self.parse_optional_length()?),
+            "MEDIUMINT" => DataType::MediumInt(self.parse_optional_length()?),
+            // INT(11)의 표시 너비는 다시 출력할 수 있도록 보존
+            "INTEGER" | "INT" | "INT4" => DataType::Int(self.parse_optional_length()?),
+            "BIGINT" | "INT8" => DataType::BigInt(self.parse_optional_length()?),
+            "SMALLSERIAL" | "SERIAL2" => DataType::SmallSerial,
+            "SERIAL" | "SERIAL4" => DataType::Serial,
+            "BIGSERIAL" | "SERIAL8" => DataType::BigSerial,
+            "REAL" | "FLOAT4" => DataType::Real,
+            "FLOAT" => DataType::Float(self.parse_optional_length()?),
+            "DOUBLE" => {
+                if self.next_token_is_word("PRECISION") {
+                    self.get_next_token();
+                }
+
+                DataType::Double
+            }
+            "FLOAT8" => DataType::Double,
+            "DECIMAL" | "DEC" | "NUMERIC" => {
+                let (precision, scale) = self.parse_optional_precision_and_scale()?;
+                DataType::Decimal { precision, scale }
+            }
+            "BOOLEAN" | "BOOL" => DataType::Boolean,
+            "CHAR" | "CHARACTER" | "NCHAR" => {
+                if self.next_token_is_word("VARYING") {
+                    self.get_next_token();
+                    DataType::Varchar(self.parse_optional_length()?)
+                } else {
+                    DataType::Char(self.parse_optional_length()?)
+                }
+            }
+            "VARCHAR" | "NVARCHAR" => DataType::Varchar(self.parse_optional_length()?),
+            "TINYTEXT" => DataType::TinyText,
+            "TEXT" => DataType::Text,
+            "MEDIUMTEXT" => DataType::MediumText,
+            "LONGTEXT" => DataType::LongText,
+            "BINARY" => DataType::Binary(self.parse_optional_length()?),
+            "VARBINARY" => DataType::Varbinary(self.parse_length()?),
+            "BLOB" => DataType::Blob,
+            "LONGBLOB" => DataType::LongBlob,
+            "BYTEA" => DataType::Bytea,
+            "DATE" => DataType::Date,
+            "TIME" => {
+                let precision = self.parse_optional_length()?;
+                let with_time_zone = self.parse_time_zone()?;
+
+                DataType::Time {
+                    precision,
+                    with_time_zone,
+                }
+            }
+            "TIMETZ" => DataType::Time {
+                precision: self.parse_optional_length()?,
+                with_time_zone: true,
+            },
+            "TIMESTAMP" => {
+                let precision = self.parse_optional_length()?;
+                let with_time_zone = self.parse_time_zone()?;
+
+                DataType::Timestamp {
+                    precision,
+                    with_time_zone,
+                }
+            }
+            "TIMESTAMPTZ" => DataType::Timestamp {
+                precision: self.parse_optional_length()?,
+                with_time_zone: true,
+            },
+            "DATETIME" => DataType::DateTime(self.parse_optional_length()?),
+            "INTERVAL" => DataType::Interval,
+            "YEAR" => DataType::Year(self.parse_optional_length()?),
+            "UUID" => DataType::Uuid,
+            "JSON" => DataType::Json,
+            "JSONB" => DataType::Jsonb,
+            "XML" => DataType::Xml,
+            "ENUM" => DataType::Enum(self.parse_string_list()?),
+            _ => {
+                // schema_name.type_name 형태의 사용자 정의 타입
+                let mut type_name = type_name;
+
+                if self.has_next_token() {
                     let current_token = self.get_next_token();
 
-                    if let Token::Integer(integer) = current_token {
-                        // 닫는 괄호 체크
+                    if Token::Period == current_token {
                         if !self.has_next_token() {
-                            return Err(ParsingError::boxed("E0009 need more tokens"));
+                            return Err(self.unexpected_eof("E0056"));
                         }
 
                         let current_token = self.get_next_token();
 
-                        if Token::RightParentheses != current_token {
-                            return Err(ParsingError::boxed(format!(
-                                "expected ')'. but your input word is '{:?}'",
-                                current_token
-                            )));
+                        if let Token::Identifier(name) = current_token {
+                            type_name = format!("{}.{}", type_name, name);
+                        } else {
+                            return Err(self.unexpected_token(
+                                "E0057",
+                                &["identifier"],
+                                current_token,
+                            ));
                         }
-
-                        Ok(DataType::Varchar(integer))
                     } else {
-                        Err(ParsingError::boxed(format!(
-                            "expected integer number. but your input word is '{:?}'",
-                            current_token
-                        )))
+                        self.unget_next_token(current_token);
                     }
                 }
-                _ => Err(ParsingError::boxed(format!(
-                    "unknown data type '{}'",
-                    type_name
-                ))),
+
+                DataType::Custom(type_name)
+            }
+        };
+
+        // 정수 및 실수 타입의 SIGNED, UNSIGNED, ZEROFILL 수식어 (MySQL)
+        // 지정한 순서대로 감싸서 다시 출력할 수 있도록 보존합니다.
+        let mut data_type = data_type;
+        let mut modifiers: Vec<&str> = vec![];
+
+        while let Some(modifier) = ["SIGNED", "UNSIGNED", "ZEROFILL"]
+            .into_iter()
+            .find(|modifier| self.next_token_is_word(modifier))
+        {
+            self.get_next_token();
+
+            if modifiers.contains(&modifier) {
+                return Err(
+                    self.parsing_error("E0090", format!("duplicate type modifier '{}'", modifier))
+                );
             }
+
+            if (modifier == "SIGNED" && modifiers.contains(&"UNSIGNED"))
+                || (modifier == "UNSIGNED" && modifiers.contains(&"SIGNED"))
+            {
+                return Err(
+                    self.parsing_error("E0091", "SIGNED and UNSIGNED cannot be used together")
+                );
+            }
+
+            modifiers.push(modifier);
+
+            data_type = match modifier {
+                "SIGNED" => DataType::Signed(Box::new(data_type)),
+                "UNSIGNED" => DataType::Unsigned(Box::new(data_type)),
+                _ => DataType::Zerofill(Box::new(data_type)),
+            };
+        }
+
+        Ok(data_type)
+    }
+
+    // (n) 형태의 필수 길이 분석
+    fn parse_length(&mut self) -> Result<i64, Error> {
+        if !self.next_token_is_left_parentheses() {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0007"));
+            }
+
+            let current_token = self.get_next_token();
+
+            return Err(self.unexpected_token("E0058", &["("], current_token));
+        }
+
+        match self.parse_optional_length()? {
+            Some(length) => Ok(length),
+            None => Err(self.parsing_error("E0059", "expected length")),
+        }
+    }
+
+    // [(n)] 형태의 선택적 길이 분석
+    fn parse_optional_length(&mut self) -> Result<Option<i64>, Error> {
+        let (length, scale) = self.parse_optional_precision_and_scale()?;
+
+        if scale.is_some() {
+            return Err(self.parsing_error("E0060", "expected only one number"));
+        }
+
+        Ok(length)
+    }
+
+    // [(precision [, scale])] 형태의 선택적 정밀도 분석
+    fn parse_optional_precision_and_scale(&mut self) -> Result<(Option<i64>, Option<i64>), Error> {
+        if !self.next_token_is_left_parentheses() {
+            return Ok((None, None));
+        }
+
+        // ( 삼킴
+        self.get_next_token();
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E0008"));
+        }
+
+        let current_token = self.get_next_token();
+
+        let precision = if let Token::Integer(integer) = current_token {
+            integer
         } else {
-            Err(ParsingError::boxed(format!(
-                "E0029 expected identifier. but your input word is '{:?}'",
-                current_token
-            )))
+            return Err(self.unexpected_token("E0061", &["integer number"], current_token));
+        };
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E0009"));
+        }
+
+        let current_token = self.get_next_token();
+
+        match current_token {
+            Token::RightParentheses => Ok((Some(precision), None)),
+            Token::Comma => {
+                let scale = self.parse_signed_integer()?;
+
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E0062"));
+                }
+
+                let current_token = self.get_next_token();
+
+                if Token::RightParentheses != current_token {
+                    return Err(self.unexpected_token("E0063", &[")"], current_token));
+                }
+
+                Ok((Some(precision), Some(scale)))
+            }
+            _ => Err(self.unexpected_token("E0064", &[",", ")"], current_token)),
+        }
+    }
+
+    // [{WITH | WITHOUT} TIME ZONE]
+    fn parse_time_zone(&mut self) -> Result<bool, Error> {
+        let with_time_zone = if self.next_token_is_word("WITH") {
+            true
+        } else if self.next_token_is_word("WITHOUT") {
+            false
+        } else {
+            return Ok(false);
+        };
+
+        // WITH, WITHOUT 삼킴
+        self.get_next_token();
+
+        for word in ["TIME", "ZONE"] {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0065"));
+            }
+
+            let current_token = self.get_next_token();
+
+            if !current_token.is_word(word) {
+                return Err(self.unexpected_token("E0066", &[word], current_token));
+            }
+        }
+
+        Ok(with_time_zone)
+    }
+
+    // ('a', 'b', ...) 형태의 문자열 목록 분석
+    fn parse_string_list(&mut self) -> Result<Vec<String>, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E0067"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if Token::LeftParentheses != current_token {
+            return Err(self.unexpected_token("E0068", &["("], current_token));
+        }
+
+        let mut values = vec![];
+
+        loop {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0069"));
+            }
+
+            let current_token = self.get_next_token();
+
+            if let Token::String(value) = current_token {
+                values.push(value);
+            } else {
+                return Err(self.unexpected_token("E0070", &["string"], current_token));
+            }
+
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0071"));
+            }
+
+            let current_token = self.get_next_token();
+
+            match current_token {
+                Token::Comma => continue,
+                Token::RightParentheses => break,
+                _ => return Err(self.unexpected_token("E0072", &[",", ")"], current_token)),
+            }
         }
+
+        Ok(values)
     }
 
     // 테이블명 분석
-    pub(crate) fn parse_table_name(
-        &mut self,
-        context: ParserContext,
-    ) -> Result<TableName, Box<dyn Error + Send>> {
+    pub(crate) fn parse_table_name(&mut self, context: ParserContext) -> Result<TableName, Error> {
         // 테이블명 획득 로직
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0010 need more tokens"));
+            return Err(self.unexpected_eof("E0010"));
         }
 
         // 첫번째로 오는 이름은 테이블명으로 추정
@@ -201,10 +844,7 @@ impl Parser {
         let mut table_name = if let Token::Identifier(name) = current_token {
             name
         } else {
-            return Err(ParsingError::boxed(format!(
-                "E0030 expected identifier. but your input word is '{:?}'",
-                current_token
-            )));
+            return Err(self.unexpected_token("E0030", &["identifier"], current_token));
         };
 
         if !self.has_next_token() {
@@ -219,7 +859,7 @@ impl Parser {
         // .가 있을 경우 "데이터베이스명"."테이블명"의 형태로 추정
         if current_token == Token::Period {
             if !self.has_next_token() {
-                return Err(ParsingError::boxed("E0012 need more tokens"));
+                return Err(self.unexpected_eof("E0012"));
             }
 
             let current_token = self.get_next_token();
@@ -228,10 +868,7 @@ impl Parser {
                 database_name = Some(table_name);
                 table_name = name;
             } else {
-                return Err(ParsingError::boxed(format!(
-                    "E0031 expected identifier. but your input word is '{:?}'",
-                    current_token
-                )));
+                return Err(self.unexpected_token("E0031", &["identifier"], current_token));
             }
         } else {
             self.unget_next_token(current_token);
@@ -243,25 +880,68 @@ impl Parser {
         ))
     }
 
+    // (column_name [, ...]) 형태의 컬럼명 목록 분석
+    pub(crate) fn parse_column_name_list(&mut self) -> Result<Vec<String>, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E0032"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if Token::LeftParentheses != current_token {
+            return Err(self.unexpected_token("E0033", &["("], current_token));
+        }
+
+        let mut columns = vec![];
+
+        loop {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0034"));
+            }
+
+            let current_token = self.get_next_token();
+
+            if let Token::Identifier(column_name) = current_token {
+                columns.push(column_name);
+            } else {
+                return Err(self.unexpected_token("E0035", &["identifier"], current_token));
+            }
+
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0036"));
+            }
+
+            let current_token = self.get_next_token();
+
+            match current_token {
+                Token::Comma => continue,
+                Token::RightParentheses => break,
+                _ => return Err(self.unexpected_token("E0037", &[",", ")"], current_token)),
+            }
+        }
+
+        Ok(columns)
+    }
+
     // IF NOT EXISTS 체크 로직
-    pub(crate) fn has_if_not_exists(&mut self) -> Result<bool, Box<dyn Error + Send>> {
+    pub(crate) fn has_if_not_exists(&mut self) -> Result<bool, Error> {
         // 테이블명 획득 로직
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0013 need more tokens"));
+            return Err(self.unexpected_eof("E0013"));
         }
 
         let current_token = self.get_next_token();
 
         if Token::If == current_token {
             if !self.has_next_token() {
-                return Err(ParsingError::boxed("E0014 need more tokens"));
+                return Err(self.unexpected_eof("E0014"));
             }
 
             let current_token = self.get_next_token();
 
             if Token::Not == current_token {
                 if !self.has_next_token() {
-                    return Err(ParsingError::boxed("E0015 need more tokens"));
+                    return Err(self.unexpected_eof("E0015"));
                 }
 
                 let current_token = self.get_next_token();
@@ -269,16 +949,10 @@ impl Parser {
                 if Token::Exists == current_token {
                     Ok(true)
                 } else {
-                    Err(ParsingError::boxed(format!(
-                        "expected keyword is 'exists'. but your input word is '{:?}'",
-                        current_token
-                    )))
+                    Err(self.unexpected_token("E0078", &["EXISTS"], current_token))
                 }
             } else {
-                Err(ParsingError::boxed(format!(
-                    "expected keyword is 'not'. but your input word is '{:?}'",
-                    current_token
-                )))
+                Err(self.unexpected_token("E0079", &["NOT"], current_token))
             }
         } else {
             self.unget_next_token(current_token);
@@ -286,18 +960,36 @@ impl Parser {
         }
     }
 
+    // [CASCADE | RESTRICT]
+    pub(crate) fn parse_drop_behavior(&mut self) -> Option<DropBehavior> {
+        if !self.has_next_token() {
+            return None;
+        }
+
+        let current_token = self.get_next_token();
+
+        match current_token {
+            Token::Cascade => Some(DropBehavior::Cascade),
+            Token::Restrict => Some(DropBehavior::Restrict),
+            _ => {
+                self.unget_next_token(current_token);
+                None
+            }
+        }
+    }
+
     // IF EXISTS 체크 로직
-    pub(crate) fn has_if_exists(&mut self) -> Result<bool, Box<dyn Error + Send>> {
+    pub(crate) fn has_if_exists(&mut self) -> Result<bool, Error> {
         // 테이블명 획득 로직
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0016 need more tokens"));
+            return Err(self.unexpected_eof("E0016"));
         }
 
         let current_token = self.get_next_token();
 
         if Token::If == current_token {
             if !self.has_next_token() {
-                return Err(ParsingError::boxed("E0017 need more tokens"));
+                return Err(self.unexpected_eof("E0017"));
             }
 
             let current_token = self.get_next_token();
@@ -305,10 +997,7 @@ impl Parser {
             if Token::Exists == current_token {
                 Ok(true)
             } else {
-                Err(ParsingError::boxed(format!(
-                    "expected keyword is 'exists'. but your input word is '{:?}'",
-                    current_token
-                )))
+                Err(self.unexpected_token("E0080", &["EXISTS"], current_token))
             }
         } else {
             self.unget_next_token(current_token);
@@ -441,6 +1130,20 @@ impl Parser {
         }
     }
 
+    // 다음 토큰이 여는 괄호인지
+    pub(crate) fn next_token_is_left_parentheses(&mut self) -> bool {
+        if !self.has_next_token() {
+            false
+        } else {
+            let first_token = self.get_next_token();
+
+            let is_left_parentheses = first_token == Token::LeftParentheses;
+
+            self.unget_next_token(first_token);
+            is_left_parentheses
+        }
+    }
+
     // 다음 토큰이 default인지
     pub(crate) fn next_token_is_default(&mut self) -> bool {
         if !self.has_next_token() {
@@ -460,4 +1163,18 @@ impl Parser {
             }
         }
     }
+
+    // 다음 토큰이 주어진 단어인지
+    pub(crate) fn next_token_is_word(&mut self, word: &str) -> bool {
+        if !self.has_next_token() {
+            false
+        } else {
+            let first_token = self.get_next_token();
+
+            let is_word = first_token.is_word(word);
+
+            self.unget_next_token(first_token);
+            is_word
+        }
+    }
 }
@@ -0,0 +1,215 @@
+use crate::ast::predule::{
+    AlterColumnAction, AlterColumnSetComment, AlterTableAction, AlterTableAlterColumn,
+    AlterTableQuery, AlterTableSetTableOption, DDLStatement, SQLStatement, TableName, TableOption,
+};
+use crate::errors::predule::Error;
+use crate::lexer::predule::Token;
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+
+impl Parser {
+    // COMMENT ON { TABLE table_name | COLUMN table_name.column_name } IS { 'comment' | NULL } (PostgreSQL)
+    // 주석을 바꾸는 ALTER TABLE 문으로 분석합니다.
+    // 컬럼 주석은 ALTER COLUMN ... SET COMMENT, 테이블 주석은 COMMENT 테이블 옵션으로 표현합니다.
+    pub(crate) fn handle_comment_on_query(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<SQLStatement, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1111"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if Token::On != current_token {
+            return Err(self.unexpected_token("E1112", &["ON"], current_token));
+        }
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1113"));
+        }
+
+        let current_token = self.get_next_token();
+
+        let (table, column_name) = match current_token {
+            Token::Table => (self.parse_table_name(context)?, None),
+            Token::Column => {
+                let (table, column_name) = self.parse_column_path(context)?;
+                (table, Some(column_name))
+            }
+            _ => {
+                return Err(self.unexpected_token("E1114", &["TABLE", "COLUMN"], current_token));
+            }
+        };
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1115"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if Token::Is != current_token {
+            return Err(self.unexpected_token("E1116", &["IS"], current_token));
+        }
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1117"));
+        }
+
+        let current_token = self.get_next_token();
+
+        // IS NULL은 주석을 삭제합니다.
+        let comment = match current_token {
+            Token::String(comment) => Some(comment),
+            Token::Null => None,
+            _ => {
+                return Err(self.unexpected_token("E1118", &["string", "NULL"], current_token));
+            }
+        };
+
+        let action: AlterTableAction = match column_name {
+            Some(column_name) => AlterTableAlterColumn {
+                column_name,
+                action: match comment {
+                    Some(comment) => AlterColumnSetComment { comment }.into(),
+                    None => AlterColumnAction::AlterColumnDropComment,
+                },
+            }
+            .into(),
+            None => AlterTableSetTableOption {
+                option: TableOption::new("COMMENT", Some(comment.unwrap_or_default())),
+            }
+            .into(),
+        };
+
+        Ok(AlterTableQuery::builder()
+            .set_table(table)
+            .add_action(action)
+            .build())
+    }
+
+    // [database_name.]table_name.column_name
+    fn parse_column_path(&mut self, context: ParserContext) -> Result<(TableName, String), Error> {
+        let mut names = vec![];
+
+        loop {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E1119"));
+            }
+
+            let current_token = self.get_next_token();
+
+            match current_token {
+                Token::Identifier(name) => names.push(name),
+                _ => return Err(self.unexpected_token("E1120", &["identifier"], current_token)),
+            }
+
+            if names.len() < 3
+                && self.has_next_token()
+                && self.tokens.front().unwrap().token == Token::Period
+            {
+                self.get_next_token();
+            } else {
+                break;
+            }
+        }
+
+        let column_name = names.pop().unwrap();
+
+        let table = match names.len() {
+            1 => TableName::new(context.default_database, names.remove(0)),
+            2 => TableName::new(Some(names.remove(0)), names.remove(0)),
+            _ => {
+                return Err(
+                    self.parsing_error("E1121", "column name must be qualified with a table name")
+                );
+            }
+        };
+
+        Ok((table, column_name))
+    }
+}
+
+// COMMENT ON 문을 바로 앞의 같은 테이블에 대한 CREATE TABLE, ALTER TABLE 문에 합칩니다.
+// 합칠 수 없다면 주석을 돌려줍니다.
+// 방언에 따라 컬럼 정의 안의 주석을 COMMENT ON 문으로 나눠서 출력하므로, 다시 분석할 때 원래의 문장으로 되돌립니다.
+pub(crate) fn merge_comment_on(
+    previous: Option<&mut SQLStatement>,
+    comment: SQLStatement,
+) -> Option<SQLStatement> {
+    let SQLStatement::DDL(DDLStatement::AlterTableQuery(query)) = &comment else {
+        return Some(comment);
+    };
+
+    let (Some(table), [action]) = (&query.table, query.actions.as_slice()) else {
+        return Some(comment);
+    };
+
+    match previous {
+        Some(SQLStatement::DDL(DDLStatement::CreateTableQuery(previous)))
+            if previous.table.as_ref() == Some(table) =>
+        {
+            match action {
+                AlterTableAction::AlterColumn(action) => {
+                    let Some(column) = previous
+                        .columns
+                        .iter_mut()
+                        .find(|column| column.name == action.column_name)
+                    else {
+                        return Some(comment);
+                    };
+
+                    column.comment = match &action.action {
+                        AlterColumnAction::AlterColumnSetComment(action) => action.comment.clone(),
+                        _ => "".into(),
+                    };
+                }
+                AlterTableAction::SetTableOption(action) => {
+                    let table_options = previous.table_options.get_or_insert_with(Default::default);
+
+                    match table_options
+                        .options
+                        .iter_mut()
+                        .find(|option| option.name == action.option.name)
+                    {
+                        Some(option) => *option = action.option.clone(),
+                        None => table_options.options.push(action.option.clone()),
+                    }
+                }
+                _ => return Some(comment),
+            }
+
+            None
+        }
+        Some(SQLStatement::DDL(DDLStatement::AlterTableQuery(previous)))
+            if previous.table.as_ref() == Some(table) =>
+        {
+            // 같은 문장에서 추가하거나 다시 정의한 컬럼이라면 컬럼 정의에 합칩니다.
+            if let AlterTableAction::AlterColumn(action) = action {
+                let defined = previous.actions.iter_mut().find_map(|previous| {
+                    match previous {
+                        AlterTableAction::AddColumn(previous) => Some(&mut previous.column),
+                        AlterTableAction::ModifyColumn(previous) => Some(&mut previous.column),
+                        AlterTableAction::ChangeColumn(previous) => Some(&mut previous.column),
+                        _ => None,
+                    }
+                    .filter(|column| column.name == action.column_name)
+                });
+
+                if let Some(column) = defined {
+                    column.comment = match &action.action {
+                        AlterColumnAction::AlterColumnSetComment(action) => action.comment.clone(),
+                        _ => "".into(),
+                    };
+
+                    return None;
+                }
+            }
+
+            previous.actions.push(action.clone());
+
+            None
+        }
+        _ => Some(comment),
+    }
+}
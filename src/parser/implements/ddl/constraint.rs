@@ -0,0 +1,298 @@
+use crate::ast::predule::{
+    CheckConstraint, ForeignKey, PrimaryKey, Reference, ReferentialAction, TableConstraint,
+    TableName, UniqueKey,
+};
+use crate::errors::predule::Error;
+use crate::lexer::predule::Token;
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+
+impl Parser {
+    // 테이블 제약조건 분석
+    // [CONSTRAINT constraint_name] { PRIMARY KEY | UNIQUE | FOREIGN KEY | CHECK } ...
+    pub(crate) fn parse_table_constraint(
+        &mut self,
+        context: ParserContext,
+        table: TableName,
+    ) -> Result<TableConstraint, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1401"));
+        }
+
+        let start = self.next_span();
+
+        let mut current_token = self.get_next_token();
+
+        // CONSTRAINT constraint_name 파싱
+        let mut constraint_name = None;
+
+        if Token::Constraint == current_token {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E1402"));
+            }
+
+            let name_token = self.get_next_token();
+
+            if let Token::Identifier(name) = name_token {
+                constraint_name = Some(name);
+            } else {
+                return Err(self.unexpected_token("E1403", &["identifier"], name_token));
+            }
+
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E1404"));
+            }
+
+            current_token = self.get_next_token();
+        }
+
+        match current_token {
+            Token::Primary => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1405"));
+                }
+
+                let current_token = self.get_next_token();
+
+                if Token::Key != current_token {
+                    return Err(self.unexpected_token("E1406", &["KEY"], current_token));
+                }
+
+                let columns = self.parse_column_name_list()?;
+
+                Ok(PrimaryKey {
+                    key_name: constraint_name,
+                    columns,
+                    span: start.union(&self.last_span()).into(),
+                }
+                .into())
+            }
+            Token::Unique => {
+                // UNIQUE KEY, UNIQUE INDEX 형태 허용
+                if self.next_token_is_key_or_index() {
+                    self.get_next_token();
+                }
+
+                let key_name = self.parse_optional_key_name()?;
+                let columns = self.parse_column_name_list()?;
+
+                Ok(UniqueKey {
+                    key_name: constraint_name.or(key_name),
+                    database_name: table.database_name,
+                    columns,
+                    span: start.union(&self.last_span()).into(),
+                }
+                .into())
+            }
+            Token::Foreign => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1407"));
+                }
+
+                let current_token = self.get_next_token();
+
+                if Token::Key != current_token {
+                    return Err(self.unexpected_token("E1408", &["KEY"], current_token));
+                }
+
+                let key_name = self.parse_optional_key_name()?;
+                let columns = self.parse_column_name_list()?;
+
+                let reference = self.parse_references(context, constraint_name.or(key_name))?;
+
+                Ok(ForeignKey {
+                    key_name: reference.key_name,
+                    table,
+                    columns,
+                    referenced_table: reference.referenced_table,
+                    referenced_columns: reference.referenced_columns,
+                    on_delete: reference.on_delete,
+                    on_update: reference.on_update,
+                    span: start.union(&self.last_span()).into(),
+                }
+                .into())
+            }
+            Token::Check => {
+                let expression = self.parse_expression_in_parentheses(context)?;
+
+                Ok(CheckConstraint {
+                    constraint_name,
+                    expression,
+                    span: start.union(&self.last_span()).into(),
+                }
+                .into())
+            }
+            _ => Err(self.unexpected_token(
+                "E1409",
+                &["PRIMARY KEY", "UNIQUE", "FOREIGN KEY", "CHECK"],
+                current_token,
+            )),
+        }
+    }
+
+    // REFERENCES table_name [(column_name [, ...])] [ON DELETE action] [ON UPDATE action]
+    pub(crate) fn parse_references(
+        &mut self,
+        context: ParserContext,
+        key_name: Option<String>,
+    ) -> Result<Reference, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1410"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if Token::References != current_token {
+            return Err(self.unexpected_token("E1411", &["REFERENCES"], current_token));
+        }
+
+        let referenced_table = self.parse_table_name(context)?;
+
+        // 참조 컬럼 목록은 생략 가능
+        let referenced_columns = if self.next_token_is_left_parentheses() {
+            self.parse_column_name_list()?
+        } else {
+            vec![]
+        };
+
+        let mut on_delete = None;
+        let mut on_update = None;
+
+        // ON DELETE, ON UPDATE 순서 무관
+        loop {
+            if !self.has_next_token() {
+                break;
+            }
+
+            let current_token = self.get_next_token();
+
+            if Token::On != current_token {
+                self.unget_next_token(current_token);
+                break;
+            }
+
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E1412"));
+            }
+
+            let current_token = self.get_next_token();
+
+            match current_token {
+                Token::Delete => {
+                    on_delete = Some(self.parse_referential_action()?);
+                }
+                Token::Update => {
+                    on_update = Some(self.parse_referential_action()?);
+                }
+                _ => {
+                    return Err(self.unexpected_token(
+                        "E1413",
+                        &["DELETE", "UPDATE"],
+                        current_token,
+                    ))
+                }
+            }
+        }
+
+        Ok(Reference {
+            key_name,
+            referenced_table,
+            referenced_columns,
+            on_delete,
+            on_update,
+        })
+    }
+
+    // CASCADE | SET NULL | SET DEFAULT | RESTRICT | NO ACTION
+    pub(crate) fn parse_referential_action(&mut self) -> Result<ReferentialAction, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1414"));
+        }
+
+        let current_token = self.get_next_token();
+
+        match current_token {
+            Token::Cascade => Ok(ReferentialAction::Cascade),
+            Token::Restrict => Ok(ReferentialAction::Restrict),
+            Token::Set => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1415"));
+                }
+
+                let current_token = self.get_next_token();
+
+                match current_token {
+                    Token::Null => Ok(ReferentialAction::SetNull),
+                    Token::Default => Ok(ReferentialAction::SetDefault),
+                    _ => Err(self.unexpected_token("E1416", &["NULL", "DEFAULT"], current_token)),
+                }
+            }
+            // NO, ACTION은 컬럼명으로도 흔히 쓰이므로 키워드로 분리하지 않음
+            Token::Identifier(_) if current_token.is_word("NO") => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1417"));
+                }
+
+                let current_token = self.get_next_token();
+
+                match current_token {
+                    Token::Identifier(_) if current_token.is_word("ACTION") => {
+                        Ok(ReferentialAction::NoAction)
+                    }
+                    _ => Err(self.unexpected_token("E1418", &["ACTION"], current_token)),
+                }
+            }
+            _ => Err(self.parsing_error(
+                "E1419",
+                format!("unknown referential action '{:?}'", current_token),
+            )),
+        }
+    }
+
+    // 키 이름은 생략 가능
+    fn parse_optional_key_name(&mut self) -> Result<Option<String>, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1420"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if let Token::Identifier(key_name) = current_token {
+            Ok(Some(key_name))
+        } else {
+            self.unget_next_token(current_token);
+            Ok(None)
+        }
+    }
+
+    // 다음 토큰이 테이블 제약조건의 시작인지
+    pub(crate) fn next_token_is_table_constraint(&mut self) -> bool {
+        if !self.has_next_token() {
+            false
+        } else {
+            let current_token = self.get_next_token();
+
+            let is_constraint = matches!(
+                current_token,
+                Token::Constraint | Token::Primary | Token::Unique | Token::Foreign | Token::Check
+            );
+
+            self.unget_next_token(current_token);
+            is_constraint
+        }
+    }
+
+    // 다음 토큰이 KEY 혹은 INDEX인지
+    fn next_token_is_key_or_index(&mut self) -> bool {
+        if !self.has_next_token() {
+            false
+        } else {
+            let current_token = self.get_next_token();
+
+            let is_key_or_index = matches!(current_token, Token::Key | Token::Index);
+
+            self.unget_next_token(current_token);
+            is_key_or_index
+        }
+    }
+}
@@ -2,17 +2,14 @@ use crate::ast::ddl::{AlterDatabaseAction, AlterDatabaseQuery, AlterDatabaseRena
 use crate::parser::predule::Parser;
 
 use crate::ast::predule::{CreateDatabaseQuery, DropDatabaseQuery, SQLStatement};
-use crate::errors::predule::ParsingError;
+use crate::errors::predule::Error;
 use crate::lexer::predule::Token;
-use std::error::Error;
 
 impl Parser {
     // CREATE DATABASE 쿼리 분석
-    pub(crate) fn handle_create_database_query(
-        &mut self,
-    ) -> Result<SQLStatement, Box<dyn Error + Send>> {
+    pub(crate) fn handle_create_database_query(&mut self) -> Result<SQLStatement, Error> {
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0101 need more tokens"));
+            return Err(self.unexpected_eof("E0101"));
         }
 
         let mut query_builder = CreateDatabaseQuery::builder();
@@ -22,7 +19,7 @@ impl Parser {
         query_builder = query_builder.set_if_not_exists(if_not_exists);
 
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0102 need more tokens"));
+            return Err(self.unexpected_eof("E0102"));
         }
 
         let current_token = self.get_next_token();
@@ -32,7 +29,8 @@ impl Parser {
                 query_builder = query_builder.set_name(identifier);
             }
             _ => {
-                return Err(ParsingError::boxed(
+                return Err(self.parsing_error(
+                    "E0110",
                     "not supported command. possible commands: (create database)",
                 ));
             }
@@ -45,41 +43,56 @@ impl Parser {
         let current_token = self.get_next_token();
 
         if Token::SemiColon != current_token {
-            return Err(ParsingError::boxed(format!(
-                "expected ';'. but your input word is '{:?}'",
-                current_token
-            )));
+            return Err(self.unexpected_token("E0111", &[";"], current_token));
         }
 
         Ok(query_builder.build())
     }
 
     // DROP DATABASE 쿼리 분석
-    pub(crate) fn handle_drop_database_query(
-        &mut self,
-    ) -> Result<SQLStatement, Box<dyn Error + Send>> {
+    pub(crate) fn handle_drop_database_query(&mut self) -> Result<SQLStatement, Error> {
         let mut query_builder = DropDatabaseQuery::builder();
 
         // IF EXISTS 파싱
         let if_exists = self.has_if_exists()?;
         query_builder = query_builder.set_if_exists(if_exists);
 
-        // 테이블명 획득 로직
+        // 데이터베이스명 획득 로직
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0104 need more tokens"));
+            return Err(self.unexpected_eof("E0104"));
         }
 
-        let current_token = self.get_next_token();
+        // , 로 구분된 데이터베이스 목록
+        loop {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0115"));
+            }
 
-        match current_token {
-            Token::Identifier(identifier) => {
-                query_builder = query_builder.set_name(identifier);
+            let current_token = self.get_next_token();
+
+            match current_token {
+                Token::Identifier(identifier) => {
+                    query_builder = query_builder.add_name(identifier);
+                }
+                _ => {
+                    return Err(self.unexpected_token("E0112", &["identifier"], current_token));
+                }
             }
-            _ => {
-                return Err(ParsingError::boxed(
-                    "not supported command. possible commands: (create database)",
-                ));
+
+            if !self.has_next_token() {
+                return Ok(query_builder.build());
             }
+
+            let current_token = self.get_next_token();
+
+            if Token::Comma != current_token {
+                self.unget_next_token(current_token);
+                break;
+            }
+        }
+
+        if let Some(drop_behavior) = self.parse_drop_behavior() {
+            query_builder = query_builder.set_drop_behavior(drop_behavior);
         }
 
         // 세미콜론 체크
@@ -90,21 +103,16 @@ impl Parser {
         let current_token = self.get_next_token();
 
         if Token::SemiColon != current_token {
-            return Err(ParsingError::boxed(format!(
-                "expected ';'. but your input word is '{:?}'",
-                current_token
-            )));
+            return Err(self.unexpected_token("E0113", &[";"], current_token));
         }
 
         Ok(query_builder.build())
     }
 
     // ALTER DATABASE 쿼리 분석
-    pub(crate) fn handle_alter_database_query(
-        &mut self,
-    ) -> Result<SQLStatement, Box<dyn Error + Send>> {
+    pub(crate) fn handle_alter_database_query(&mut self) -> Result<SQLStatement, Error> {
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0105 need more tokens"));
+            return Err(self.unexpected_eof("E0105"));
         }
 
         let mut query_builder = AlterDatabaseQuery::builder();
@@ -116,7 +124,8 @@ impl Parser {
                 query_builder = query_builder.set_name(identifier);
             }
             _ => {
-                return Err(ParsingError::boxed(
+                return Err(self.parsing_error(
+                    "E0114",
                     "not supported command. possible commands: (alter database)",
                 ));
             }
@@ -131,24 +140,17 @@ impl Parser {
         match current_token {
             Token::Rename => {
                 if !self.has_next_token() {
-                    return Err(ParsingError::boxed(
-                        "E106: expected 'TO'. but no more token",
-                    ));
+                    return Err(self.unexpected_eof("E0106"));
                 }
 
                 let current_token = self.get_next_token();
 
                 if current_token != Token::To {
-                    return Err(ParsingError::boxed(format!(
-                        "E107: expected 'TO'. but your input word is '{:?}'",
-                        current_token
-                    )));
+                    return Err(self.unexpected_token("E0107", &["TO"], current_token));
                 }
 
                 if !self.has_next_token() {
-                    return Err(ParsingError::boxed(
-                        "E108: expected identifier. but no more token",
-                    ));
+                    return Err(self.unexpected_eof("E0108"));
                 }
 
                 let current_token = self.get_next_token();
@@ -160,18 +162,19 @@ impl Parser {
                         ));
                     }
                     _ => {
-                        return Err(ParsingError::boxed(
-                            "E109: not supported command. possible commands: (alter database)",
+                        return Err(self.parsing_error(
+                            "E0109",
+                            "not supported command. possible commands: (alter database)",
                         ));
                     }
                 }
             }
             Token::SemiColon => {}
             _ => {
-                return Err(ParsingError::boxed(format!(
-                    "E107: not supported syntax'{:?}'",
-                    current_token
-                )));
+                return Err(self.parsing_error(
+                    "E0116",
+                    format!("not supported syntax'{:?}'", current_token),
+                ));
             }
         }
 
@@ -0,0 +1,193 @@
+use crate::ast::predule::{CreateIndexQuery, DropIndexQuery, IndexColumn, SQLStatement, SortOrder};
+use crate::errors::predule::Error;
+use crate::lexer::predule::Token;
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+
+impl Parser {
+    // CREATE [UNIQUE] INDEX 쿼리 분석
+    pub(crate) fn handle_create_index_query(
+        &mut self,
+        context: ParserContext,
+        is_unique: bool,
+    ) -> Result<SQLStatement, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1301"));
+        }
+
+        let mut query_builder = CreateIndexQuery::builder().set_unique(is_unique);
+
+        // IF NOT EXISTS 파싱
+        let if_not_exists = self.has_if_not_exists()?;
+        query_builder = query_builder.set_if_not_exists(if_not_exists);
+
+        // 인덱스명 설정
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1302"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if let Token::Identifier(index_name) = current_token {
+            query_builder = query_builder.set_index_name(index_name);
+        } else {
+            return Err(self.unexpected_token("E1303", &["identifier"], current_token));
+        }
+
+        // ON 체크
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1304"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if Token::On != current_token {
+            return Err(self.unexpected_token("E1305", &["ON"], current_token));
+        }
+
+        // 테이블명 설정
+        let table = self.parse_table_name(context)?;
+        query_builder = query_builder.set_table(table);
+
+        // USING method (PostgreSQL)
+        if self.dialect.supports_index_method() && self.next_token_is_word("USING") {
+            self.get_next_token();
+
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E1316"));
+            }
+
+            let current_token = self.get_next_token();
+
+            if let Token::Identifier(method) = current_token {
+                query_builder = query_builder.set_method(method);
+            } else {
+                return Err(self.unexpected_token("E1317", &["identifier"], current_token));
+            }
+        }
+
+        // 인덱스 컬럼 목록 파싱
+        for column in self.parse_index_column_list()? {
+            query_builder = query_builder.add_column(column);
+        }
+
+        if !self.has_next_token() {
+            return Ok(query_builder.build());
+        }
+
+        let current_token = self.get_next_token();
+
+        if Token::SemiColon != current_token {
+            return Err(self.unexpected_token("E1306", &[";"], current_token));
+        }
+
+        Ok(query_builder.build())
+    }
+
+    // DROP INDEX 쿼리 분석
+    pub(crate) fn handle_drop_index_query(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<SQLStatement, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1314"));
+        }
+
+        // IF EXISTS 파싱
+        let if_exists = self.has_if_exists()?;
+
+        // [database_name.]index_name
+        let index = self.parse_table_name(context.clone())?;
+
+        let mut query_builder = DropIndexQuery::builder()
+            .set_if_exists(if_exists)
+            .set_database_name(index.database_name)
+            .set_index_name(index.table_name);
+
+        if !self.has_next_token() {
+            return Ok(query_builder.build());
+        }
+
+        let current_token = self.get_next_token();
+
+        // ON table_name (MySQL)
+        let current_token = if Token::On == current_token {
+            let table = self.parse_table_name(context)?;
+            query_builder = query_builder.set_table(table);
+
+            if !self.has_next_token() {
+                return Ok(query_builder.build());
+            }
+
+            self.get_next_token()
+        } else {
+            current_token
+        };
+
+        if Token::SemiColon != current_token {
+            return Err(self.unexpected_token("E1315", &[";"], current_token));
+        }
+
+        Ok(query_builder.build())
+    }
+
+    // (column_name [ASC | DESC], ...) 형태의 인덱스 컬럼 목록 분석
+    pub(crate) fn parse_index_column_list(&mut self) -> Result<Vec<IndexColumn>, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1307"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if Token::LeftParentheses != current_token {
+            return Err(self.unexpected_token("E1308", &["("], current_token));
+        }
+
+        let mut columns = vec![];
+
+        loop {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E1309"));
+            }
+
+            let current_token = self.get_next_token();
+
+            let column_name = if let Token::Identifier(column_name) = current_token {
+                column_name
+            } else {
+                return Err(self.unexpected_token("E1310", &["identifier"], current_token));
+            };
+
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E1311"));
+            }
+
+            let mut current_token = self.get_next_token();
+
+            // 정렬 순서는 생략 가능
+            let order = match current_token {
+                Token::Asc => Some(SortOrder::Asc),
+                Token::Desc => Some(SortOrder::Desc),
+                _ => None,
+            };
+
+            if order.is_some() {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1312"));
+                }
+
+                current_token = self.get_next_token();
+            }
+
+            columns.push(IndexColumn::new(column_name, order));
+
+            match current_token {
+                Token::Comma => continue,
+                Token::RightParentheses => break,
+                _ => return Err(self.unexpected_token("E1313", &[",", ")"], current_token)),
+            }
+        }
+
+        Ok(columns)
+    }
+}
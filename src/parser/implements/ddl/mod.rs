@@ -1,3 +1,6 @@
+// impl 블록만 있는 모듈도 기존 경로로 접근할 수 있도록 다시 내보냅니다.
+#![allow(unused_imports)]
+
 pub mod top_level;
 pub use top_level::*;
 
@@ -6,3 +9,12 @@ pub use table::*;
 
 pub mod database;
 pub use database::*;
+
+pub mod index;
+pub use index::*;
+
+pub mod constraint;
+pub use constraint::*;
+
+pub mod comment_on;
+pub use comment_on::*;
@@ -1,23 +1,30 @@
 use crate::ast::ddl::{
-    AlterColumnDropDefault, AlterColumnDropNotNull, AlterColumnSetDefault, AlterColumnSetNotNull,
-    AlterColumnSetType, AlterTableAddColumn, AlterTableAlterColumn, AlterTableDropColumn,
-    AlterTableQuery, AlterTableRenameColumn, AlterTableRenameTo,
+    AlterColumnAction, AlterColumnAddIdentity, AlterColumnDropComment, AlterColumnDropDefault,
+    AlterColumnDropIdentity, AlterColumnDropNotNull, AlterColumnSetCollation,
+    AlterColumnSetComment, AlterColumnSetDefault, AlterColumnSetIdentity, AlterColumnSetNotNull,
+    AlterColumnSetStatistics, AlterColumnSetType, AlterTableAction, AlterTableAddColumn,
+    AlterTableAddConstraint, AlterTableAddIndex, AlterTableAlterColumn, AlterTableChangeColumn,
+    AlterTableDropColumn, AlterTableDropConstraint, AlterTableDropIndex, AlterTableModifyColumn,
+    AlterTableQuery, AlterTableRenameColumn, AlterTableRenameConstraint, AlterTableRenameTo,
+    AlterTableSetTableOption, AlterTableValidateConstraint,
 };
-use crate::ast::predule::{CreateTableQuery, DropTableQuery, SQLStatement};
-use crate::errors::predule::ParsingError;
-use crate::lexer::predule::Token;
+use crate::ast::predule::{
+    ColumnPosition, CreateTableQuery, DropTableQuery, Index, SQLStatement, TableName, TableOption,
+    TableOptions,
+};
+use crate::errors::predule::Error;
+use crate::lexer::predule::{OperatorToken, Token};
 use crate::parser::context::ParserContext;
 use crate::parser::predule::Parser;
-use std::error::Error;
 
 impl Parser {
     // CREATE TABLE 쿼리 분석
     pub(crate) fn handle_create_table_query(
         &mut self,
         context: ParserContext,
-    ) -> Result<SQLStatement, Box<dyn Error + Send>> {
+    ) -> Result<SQLStatement, Error> {
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E1205 need more tokens"));
+            return Err(self.unexpected_eof("E1205"));
         }
 
         let mut query_builder = CreateTableQuery::builder();
@@ -27,27 +34,24 @@ impl Parser {
         query_builder = query_builder.set_if_not_exists(if_not_exists);
 
         // 테이블명 설정
-        let table = self.parse_table_name(context)?;
-        query_builder = query_builder.set_table(table);
+        let table = self.parse_table_name(context.clone())?;
+        query_builder = query_builder.set_table(table.clone());
 
         // 여는 괄호 체크
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E1206 need more tokens"));
+            return Err(self.unexpected_eof("E1206"));
         }
 
         let current_token = self.get_next_token();
 
         if Token::LeftParentheses != current_token {
-            return Err(ParsingError::boxed(format!(
-                "E1207 expected '('. but your input word is '{:?}'",
-                current_token
-            )));
+            return Err(self.unexpected_token("E1207", &["("], current_token));
         }
 
         // 닫는 괄호 나올때까지 행 파싱 반복
         loop {
             if !self.has_next_token() {
-                return Err(ParsingError::boxed("E1208 need more tokens"));
+                return Err(self.unexpected_eof("E1208"));
             }
 
             let current_token = self.get_next_token();
@@ -59,24 +63,72 @@ impl Parser {
                 }
                 _ => {
                     self.unget_next_token(current_token);
-                    let column = self.parse_table_column()?;
-                    query_builder = query_builder.add_column(column);
+
+                    if self.dialect.supports_inline_index()
+                        && matches!(
+                            self.tokens.front().unwrap().token,
+                            Token::Index | Token::Key
+                        )
+                    {
+                        self.get_next_token();
+
+                        let index = self.parse_index_definition()?;
+                        query_builder = query_builder.add_index(index);
+                    } else if self.next_token_is_table_constraint() {
+                        let constraint =
+                            self.parse_table_constraint(context.clone(), table.clone())?;
+                        query_builder = query_builder
+                            .add_constraint(constraint)
+                            .map_err(|error| error.or_span(self.last_span()))?;
+                    } else {
+                        let column = self.parse_table_column(context.clone())?;
+
+                        if column.primary_key && query_builder.has_primary_key() {
+                            return Err(self.parsing_error(
+                                "E1270",
+                                "multiple primary keys for table are not allowed",
+                            ));
+                        }
+
+                        query_builder = query_builder.add_column(column);
+                    }
+
+                    if !self.has_next_token() {
+                        return Err(self.unexpected_eof("E1235"));
+                    }
+
+                    // , 는 삼키고 ) 는 반환
+                    let current_token = self.get_next_token();
+
+                    match current_token {
+                        Token::Comma => {}
+                        Token::RightParentheses => self.unget_next_token(current_token),
+                        _ => {
+                            return Err(self.unexpected_token("E1236", &[",", ")"], current_token))
+                        }
+                    }
                 }
             }
         }
 
         // 닫는 괄호 체크
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E1209 need more tokens"));
+            return Err(self.unexpected_eof("E1209"));
         }
 
         let current_token = self.get_next_token();
 
         if Token::RightParentheses != current_token {
-            return Err(ParsingError::boxed(format!(
-                "E1210 expected ')'. but your input word is '{:?}'",
-                current_token
-            )));
+            return Err(self.unexpected_token("E1210", &[")"], current_token));
+        }
+
+        // 방언에 따라 테이블 옵션 파싱
+        if self.dialect.supports_table_options() {
+            let table_options = self.parse_table_options()?;
+
+            if !table_options.options.is_empty() {
+                query_builder = query_builder.set_table_option(table_options);
+            }
         }
 
         if !self.has_next_token() {
@@ -86,10 +138,7 @@ impl Parser {
         let current_token = self.get_next_token();
 
         if Token::SemiColon != current_token {
-            return Err(ParsingError::boxed(format!(
-                "E1211 expected ';'. but your input word is '{:?}'",
-                current_token
-            )));
+            return Err(self.unexpected_token("E1211", &[";"], current_token));
         }
 
         Ok(query_builder.build())
@@ -99,329 +148,689 @@ impl Parser {
     pub(crate) fn handle_alter_table_query(
         &mut self,
         context: ParserContext,
-    ) -> Result<SQLStatement, Box<dyn Error + Send>> {
+    ) -> Result<SQLStatement, Error> {
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E1201 need more tokens"));
+            return Err(self.unexpected_eof("E1201"));
         }
 
         let mut query_builder = AlterTableQuery::builder();
 
+        // ONLY table_name (PostgreSQL)
+        // ONLY 뒤에 테이블명이 이어지지 않으면 ONLY라는 이름의 테이블로 봅니다.
+        if self.dialect.supports_alter_table_only()
+            && self.next_token_is_word("ONLY")
+            && matches!(
+                self.tokens.get(1).map(|token| &token.token),
+                Some(Token::Identifier(_))
+            )
+        {
+            self.get_next_token();
+            query_builder = query_builder.set_only(true);
+        }
+
         let table_name = self.parse_table_name(context.clone())?;
 
-        query_builder = query_builder.set_table(table_name);
+        query_builder = query_builder.set_table(table_name.clone());
 
+        // 동작 없이 끝나는 경우
         if !self.has_next_token() {
-            return Ok(query_builder.build());
+            return Err(self.unexpected_eof("E1243"));
+        }
+
+        if self.tokens.front().unwrap().token == Token::SemiColon {
+            let current_token = self.get_next_token();
+
+            return Err(self.unexpected_token("E1243", &["action"], current_token));
+        }
+
+        // , 로 구분된 동작 목록
+        loop {
+            let action = self.parse_alter_table_action(context.clone(), &table_name)?;
+            query_builder = query_builder.add_action(action);
+
+            if !self.has_next_token() {
+                break;
+            }
+
+            let current_token = self.get_next_token();
+
+            match current_token {
+                Token::Comma => {}
+                Token::SemiColon => {
+                    self.unget_next_token(current_token);
+                    break;
+                }
+                _ => return Err(self.unexpected_token("E1242", &[",", ";"], current_token)),
+            }
+        }
+
+        Ok(query_builder.build())
+    }
+
+    // ALTER TABLE의 동작 하나를 분석합니다.
+    fn parse_alter_table_action(
+        &mut self,
+        context: ParserContext,
+        table: &TableName,
+    ) -> Result<AlterTableAction, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1243"));
         }
 
         let current_token = self.get_next_token();
 
         match current_token {
-            Token::SemiColon => return Ok(query_builder.build()),
-            Token::Rename => {
+            Token::Rename => self.parse_alter_table_rename(),
+            Token::Add => self.parse_alter_table_add(context, table),
+            Token::Drop => self.parse_alter_table_drop(),
+            Token::Alter => self.parse_alter_table_alter_column(context),
+            Token::Identifier(_) if current_token.is_word("MODIFY") => {
+                self.parse_alter_table_modify(context)
+            }
+            Token::Identifier(_) if current_token.is_word("CHANGE") => {
+                self.parse_alter_table_change(context)
+            }
+            // VALIDATE CONSTRAINT constraint_name
+            Token::Identifier(_) if current_token.is_word("VALIDATE") => {
                 if !self.has_next_token() {
-                    return Err(ParsingError::boxed("E1212 need more tokens"));
+                    return Err(self.unexpected_eof("E1244"));
                 }
 
                 let current_token = self.get_next_token();
 
-                match current_token {
-                    // table name rename
-                    Token::To => {
-                        if !self.has_next_token() {
-                            return Err(ParsingError::boxed("E1213 need more tokens"));
-                        }
+                if Token::Constraint != current_token {
+                    return Err(self.unexpected_token("E1245", &["CONSTRAINT"], current_token));
+                }
 
-                        let current_token = self.get_next_token();
+                let constraint_name = self.parse_identifier("E1246")?;
 
-                        match current_token {
-                            Token::Identifier(identifier) => {
-                                query_builder = query_builder
-                                    .set_action(AlterTableRenameTo { name: identifier }.into());
-                            }
-                            _ => {
-                                return Err(ParsingError::boxed(format!(
-                                    "E1214 unexpected token {:?}",
-                                    current_token
-                                )))
-                            }
-                        }
-                    }
-                    // table column name rename
-                    Token::Column => {
-                        if !self.has_next_token() {
-                            return Err(ParsingError::boxed("E1217 need more tokens"));
-                        }
+                Ok(AlterTableValidateConstraint { constraint_name }.into())
+            }
+            // option_name [=] value (MySQL)
+            Token::Identifier(_)
+            | Token::Default
+            | Token::Comment
+            | Token::AutoIncrement
+            | Token::Collate
+                if self.dialect.supports_alter_table_options() =>
+            {
+                let option = self.parse_table_option(current_token)?;
+
+                Ok(AlterTableSetTableOption { option }.into())
+            }
+            _ => Err(self.unexpected_token("E1202", &[], current_token)),
+        }
+    }
 
-                        let current_token = self.get_next_token();
+    // 식별자 하나를 읽습니다.
+    fn parse_identifier(&mut self, code: &'static str) -> Result<String, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof(code));
+        }
 
-                        if let Token::Identifier(from_name) = current_token {
-                            if !self.has_next_token() {
-                                return Err(ParsingError::boxed("E1219 need more tokens"));
-                            }
+        let current_token = self.get_next_token();
 
-                            let current_token = self.get_next_token();
+        if let Token::Identifier(identifier) = current_token {
+            Ok(identifier)
+        } else {
+            Err(self.unexpected_token(code, &["identifier"], current_token))
+        }
+    }
 
-                            if Token::To != current_token {
-                                return Err(ParsingError::boxed(format!(
-                                    "E1220 expected token is 'TO', but you input is {:?}",
-                                    current_token
-                                )));
-                            }
+    // RENAME TO new_table_name
+    // RENAME [COLUMN] from_name TO to_name
+    // RENAME CONSTRAINT from_name TO to_name
+    fn parse_alter_table_rename(&mut self) -> Result<AlterTableAction, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1212"));
+        }
 
-                            if !self.has_next_token() {
-                                return Err(ParsingError::boxed("E1221 need more tokens"));
-                            }
+        let current_token = self.get_next_token();
 
-                            let current_token = self.get_next_token();
+        match current_token {
+            // table name rename
+            Token::To => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1213"));
+                }
 
-                            if let Token::Identifier(to_name) = current_token {
-                                query_builder = query_builder.set_action(
-                                    AlterTableRenameColumn { from_name, to_name }.into(),
-                                );
-                            } else {
-                                return Err(ParsingError::boxed(format!(
-                                    "E1222 expected token is 'identifer', but you input is {:?}",
-                                    current_token
-                                )));
-                            }
-                        } else {
-                            return Err(ParsingError::boxed(format!(
-                                "E1218 expected token {:?}",
-                                current_token
-                            )));
-                        }
+                let current_token = self.get_next_token();
+
+                match current_token {
+                    Token::Identifier(identifier) => {
+                        Ok(AlterTableRenameTo { name: identifier }.into())
                     }
-                    // table column name rename
-                    Token::Identifier(from_name) => {
-                        if !self.has_next_token() {
-                            return Err(ParsingError::boxed("E1218 need more tokens"));
-                        }
+                    _ => Err(self.unexpected_token("E1214", &[], current_token)),
+                }
+            }
+            // table column name rename
+            Token::Column => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1217"));
+                }
 
-                        let current_token = self.get_next_token();
+                let current_token = self.get_next_token();
 
-                        if Token::To != current_token {
-                            return Err(ParsingError::boxed(format!(
-                                "E1223 expected token is 'TO', but you input is {:?}",
-                                current_token
-                            )));
-                        }
+                if let Token::Identifier(from_name) = current_token {
+                    if !self.has_next_token() {
+                        return Err(self.unexpected_eof("E1219"));
+                    }
 
-                        if !self.has_next_token() {
-                            return Err(ParsingError::boxed("E1224 need more tokens"));
-                        }
+                    let current_token = self.get_next_token();
 
-                        let current_token = self.get_next_token();
+                    if Token::To != current_token {
+                        return Err(self.unexpected_token("E1220", &["TO"], current_token));
+                    }
 
-                        if let Token::Identifier(to_name) = current_token {
-                            query_builder = query_builder
-                                .set_action(AlterTableRenameColumn { from_name, to_name }.into());
-                        } else {
-                            return Err(ParsingError::boxed(format!(
-                                "E1225 expected token is 'identifer', but you input is {:?}",
-                                current_token
-                            )));
-                        }
+                    if !self.has_next_token() {
+                        return Err(self.unexpected_eof("E1221"));
                     }
-                    _ => {
-                        return Err(ParsingError::boxed(format!(
-                            "E1213 expected token is 'TO' or 'COLUMN', but you input is {:?}",
-                            current_token
-                        )))
+
+                    let current_token = self.get_next_token();
+
+                    if let Token::Identifier(to_name) = current_token {
+                        Ok(AlterTableRenameColumn { from_name, to_name }.into())
+                    } else {
+                        Err(self.unexpected_token("E1222", &["identifier"], current_token))
                     }
+                } else {
+                    Err(self.unexpected_token("E1218", &[], current_token))
                 }
             }
-            Token::Add => {
+            // table column name rename
+            Token::Identifier(from_name) => {
                 if !self.has_next_token() {
-                    return Err(ParsingError::boxed("E1215 need more tokens"));
+                    return Err(self.unexpected_eof("E1218"));
                 }
 
                 let current_token = self.get_next_token();
 
-                match current_token {
-                    Token::Column => {
-                        let column = self.parse_table_column()?;
+                if Token::To != current_token {
+                    return Err(self.unexpected_token("E1223", &["TO"], current_token));
+                }
 
-                        query_builder =
-                            query_builder.set_action(AlterTableAddColumn { column }.into());
-                    }
-                    Token::Identifier(_) => {
-                        self.unget_next_token(current_token);
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1224"));
+                }
 
-                        let column = self.parse_table_column()?;
+                let current_token = self.get_next_token();
 
-                        query_builder =
-                            query_builder.set_action(AlterTableAddColumn { column }.into());
-                    }
-                    _ => {
-                        return Err(ParsingError::boxed(format!(
-                            "E1216 unexpected keyword '{:?}'",
-                            current_token
-                        )))
-                    }
+                if let Token::Identifier(to_name) = current_token {
+                    Ok(AlterTableRenameColumn { from_name, to_name }.into())
+                } else {
+                    Err(self.unexpected_token("E1225", &["identifier"], current_token))
                 }
             }
-            Token::Drop => {
-                if self.next_token_is_column() {
-                    self.get_next_token();
-                }
+            // constraint name rename
+            Token::Constraint => {
+                let from_name = self.parse_identifier("E1247")?;
 
                 if !self.has_next_token() {
-                    return Err(ParsingError::boxed("E1226 need more tokens"));
+                    return Err(self.unexpected_eof("E1248"));
                 }
 
                 let current_token = self.get_next_token();
 
-                if let Token::Identifier(column_name) = current_token {
-                    query_builder =
-                        query_builder.set_action(AlterTableDropColumn { column_name }.into());
-                } else {
-                    return Err(ParsingError::boxed(format!(
-                        "E1227 unexpected token {:?}",
-                        current_token
-                    )));
+                if Token::To != current_token {
+                    return Err(self.unexpected_token("E1248", &["TO"], current_token));
                 }
+
+                let to_name = self.parse_identifier("E1249")?;
+
+                Ok(AlterTableRenameConstraint { from_name, to_name }.into())
             }
-            Token::Alter => {
-                if self.next_token_is_column() {
+            _ => {
+                Err(self.unexpected_token("E1213", &["TO", "COLUMN", "CONSTRAINT"], current_token))
+            }
+        }
+    }
+
+    // ADD [COLUMN] column_definition
+    // ADD table_constraint [NOT VALID]
+    // ADD {INDEX | KEY} [index_name] (column_name [, ...])
+    fn parse_alter_table_add(
+        &mut self,
+        context: ParserContext,
+        table: &TableName,
+    ) -> Result<AlterTableAction, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1215"));
+        }
+
+        if self.next_token_is_table_constraint() {
+            let constraint = self.parse_table_constraint(context, table.clone())?;
+
+            // NOT VALID (PostgreSQL)
+            let mut not_valid = false;
+
+            if self.has_next_token() {
+                let current_token = self.get_next_token();
+
+                if Token::Not == current_token && self.next_token_is_word("VALID") {
                     self.get_next_token();
+                    not_valid = true;
+                } else {
+                    self.unget_next_token(current_token);
                 }
+            }
+
+            return Ok(AlterTableAddConstraint {
+                constraint,
+                not_valid,
+            }
+            .into());
+        }
+
+        let current_token = self.get_next_token();
+
+        match current_token {
+            Token::Index | Token::Key => {
+                let index = self.parse_index_definition()?;
+
+                Ok(AlterTableAddIndex { index }.into())
+            }
+            Token::Column => {
+                let column = self.parse_table_column(context)?;
+
+                let position = self.parse_column_position()?;
+
+                Ok(AlterTableAddColumn { column, position }.into())
+            }
+            Token::Identifier(_) => {
+                self.unget_next_token(current_token);
+
+                let column = self.parse_table_column(context)?;
+
+                let position = self.parse_column_position()?;
+
+                Ok(AlterTableAddColumn { column, position }.into())
+            }
+            _ => Err(self.unexpected_token("E1216", &[], current_token)),
+        }
+    }
+
+    // {INDEX | KEY} 다음의 [index_name] (column_name [ASC | DESC], ...) (MySQL)
+    // CREATE TABLE 안의 인덱스 정의와 ALTER TABLE ... ADD INDEX에서 사용합니다.
+    fn parse_index_definition(&mut self) -> Result<Index, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1250"));
+        }
+
+        // 인덱스명을 생략하면 첫번째 컬럼명을 사용
+        let current_token = self.get_next_token();
+
+        let index_name = if let Token::Identifier(index_name) = current_token {
+            Some(index_name)
+        } else {
+            self.unget_next_token(current_token);
+            None
+        };
+
+        let columns = self.parse_index_column_list()?;
+
+        Ok(Index {
+            index_name: index_name.unwrap_or_else(|| columns[0].column_name.clone()),
+            columns,
+            is_unique: false,
+            method: None,
+        })
+    }
+
+    // MODIFY [COLUMN] column_definition [FIRST | AFTER column_name]
+    fn parse_alter_table_modify(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<AlterTableAction, Error> {
+        if self.next_token_is_column() {
+            self.get_next_token();
+        }
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1256"));
+        }
+
+        let column = self.parse_table_column(context)?;
+        let position = self.parse_column_position()?;
+
+        Ok(AlterTableModifyColumn { column, position }.into())
+    }
+
+    // CHANGE [COLUMN] old_name column_definition [FIRST | AFTER column_name]
+    fn parse_alter_table_change(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<AlterTableAction, Error> {
+        if self.next_token_is_column() {
+            self.get_next_token();
+        }
+
+        let old_name = self.parse_identifier("E1257")?;
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1258"));
+        }
+
+        let column = self.parse_table_column(context)?;
+        let position = self.parse_column_position()?;
+
+        Ok(AlterTableChangeColumn {
+            old_name,
+            column,
+            position,
+        }
+        .into())
+    }
+
+    // [FIRST | AFTER column_name]
+    fn parse_column_position(&mut self) -> Result<Option<ColumnPosition>, Error> {
+        if !self.has_next_token() {
+            return Ok(None);
+        }
+
+        let current_token = self.get_next_token();
+
+        match current_token {
+            Token::First => Ok(Some(ColumnPosition::First)),
+            Token::Identifier(_) if current_token.is_word("AFTER") => {
+                let column_name = self.parse_identifier("E1259")?;
 
+                Ok(Some(ColumnPosition::After(column_name)))
+            }
+            _ => {
+                self.unget_next_token(current_token);
+                Ok(None)
+            }
+        }
+    }
+
+    // DROP [COLUMN] column_name
+    // DROP CONSTRAINT [IF EXISTS] constraint_name [CASCADE | RESTRICT]
+    // DROP FOREIGN KEY constraint_name
+    // DROP PRIMARY KEY
+    // DROP {INDEX | KEY} index_name
+    fn parse_alter_table_drop(&mut self) -> Result<AlterTableAction, Error> {
+        if self.next_token_is_column() {
+            self.get_next_token();
+        }
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1226"));
+        }
+
+        let current_token = self.get_next_token();
+
+        match current_token {
+            Token::Constraint => {
+                let if_exists = self.has_if_exists()?;
+                let constraint_name = self.parse_identifier("E1251")?;
+
+                let drop_behavior = self.parse_drop_behavior();
+
+                return Ok(AlterTableDropConstraint {
+                    constraint_name,
+                    if_exists,
+                    drop_behavior,
+                }
+                .into());
+            }
+            Token::Foreign | Token::Primary => {
                 if !self.has_next_token() {
-                    return Err(ParsingError::boxed("E1228 need more tokens"));
+                    return Err(self.unexpected_eof("E1252"));
                 }
 
-                let current_token = self.get_next_token();
+                let key_token = self.get_next_token();
+
+                if Token::Key != key_token {
+                    return Err(self.unexpected_token("E1253", &["KEY"], key_token));
+                }
+
+                if Token::Primary == current_token {
+                    return Ok(AlterTableAction::DropPrimaryKey);
+                }
+
+                let constraint_name = self.parse_identifier("E1254")?;
+
+                return Ok(AlterTableDropConstraint {
+                    constraint_name,
+                    if_exists: false,
+                    drop_behavior: None,
+                }
+                .into());
+            }
+            Token::Index | Token::Key => {
+                let index_name = self.parse_identifier("E1255")?;
+
+                return Ok(AlterTableDropIndex { index_name }.into());
+            }
+            _ => {}
+        }
+
+        if let Token::Identifier(column_name) = current_token {
+            Ok(AlterTableDropColumn { column_name }.into())
+        } else {
+            Err(self.unexpected_token("E1227", &[], current_token))
+        }
+    }
+
+    // ALTER [COLUMN] column_name { SET | DROP | ADD | TYPE } ...
+    fn parse_alter_table_alter_column(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<AlterTableAction, Error> {
+        if self.next_token_is_column() {
+            self.get_next_token();
+        }
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1228"));
+        }
+
+        let current_token = self.get_next_token();
+
+        let column_name = if let Token::Identifier(column_name) = current_token {
+            column_name
+        } else {
+            return Err(self.unexpected_token("E1229", &[], current_token));
+        };
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1230"));
+        }
+
+        let current_token = self.get_next_token();
+
+        let action: AlterColumnAction = match current_token {
+            Token::Set => {
+                if self.next_token_is_not_null() {
+                    self.get_next_token();
+                    self.get_next_token();
+
+                    AlterColumnSetNotNull {}.into()
+                } else if self.next_token_is_data_type() {
+                    self.get_next_token();
+                    self.get_next_token();
 
-                if let Token::Identifier(column_name) = current_token {
                     if !self.has_next_token() {
-                        return Err(ParsingError::boxed("E1230 need more tokens"));
+                        return Err(self.unexpected_eof("E1233"));
                     }
 
-                    let current_token = self.get_next_token();
+                    self.parse_alter_column_set_type(context)?
+                } else if self.next_token_is_default() {
+                    self.get_next_token();
 
-                    match current_token {
-                        Token::Set => {
-                            if self.next_token_is_not_null() {
-                                self.get_next_token();
-                                self.get_next_token();
-
-                                query_builder = query_builder.set_action(
-                                    AlterTableAlterColumn {
-                                        action: AlterColumnSetNotNull {}.into(),
-                                        column_name,
-                                    }
-                                    .into(),
-                                );
-                            } else if self.next_token_is_data_type() {
-                                self.get_next_token();
-                                self.get_next_token();
-
-                                if !self.has_next_token() {
-                                    return Err(ParsingError::boxed("E1233 need more tokens"));
-                                }
+                    if !self.has_next_token() {
+                        return Err(self.unexpected_eof("E1234"));
+                    }
 
-                                let data_type = self.parse_data_type()?;
+                    let expression = self.parse_expression(context)?;
 
-                                query_builder = query_builder.set_action(
-                                    AlterTableAlterColumn {
-                                        action: AlterColumnSetType { data_type }.into(),
-                                        column_name,
-                                    }
-                                    .into(),
-                                );
-                            } else if self.next_token_is_default() {
-                                self.get_next_token();
+                    AlterColumnSetDefault { expression }.into()
+                } else {
+                    if !self.has_next_token() {
+                        return Err(self.unexpected_eof("E1260"));
+                    }
 
-                                if !self.has_next_token() {
-                                    return Err(ParsingError::boxed("E1234 need more tokens"));
-                                }
+                    let current_token = self.get_next_token();
+
+                    match current_token {
+                        Token::Comment => {
+                            if !self.has_next_token() {
+                                return Err(self.unexpected_eof("E1261"));
+                            }
 
-                                let expression = self.parse_expression(context)?;
+                            let current_token = self.get_next_token();
 
-                                query_builder = query_builder.set_action(
-                                    AlterTableAlterColumn {
-                                        action: AlterColumnSetDefault { expression }.into(),
-                                        column_name,
-                                    }
-                                    .into(),
-                                );
+                            if let Token::String(comment) = current_token {
+                                AlterColumnSetComment { comment }.into()
                             } else {
-                                return Err(ParsingError::boxed("E1231 unexpected tokens"));
+                                return Err(self.unexpected_token(
+                                    "E1262",
+                                    &["string"],
+                                    current_token,
+                                ));
                             }
                         }
-                        Token::Drop => {
-                            if self.next_token_is_not_null() {
-                                self.get_next_token();
-                                self.get_next_token();
-
-                                query_builder = query_builder.set_action(
-                                    AlterTableAlterColumn {
-                                        action: AlterColumnDropNotNull {}.into(),
-                                        column_name,
-                                    }
-                                    .into(),
-                                );
-                            } else if self.next_token_is_default() {
-                                self.get_next_token();
-
-                                query_builder = query_builder.set_action(
-                                    AlterTableAlterColumn {
-                                        action: AlterColumnDropDefault {}.into(),
-                                        column_name,
-                                    }
-                                    .into(),
-                                );
-                            } else {
-                                return Err(ParsingError::boxed("E1231 unexpected tokens"));
-                            }
+                        // SET GENERATED { ALWAYS | BY DEFAULT }
+                        Token::Identifier(_) if current_token.is_word("GENERATED") => {
+                            let kind = self.parse_generated_kind()?;
+
+                            AlterColumnSetIdentity { kind }.into()
+                        }
+                        Token::Identifier(_) if current_token.is_word("STATISTICS") => {
+                            let value = self.parse_signed_integer()?;
+
+                            AlterColumnSetStatistics { value }.into()
                         }
-                        Token::Type => {
+                        Token::Identifier(_) if current_token.is_word("COLLATION") => {
                             if !self.has_next_token() {
-                                return Err(ParsingError::boxed("E1232 need more tokens"));
+                                return Err(self.unexpected_eof("E1263"));
                             }
 
-                            let data_type = self.parse_data_type()?;
+                            let current_token = self.get_next_token();
 
-                            query_builder = query_builder.set_action(
-                                AlterTableAlterColumn {
-                                    action: AlterColumnSetType { data_type }.into(),
-                                    column_name,
+                            match current_token {
+                                Token::Identifier(collation) | Token::String(collation) => {
+                                    AlterColumnSetCollation { collation }.into()
                                 }
-                                .into(),
-                            );
-                        }
-                        _ => {
-                            return Err(ParsingError::boxed(format!(
-                                "E1229 unexpected token {:?}",
-                                current_token
-                            )))
+                                _ => {
+                                    return Err(self.unexpected_token(
+                                        "E1264",
+                                        &["collation name"],
+                                        current_token,
+                                    ));
+                                }
+                            }
                         }
+                        _ => return Err(self.parsing_error("E1231", "unexpected tokens")),
                     }
+                }
+            }
+            Token::Drop => {
+                if self.next_token_is_not_null() {
+                    self.get_next_token();
+                    self.get_next_token();
+
+                    AlterColumnDropNotNull {}.into()
+                } else if self.next_token_is_default() {
+                    self.get_next_token();
+
+                    AlterColumnDropDefault {}.into()
+                } else if self.next_token_is_word("IDENTITY") {
+                    self.get_next_token();
+
+                    let if_exists = self.has_if_exists()?;
+
+                    AlterColumnDropIdentity { if_exists }.into()
+                } else if self.has_next_token()
+                    && self.tokens.front().unwrap().token == Token::Comment
+                {
+                    self.get_next_token();
+
+                    AlterColumnDropComment {}.into()
                 } else {
-                    return Err(ParsingError::boxed(format!(
-                        "E1229 unexpected token {:?}",
-                        current_token
-                    )));
+                    return Err(self.parsing_error("E1231", "unexpected tokens"));
                 }
             }
-            _ => {
-                return Err(ParsingError::boxed(format!(
-                    "E1202 unexpected keyword '{:?}'",
-                    current_token
-                )))
+            // ADD GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY [( sequence_options )]
+            Token::Add => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1265"));
+                }
+
+                let current_token = self.get_next_token();
+
+                if !current_token.is_word("GENERATED") {
+                    return Err(self.unexpected_token("E1266", &["GENERATED"], current_token));
+                }
+
+                let kind = self.parse_generated_kind()?;
+
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1267"));
+                }
+
+                let current_token = self.get_next_token();
+
+                if Token::As != current_token {
+                    return Err(self.unexpected_token("E1271", &["AS"], current_token));
+                }
+
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1268"));
+                }
+
+                let current_token = self.get_next_token();
+
+                if !current_token.is_word("IDENTITY") {
+                    return Err(self.unexpected_token("E1272", &["IDENTITY"], current_token));
+                }
+
+                let identity = self.parse_identity_options(kind)?;
+
+                AlterColumnAddIdentity { identity }.into()
+            }
+            Token::Type => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1232"));
+                }
+
+                self.parse_alter_column_set_type(context)?
             }
+            _ => return Err(self.unexpected_token("E1229", &[], current_token)),
+        };
+
+        Ok(AlterTableAlterColumn {
+            action,
+            column_name,
         }
+        .into())
+    }
 
-        Ok(query_builder.build())
+    // type_name [USING expression]
+    fn parse_alter_column_set_type(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<AlterColumnAction, Error> {
+        let data_type = self.parse_data_type()?;
+
+        let using = if self.next_token_is_word("USING") {
+            self.get_next_token();
+
+            Some(self.parse_expression(context)?)
+        } else {
+            None
+        };
+
+        Ok(AlterColumnSetType { data_type, using }.into())
     }
 
     // DROP TABLE 쿼리 분석
     pub(crate) fn handle_drop_table_query(
         &mut self,
         context: ParserContext,
-    ) -> Result<SQLStatement, Box<dyn Error + Send>> {
-        let mut query_builder = DropTableQuery::builder();
+        temporary: bool,
+    ) -> Result<SQLStatement, Error> {
+        let mut query_builder = DropTableQuery::builder().set_temporary(temporary);
 
         // IF EXISTS 파싱
         let if_exists = self.has_if_exists()?;
@@ -429,13 +838,29 @@ impl Parser {
 
         // 테이블명 획득 로직
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E1203 need more tokens"));
+            return Err(self.unexpected_eof("E1203"));
         }
 
-        let table = self.parse_table_name(context)?;
+        // , 로 구분된 테이블 목록
+        loop {
+            let table = self.parse_table_name(context.clone())?;
+            query_builder = query_builder.add_table(table);
+
+            if !self.has_next_token() {
+                return Ok(query_builder.build());
+            }
+
+            let current_token = self.get_next_token();
 
-        // 테이블명 설정
-        query_builder = query_builder.set_table(table);
+            if Token::Comma != current_token {
+                self.unget_next_token(current_token);
+                break;
+            }
+        }
+
+        if let Some(drop_behavior) = self.parse_drop_behavior() {
+            query_builder = query_builder.set_drop_behavior(drop_behavior);
+        }
 
         if !self.has_next_token() {
             return Ok(query_builder.build());
@@ -444,12 +869,110 @@ impl Parser {
         let current_token = self.get_next_token();
 
         if Token::SemiColon != current_token {
-            return Err(ParsingError::boxed(format!(
-                "E1204 expected ';'. but your input word is '{:?}'",
-                current_token
-            )));
+            return Err(self.unexpected_token("E1204", &[";"], current_token));
         }
 
         Ok(query_builder.build())
     }
+
+    // 테이블 옵션 분석
+    // option_name [=] value [[,] option_name [=] value ...]
+    fn parse_table_options(&mut self) -> Result<TableOptions, Error> {
+        let mut table_options = TableOptions::default();
+
+        loop {
+            if !self.has_next_token() {
+                break;
+            }
+
+            let current_token = self.get_next_token();
+
+            match current_token {
+                Token::SemiColon => {
+                    self.unget_next_token(current_token);
+                    break;
+                }
+                Token::Comma => continue,
+                _ => {
+                    let option = self.parse_table_option(current_token)?;
+                    table_options.options.push(option);
+                }
+            }
+        }
+
+        Ok(table_options)
+    }
+
+    // 테이블 옵션 하나를 분석합니다. 옵션명의 첫 토큰은 이미 읽은 상태입니다.
+    fn parse_table_option(&mut self, current_token: Token) -> Result<TableOption, Error> {
+        let mut name = match current_token {
+            // DEFAULT CHARSET=... 의 DEFAULT는 생략 가능한 접두어
+            Token::Default => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1237"));
+                }
+
+                let current_token = self.get_next_token();
+                self.table_option_word(current_token)?
+            }
+            _ => self.table_option_word(current_token)?,
+        };
+
+        // 두 단어로 구성된 옵션명
+        if name == "CHARACTER" || name == "WITHOUT" {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E1238"));
+            }
+
+            let current_token = self.get_next_token();
+            name = format!("{} {}", name, self.table_option_word(current_token)?);
+        }
+
+        let has_equal = if self.has_next_token() {
+            let current_token = self.get_next_token();
+
+            if let Token::Operator(OperatorToken::Eq) = current_token {
+                true
+            } else {
+                self.unget_next_token(current_token);
+                false
+            }
+        } else {
+            false
+        };
+
+        // 값이 없는 옵션
+        if !has_equal && (name == "STRICT" || name == "WITHOUT ROWID") {
+            return Ok(TableOption::new(name, None));
+        }
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E1239"));
+        }
+
+        let current_token = self.get_next_token();
+
+        let value = match current_token {
+            Token::Identifier(value) | Token::String(value) => value,
+            Token::Integer(integer) => integer.to_string(),
+            Token::Default => "DEFAULT".to_owned(),
+            _ => {
+                return Err(self.unexpected_token("E1240", &["table option value"], current_token))
+            }
+        };
+
+        Ok(TableOption::new(name, Some(value)))
+    }
+
+    // 테이블 옵션명을 구성하는 단어
+    fn table_option_word(&self, token: Token) -> Result<String, Error> {
+        match token {
+            Token::Identifier(word) => Ok(word.to_uppercase()),
+            Token::Comment => Ok("COMMENT".to_owned()),
+            Token::AutoIncrement => Ok("AUTO_INCREMENT".to_owned()),
+            Token::Collate => Ok("COLLATE".to_owned()),
+            Token::Set => Ok("SET".to_owned()),
+            _ => Err(self.unexpected_token("E1241", &["table option"], token)),
+        }
+    }
 }
@@ -1,19 +1,18 @@
 use crate::ast::predule::SQLStatement;
-use crate::errors::predule::ParsingError;
 use crate::lexer::predule::Token;
 use crate::parser::context::ParserContext;
 use crate::parser::predule::Parser;
 
-use std::error::Error;
+use crate::errors::predule::Error;
 
 impl Parser {
     // CREATE...로 시작되는 쿼리 분석
     pub(crate) fn handle_create_query(
         &mut self,
         context: ParserContext,
-    ) -> Result<SQLStatement, Box<dyn Error + Send>> {
+    ) -> Result<SQLStatement, Error> {
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E1101 need more tokens"));
+            return Err(self.unexpected_eof("E1101"));
         }
 
         let current_token = self.get_next_token();
@@ -21,10 +20,28 @@ impl Parser {
         match current_token {
             Token::Table => self.handle_create_table_query(context),
             Token::Database => self.handle_create_database_query(),
-            _ => Err(ParsingError::boxed(format!(
-                "E1102 not supported command. possible commands: (create table). but your input is {:?}",
-                current_token
-            ))),
+            Token::Index => self.handle_create_index_query(context, false),
+            Token::Unique => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1107"));
+                }
+
+                let current_token = self.get_next_token();
+
+                if Token::Index != current_token {
+                    return Err(self.unexpected_token("E1108", &["INDEX"], current_token));
+                }
+
+                self.handle_create_index_query(context, true)
+            }
+            _ => Err(self.parsing_error(
+                "E1102",
+                format!(
+                    "not supported command. possible commands: \
+                     (create table, create database, create index). but your input is {:?}",
+                    current_token
+                ),
+            )),
         }
     }
 
@@ -32,9 +49,9 @@ impl Parser {
     pub(crate) fn handle_alter_query(
         &mut self,
         context: ParserContext,
-    ) -> Result<SQLStatement, Box<dyn Error + Send>> {
+    ) -> Result<SQLStatement, Error> {
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E1103 need more tokens"));
+            return Err(self.unexpected_eof("E1103"));
         }
 
         let current_token = self.get_next_token();
@@ -42,8 +59,9 @@ impl Parser {
         match current_token {
             Token::Table => self.handle_alter_table_query(context),
             Token::Database => self.handle_alter_database_query(),
-            _ => Err(ParsingError::boxed(
-                "E1104 not supported command. possible commands: (alter table)",
+            _ => Err(self.parsing_error(
+                "E1104",
+                "not supported command. possible commands: (alter table, alter database)",
             )),
         }
     }
@@ -51,18 +69,34 @@ impl Parser {
     pub(crate) fn handle_drop_query(
         &mut self,
         context: ParserContext,
-    ) -> Result<SQLStatement, Box<dyn Error + Send>> {
+    ) -> Result<SQLStatement, Error> {
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E1105 need more tokens"));
+            return Err(self.unexpected_eof("E1105"));
         }
 
         let current_token = self.get_next_token();
 
         match current_token {
-            Token::Table => self.handle_drop_table_query(context),
+            Token::Table => self.handle_drop_table_query(context, false),
             Token::Database => self.handle_drop_database_query(),
-            _ => Err(ParsingError::boxed(
-                "E1106 not supported command. possible commands: (create table)",
+            Token::Index => self.handle_drop_index_query(context),
+            // DROP TEMPORARY TABLE (MySQL)
+            Token::Identifier(_) if current_token.is_word("TEMPORARY") => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E1109"));
+                }
+
+                let current_token = self.get_next_token();
+
+                if Token::Table != current_token {
+                    return Err(self.unexpected_token("E1110", &["TABLE"], current_token));
+                }
+
+                self.handle_drop_table_query(context, true)
+            }
+            _ => Err(self.parsing_error(
+                "E1106",
+                "not supported command. possible commands: (drop table, drop database, drop index)",
             )),
         }
     }
@@ -1,8 +1,9 @@
+use crate::logger::predule::log_debug;
 use crate::parser::predule::Parser;
 
 impl Parser {
     #[allow(dead_code)]
     pub(crate) fn show_tokens(&self) {
-        println!("{:?}", self);
+        log_debug!("{:?}", self);
     }
 }
@@ -1,135 +1,533 @@
-use std::error::Error;
+use crate::errors::predule::Error;
+use std::convert::{TryFrom, TryInto};
 
-use crate::errors::predule::ParsingError;
+use crate::ast::predule::{
+    BetweenExpression, BinaryOperator, BinaryOperatorExpression, BuiltInFunction, CallExpression,
+    CaseExpression, CastExpression, ColumnName, Expression, Function, InListExpression,
+    ParenthesesExpression, SQLValueFunction, UnaryOperator, UnaryOperatorExpression,
+    UserDefinedFunction, WhenClause,
+};
 use crate::lexer::predule::Token;
 use crate::parser::predule::Parser;
 use crate::parser::predule::ParserContext;
 
 impl Parser {
-    pub(crate) fn parse_expression(
-        &mut self,
-        context: ParserContext,
-    ) -> Result<String, Box<dyn Error + Send>> {
+    pub(crate) fn parse_expression(&mut self, context: ParserContext) -> Result<Expression, Error> {
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0201 need more tokens"));
+            return Err(self.unexpected_eof("E0201"));
         }
 
-        let mut expression = String::new();
+        self.parse_binary_expression(context, 0)
+    }
+
+    /**
+     * 2항 연산 파싱
+     * 우선순위가 min_precedence보다 높은 연산자만 결합합니다.
+     * binaryexpr ::= unaryexpr [operator binaryexpr]
+     */
+    fn parse_binary_expression(
+        &mut self,
+        context: ParserContext,
+        min_precedence: i32,
+    ) -> Result<Expression, Error> {
+        let mut lhs = self.parse_unary_expression(context.clone())?;
 
         while self.has_next_token() {
-            let current_token = self.get_next_token();
+            let mut operator_token = self.get_next_token();
+            let mut negated = false;
 
-            match current_token {
-                Token::Operator(operator) => {
-                    expression.push_str(operator.to_string().as_str());
-                }
-                Token::Not => {
-                    expression.push_str("NOT");
-                }
-                Token::Integer(integer) => {
-                    let string = integer.to_string();
-                    expression.push_str(string.as_str());
-                }
-                Token::Float(float) => {
-                    let string = float.to_string();
-                    expression.push_str(string.as_str());
+            // expression::data_type 형태의 타입 변환 (PostgreSQL)
+            // 어떤 연산자보다도 먼저 결합합니다.
+            if Token::DoubleColon == operator_token {
+                let data_type = self.parse_data_type()?;
+
+                lhs = CastExpression {
+                    expression: Box::new(lhs),
+                    data_type,
                 }
-                Token::String(string) => {
-                    expression.push_str(string.as_str());
+                .into();
+
+                continue;
+            }
+
+            // NOT IN, NOT LIKE, NOT BETWEEN
+            if Token::Not == operator_token {
+                if !self.has_next_token() {
+                    self.unget_next_token(operator_token);
+                    break;
                 }
-                Token::Boolean(boolean) => {
-                    let string = boolean.to_string();
-                    expression.push_str(string.as_str());
+
+                let second_token = self.get_next_token();
+
+                if matches!(second_token, Token::In | Token::Like | Token::Between) {
+                    negated = true;
+                    operator_token = second_token;
+                } else {
+                    self.unget_next_token(second_token);
+                    self.unget_next_token(operator_token);
+                    break;
                 }
-                Token::Null => {
-                    expression.push_str("NULL");
+            }
+
+            // BETWEEN은 비교 연산과 같은 우선순위
+            let precedence = match operator_token {
+                Token::Between => BinaryOperator::In.get_precedence(),
+                _ => match TryInto::<BinaryOperator>::try_into(operator_token.clone()) {
+                    Ok(operator) => operator.get_precedence(),
+                    Err(_) => {
+                        self.unget_next_token(operator_token);
+                        break;
+                    }
+                },
+            };
+
+            if precedence <= min_precedence {
+                self.unget_next_token(operator_token);
+
+                if negated {
+                    self.unget_next_token(Token::Not);
                 }
-                Token::LeftParentheses => {
+
+                break;
+            }
+
+            lhs = match operator_token {
+                Token::Between => {
+                    let low = self.parse_binary_expression(context.clone(), precedence)?;
+
                     if !self.has_next_token() {
-                        return Err(ParsingError::boxed("E0214 need more tokens"));
+                        return Err(self.unexpected_eof("E0206"));
                     }
 
-                    let second_token = self.get_next_token();
+                    let current_token = self.get_next_token();
 
-                    match second_token {
-                        Token::Select => {
-                            return Err(ParsingError::boxed("Select not supported in expression"));
-                        }
-                        _ => {
-                            self.unget_next_token(second_token);
+                    if Token::And != current_token {
+                        return Err(self.unexpected_token("E0207", &["AND"], current_token));
+                    }
+
+                    let high = self.parse_binary_expression(context.clone(), precedence)?;
+
+                    BetweenExpression {
+                        expression: Box::new(lhs),
+                        low: Box::new(low),
+                        high: Box::new(high),
+                        negated,
+                    }
+                    .into()
+                }
+                Token::In => {
+                    let list = self.parse_expression_list(context.clone())?;
+
+                    InListExpression {
+                        expression: Box::new(lhs),
+                        list,
+                        negated,
+                    }
+                    .into()
+                }
+                Token::Is => {
+                    let mut operator = BinaryOperator::Is;
+
+                    if self.has_next_token() {
+                        let current_token = self.get_next_token();
+
+                        if Token::Not == current_token {
+                            operator = BinaryOperator::IsNot;
+                        } else {
                             self.unget_next_token(current_token);
-                            let string = self.parse_parentheses_expression(context.clone())?;
-                            expression.push_str(string.as_str());
                         }
                     }
+
+                    let rhs = self.parse_binary_expression(context.clone(), precedence)?;
+
+                    BinaryOperatorExpression {
+                        operator,
+                        lhs: Box::new(lhs),
+                        rhs: Box::new(rhs),
+                    }
+                    .into()
                 }
-                Token::RightParentheses => {
-                    self.unget_next_token(current_token);
-                    break;
+                _ => {
+                    let mut operator: BinaryOperator = operator_token.try_into()?;
+
+                    if negated && operator == BinaryOperator::Like {
+                        operator = BinaryOperator::NotLike;
+                    }
+
+                    let rhs = self.parse_binary_expression(context.clone(), precedence)?;
+
+                    BinaryOperatorExpression {
+                        operator,
+                        lhs: Box::new(lhs),
+                        rhs: Box::new(rhs),
+                    }
+                    .into()
                 }
-                Token::Comma => {
-                    self.unget_next_token(current_token);
-                    break;
+            };
+        }
+
+        Ok(lhs)
+    }
+
+    /**
+     * 단항 연산 파싱
+     * unaryexpr ::= [-|+|NOT] primaryexpr
+     */
+    fn parse_unary_expression(&mut self, context: ParserContext) -> Result<Expression, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E0208"));
+        }
+
+        let current_token = self.get_next_token();
+
+        let operator: UnaryOperator = match current_token {
+            Token::Not => UnaryOperator::Not,
+            Token::Operator(operator) if operator.is_unary_operator() => operator.try_into()?,
+            _ => {
+                self.unget_next_token(current_token);
+                return self.parse_primary_expression(context);
+            }
+        };
+
+        let operand = self.parse_binary_expression(context, operator.get_precedence())?;
+
+        // 부호가 붙은 숫자 리터럴은 하나의 값으로 취급
+        let expression = match (operator, operand) {
+            (UnaryOperator::Neg, Expression::Integer(integer)) => Expression::Integer(-integer),
+            (UnaryOperator::Neg, Expression::Float(float)) => Expression::Float(-float),
+            (UnaryOperator::Pos, Expression::Integer(integer)) => Expression::Integer(integer),
+            (UnaryOperator::Pos, Expression::Float(float)) => Expression::Float(float),
+            (operator, operand) => UnaryOperatorExpression {
+                operator,
+                operand: Box::new(operand),
+            }
+            .into(),
+        };
+
+        Ok(expression)
+    }
+
+    /**
+     * 단일 값 파싱
+     * primaryexpr ::= literal | column_name | function_call | CAST | CASE | parenexpr
+     */
+    fn parse_primary_expression(&mut self, context: ParserContext) -> Result<Expression, Error> {
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E0209"));
+        }
+
+        let current_token = self.get_next_token();
+
+        match current_token {
+            Token::Integer(integer) => Ok(Expression::Integer(integer)),
+            Token::Float(float) => Ok(Expression::Float(float)),
+            Token::String(string) => Ok(Expression::String(string)),
+            Token::HexString(digits) => Ok(Expression::HexString(digits)),
+            Token::Boolean(boolean) => Ok(Expression::Boolean(boolean)),
+            Token::Null => Ok(Expression::Null),
+            Token::LeftParentheses => {
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E0214"));
                 }
-                _ => {
-                    break;
+
+                let second_token = self.get_next_token();
+
+                match second_token {
+                    Token::Select => {
+                        Err(self.parsing_error("E0226", "Select not supported in expression"))
+                    }
+                    _ => {
+                        self.unget_next_token(second_token);
+                        self.unget_next_token(current_token);
+                        self.parse_parentheses_expression(context)
+                    }
+                }
+            }
+            Token::Identifier(_) if current_token.is_word("CAST") => {
+                if self.next_token_is_left_parentheses() {
+                    self.parse_cast_expression(context)
+                } else {
+                    self.parse_identifier_expression(context, current_token)
                 }
             }
+            Token::Identifier(_) if current_token.is_word("CASE") => {
+                self.parse_case_expression(context)
+            }
+            Token::Identifier(_) => self.parse_identifier_expression(context, current_token),
+            _ => Err(self.parsing_error(
+                "E0210",
+                format!("unexpected token in expression: {:?}", current_token),
+            )),
         }
+    }
 
-        Ok(expression)
+    /**
+     * 식별자로 시작하는 표현식 파싱
+     * identexpr ::= [table_name.]column_name | function_name([argument, ...]) | CURRENT_TIMESTAMP ...
+     */
+    fn parse_identifier_expression(
+        &mut self,
+        context: ParserContext,
+        current_token: Token,
+    ) -> Result<Expression, Error> {
+        let name = if let Token::Identifier(name) = current_token {
+            name
+        } else {
+            return Err(self.unexpected_token("E0211", &["identifier"], current_token));
+        };
+
+        // 함수 호출
+        if self.next_token_is_left_parentheses() {
+            let function = match BuiltInFunction::try_from(name.clone()) {
+                Ok(built_in) => Function::BuiltIn(built_in),
+                Err(_) => UserDefinedFunction {
+                    database_name: None,
+                    function_name: name,
+                }
+                .into(),
+            };
+
+            let arguments = self.parse_expression_list(context)?;
+
+            return Ok(CallExpression {
+                function,
+                arguments,
+            }
+            .into());
+        }
+
+        if let Some(value_function) = SQLValueFunction::from_name(&name) {
+            return Ok(value_function.into());
+        }
+
+        if !self.has_next_token() {
+            return Ok(ColumnName::new(None, name).into());
+        }
+
+        let current_token = self.get_next_token();
+
+        // .가 있을 경우 "테이블명"."컬럼명"의 형태로 추정
+        if Token::Period == current_token {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0212"));
+            }
+
+            let current_token = self.get_next_token();
+
+            if let Token::Identifier(column_name) = current_token {
+                Ok(ColumnName::new(Some(name), column_name).into())
+            } else {
+                Err(self.unexpected_token("E0213", &["identifier"], current_token))
+            }
+        } else {
+            self.unget_next_token(current_token);
+            Ok(ColumnName::new(None, name).into())
+        }
+    }
+
+    /**
+     * 타입 변환 파싱
+     * castexpr ::= CAST '(' expression AS data_type ')'
+     */
+    fn parse_cast_expression(&mut self, context: ParserContext) -> Result<Expression, Error> {
+        // ( 삼킴
+        self.get_next_token();
+
+        let expression = self.parse_expression(context)?;
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E0215"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if Token::As != current_token {
+            return Err(self.unexpected_token("E0216", &["AS"], current_token));
+        }
+
+        let data_type = self.parse_data_type()?;
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E0217"));
+        }
+
+        let current_token = self.get_next_token();
+
+        if Token::RightParentheses != current_token {
+            return Err(self.unexpected_token("E0227", &[")"], current_token));
+        }
+
+        Ok(CastExpression {
+            expression: Box::new(expression),
+            data_type,
+        }
+        .into())
+    }
+
+    /**
+     * 조건 분기 파싱
+     * caseexpr ::= CASE [expression] WHEN expression THEN expression [...] [ELSE expression] END
+     */
+    fn parse_case_expression(&mut self, context: ParserContext) -> Result<Expression, Error> {
+        let operand = if self.next_token_is_word("WHEN") {
+            None
+        } else {
+            Some(Box::new(self.parse_expression(context.clone())?))
+        };
+
+        let mut when_clauses = vec![];
+        let mut else_result = None;
+
+        loop {
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0218"));
+            }
+
+            let current_token = self.get_next_token();
+
+            if current_token.is_word("WHEN") {
+                let condition = self.parse_expression(context.clone())?;
+
+                if !self.has_next_token() {
+                    return Err(self.unexpected_eof("E0219"));
+                }
+
+                let current_token = self.get_next_token();
+
+                if !current_token.is_word("THEN") {
+                    return Err(self.unexpected_token("E0220", &["THEN"], current_token));
+                }
+
+                let result = self.parse_expression(context.clone())?;
+
+                when_clauses.push(WhenClause { condition, result });
+            } else if current_token.is_word("ELSE") && else_result.is_none() {
+                else_result = Some(Box::new(self.parse_expression(context.clone())?));
+            } else if current_token.is_word("END") && !when_clauses.is_empty() {
+                break;
+            } else {
+                return Err(self.unexpected_token(
+                    "E0221",
+                    &["WHEN", "ELSE", "END"],
+                    current_token,
+                ));
+            }
+        }
+
+        Ok(CaseExpression {
+            operand,
+            when_clauses,
+            else_result,
+        }
+        .into())
     }
 
     /**
-     * 소괄호연산자, 혹은 리스트 파싱
-    parenexpr ::= '(' expression ')'
-    parenexpr ::= '(' 1, 2, 3 ')'
-    */
+     * 소괄호연산자 파싱
+     * parenexpr ::= '(' expression ')'
+     */
     pub(crate) fn parse_parentheses_expression(
         &mut self,
         context: ParserContext,
-    ) -> Result<String, Box<dyn Error + Send>> {
+    ) -> Result<Expression, Error> {
+        let expression = self.parse_expression_in_parentheses(context)?;
+
+        Ok(ParenthesesExpression {
+            expression: Box::new(expression),
+        }
+        .into())
+    }
+
+    /**
+     * 괄호가 문법의 일부인 경우의 표현식 파싱
+     * CHECK (expression), AS (expression) 등
+     */
+    pub(crate) fn parse_expression_in_parentheses(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<Expression, Error> {
         let context = context.set_in_parentheses(true);
 
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0203 need more tokens"));
+            return Err(self.unexpected_eof("E0203"));
         }
 
         // ( 삼킴
         let current_token = self.get_next_token();
 
         if current_token != Token::LeftParentheses {
-            return Err(ParsingError::boxed(format!(
-                "expected left parentheses. but your input is {:?}",
-                current_token
-            )));
+            return Err(self.unexpected_token("E0228", &["("], current_token));
         }
 
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0204 need more tokens"));
+            return Err(self.unexpected_eof("E0204"));
         }
 
         // 표현식 파싱
-        let expression = self.parse_expression(context.clone())?;
+        let expression = self.parse_expression(context)?;
 
         if !self.has_next_token() {
-            return Err(ParsingError::boxed("E0205 need more tokens"));
+            return Err(self.unexpected_eof("E0205"));
         }
 
         // ) 삼킴
         let current_token = self.get_next_token();
 
         match current_token {
-            // 우선순위 연산자
-            Token::RightParentheses => {
-                let expression = format!("({})", expression);
+            Token::RightParentheses => Ok(expression),
+            _ => Err(self.unexpected_token("E0229", &[")"], current_token)),
+        }
+    }
+
+    /**
+     * 리스트 파싱
+     * listexpr ::= '(' [expression [, ...]] ')'
+     */
+    fn parse_expression_list(&mut self, context: ParserContext) -> Result<Vec<Expression>, Error> {
+        let context = context.set_in_parentheses(true);
 
-                Ok(expression.into())
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E0222"));
+        }
+
+        // ( 삼킴
+        let current_token = self.get_next_token();
+
+        if current_token != Token::LeftParentheses {
+            return Err(self.unexpected_token("E0230", &["("], current_token));
+        }
+
+        let mut list = vec![];
+
+        if !self.has_next_token() {
+            return Err(self.unexpected_eof("E0223"));
+        }
+
+        let current_token = self.get_next_token();
+
+        // 빈 목록
+        if Token::RightParentheses == current_token {
+            return Ok(list);
+        }
+
+        self.unget_next_token(current_token);
+
+        loop {
+            list.push(self.parse_expression(context.clone())?);
+
+            if !self.has_next_token() {
+                return Err(self.unexpected_eof("E0224"));
+            }
+
+            let current_token = self.get_next_token();
+
+            match current_token {
+                Token::Comma => continue,
+                Token::RightParentheses => break,
+                _ => return Err(self.unexpected_token("E0225", &[",", ")"], current_token)),
             }
-            _ => Err(ParsingError::boxed(format!(
-                "expected right parentheses. but your input is {:?}",
-                current_token
-            ))),
         }
+
+        Ok(list)
     }
 }
@@ -1,2 +1,5 @@
+// impl 블록만 있는 모듈도 기존 경로로 접근할 수 있도록 다시 내보냅니다.
+#![allow(unused_imports)]
+
 pub mod expression;
 pub use expression::*;
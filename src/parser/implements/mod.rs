@@ -1,3 +1,6 @@
+// impl 블록만 있는 모듈도 기존 경로로 접근할 수 있도록 다시 내보냅니다.
+#![allow(unused_imports)]
+
 pub mod utils;
 pub use utils::*;
 
@@ -10,5 +13,8 @@ pub use dml::*;
 pub mod common;
 pub use common::*;
 
+pub mod comment;
+pub use comment::*;
+
 pub mod debug;
 pub use debug::*;
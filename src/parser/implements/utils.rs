@@ -1,20 +1,78 @@
+use crate::errors::predule::{Error, ErrorKind};
+use crate::lexer::predule::{Span, Token, TokenWithSpan};
 use crate::parser::predule::Parser;
 
-use crate::lexer::predule::Token;
-
 impl Parser {
     // 다음 토큰 획득
     pub(crate) fn get_next_token(&mut self) -> Token {
-        self.tokens.pop_front().unwrap()
+        let TokenWithSpan { token, span } = self.tokens.pop_front().unwrap();
+        self.consumed_spans.push(span);
+        token
     }
 
     // 토큰 획득 롤백
     pub(crate) fn unget_next_token(&mut self, token: Token) {
-        self.tokens.push_front(token)
+        let span = self.consumed_spans.pop().unwrap_or_default();
+        self.tokens.push_front(TokenWithSpan::new(token, span))
     }
 
     // 다음 토큰 유무 확인
     pub(crate) fn has_next_token(&self) -> bool {
-        !self.tokens.is_empty() && !self.tokens.front().unwrap().is_eof()
+        !self.tokens.is_empty() && !self.tokens.front().unwrap().token.is_eof()
+    }
+
+    // 마지막으로 읽은 토큰의 위치
+    pub(crate) fn last_span(&self) -> Span {
+        self.consumed_spans.last().copied().unwrap_or_default()
+    }
+
+    // 다음에 읽을 토큰의 위치
+    pub(crate) fn next_span(&self) -> Span {
+        self.tokens
+            .front()
+            .map(|token| token.span)
+            .unwrap_or_else(|| self.last_span())
+    }
+
+    // 마지막으로 읽은 토큰 위치를 담은 구문 분석 오류
+    pub(crate) fn parsing_error<T: ToString>(&self, code: &'static str, message: T) -> Error {
+        Error::Parsing(Box::new(
+            ErrorKind::new(code, message).with_span(self.last_span()),
+        ))
+    }
+
+    // 토큰이 더 필요한데 입력이 끝난 경우
+    pub(crate) fn unexpected_eof(&self, code: &'static str) -> Error {
+        self.parsing_error(code, "need more tokens")
+    }
+
+    // 기대하지 않은 토큰을 만난 경우
+    // expected가 비어있으면 어떤 토큰이 와야 하는지 특정할 수 없는 경우입니다.
+    pub(crate) fn unexpected_token(
+        &self,
+        code: &'static str,
+        expected: &[&str],
+        found: Token,
+    ) -> Error {
+        let message = if expected.is_empty() {
+            format!("unexpected token {:?}", found)
+        } else {
+            format!(
+                "expected {}. but your input is {:?}",
+                expected
+                    .iter()
+                    .map(|word| format!("'{}'", word))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                found
+            )
+        };
+
+        Error::Parsing(Box::new(
+            ErrorKind::new(code, message)
+                .with_expected(expected)
+                .with_found(found)
+                .with_span(self.last_span()),
+        ))
     }
 }
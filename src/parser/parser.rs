@@ -1,83 +1,210 @@
-use std::{collections::VecDeque, error::Error};
+use std::collections::VecDeque;
+use std::sync::Arc;
 
-use crate::ast::predule::SQLStatement;
-use crate::lexer::predule::{Token, Tokenizer};
-use crate::parser::predule::ParserContext;
+use crate::ast::predule::{CodeComments, SQLStatement};
+use crate::dialect::predule::{Dialect, GenericDialect};
+use crate::errors::predule::{Error, ErrorKind};
+use crate::lexer::predule::{Span, Token, TokenWithSpan, Tokenizer};
+use crate::logger::predule::log_warn;
+use crate::parser::predule::{merge_comment_on, ParserContext};
 
 #[derive(Debug)]
 pub struct Parser {
     pub current_token: Token,
-    pub tokens: VecDeque<Token>,
+    pub tokens: VecDeque<TokenWithSpan>,
+    pub dialect: Arc<dyn Dialect>,
+    // 읽어간 토큰들의 위치 (토큰을 되돌릴 때 함께 복원)
+    pub(crate) consumed_spans: Vec<Span>,
+    // 토큰 목록에서 분리한 코드 주석 (아직 노드에 붙지 않은 것들)
+    pub(crate) comments: VecDeque<TokenWithSpan>,
+    // 어휘 분석에 실패해서 건너뛴 문장의 오류
+    pub(crate) lexing_diagnostics: Vec<Error>,
 }
 
 impl Parser {
     // 파서 객체 생성
-    pub fn new(text: String) -> Result<Self, Box<dyn Error + Send>> {
-        Ok(Self {
-            current_token: Token::EOF,
-            tokens: VecDeque::from(Tokenizer::string_to_tokens(text)?),
-        })
+    pub fn new(text: String) -> Result<Self, Error> {
+        Self::with_dialect(text, Arc::new(GenericDialect {}))
+    }
+
+    // 주어진 방언의 규칙을 따르는 파서 객체 생성
+    pub fn with_dialect(text: String, dialect: Arc<dyn Dialect>) -> Result<Self, Error> {
+        Self::with_tokenizer(Tokenizer::with_dialect(text, dialect))
+    }
+
+    // 설정을 마친 어휘 분석기로 파서 객체 생성
+    // 예: Tokenizer::with_dialect(text, dialect).set_conditional_comments(true)
+    // 어휘 분석 오류가 난 문장은 건너뛰고, 오류는 구문 분석 오류와 함께 보고합니다.
+    pub fn with_tokenizer(mut tokenizer: Tokenizer) -> Result<Self, Error> {
+        let (tokens, lexing_diagnostics) = tokenizer.tokenize_with_diagnostics();
+
+        let mut parser = Self::from_spanned_tokens(tokens, tokenizer.dialect());
+        parser.lexing_diagnostics = lexing_diagnostics;
+
+        Ok(parser)
     }
 
     // 파서 객체 생성
     pub fn with_tokens(tokens: VecDeque<Token>) -> Self {
+        Self::from_spanned_tokens(
+            tokens.into_iter().map(TokenWithSpan::from).collect(),
+            Arc::new(GenericDialect {}),
+        )
+    }
+
+    // 코드 주석은 구문 분석에 끼어들지 않도록 따로 보관합니다.
+    fn from_spanned_tokens(tokens: Vec<TokenWithSpan>, dialect: Arc<dyn Dialect>) -> Self {
+        let (comments, tokens): (VecDeque<_>, VecDeque<_>) = tokens
+            .into_iter()
+            .partition(|token| matches!(token.token, Token::CodeComment(_)));
+
         Self {
             current_token: Token::EOF,
             tokens,
+            dialect,
+            consumed_spans: vec![],
+            comments,
+            lexing_diagnostics: vec![],
+        }
+    }
+
+    // 쿼리 목록 분석
+    // 하나라도 분석에 실패하면 첫번째 오류를 반환합니다.
+    pub fn parse(&mut self, context: ParserContext) -> Result<Vec<SQLStatement>, Error> {
+        let result = self.parse_with_diagnostics(context);
+
+        match result.diagnostics.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(result.statements),
         }
     }
 
-    pub fn parse(
-        &mut self,
-        context: ParserContext,
-    ) -> Result<Vec<SQLStatement>, Box<dyn Error + Send>> {
-        let mut statements: Vec<SQLStatement> = vec![];
+    // 쿼리 목록을 분석해서 JSON 문자열로 변환합니다.
+    // JSON 형태는 ast/README.md를 참고하세요.
+    pub fn parse_to_json(&mut self, context: ParserContext) -> Result<String, Error> {
+        let statements = self.parse(context)?;
+
+        serde_json::to_string_pretty(&statements)
+            .map_err(|error| Error::Into(Box::new(ErrorKind::new("E0903", error))))
+    }
+
+    // 쿼리 목록 분석
+    // 분석에 실패한 쿼리는 다음 ;까지 건너뛰고 오류를 diagnostics에 모읍니다.
+    // strict 모드에서는 첫 오류에서 분석을 중단합니다.
+    pub fn parse_with_diagnostics(&mut self, context: ParserContext) -> ParseResult {
+        let mut result = ParseResult::default();
+
+        // 어휘 분석 오류는 원본 위치 순서대로 구문 분석 오류 사이에 끼워 넣습니다.
+        let mut lexing_diagnostics: VecDeque<Error> =
+            std::mem::take(&mut self.lexing_diagnostics).into();
 
         // Top-Level Parser Loop
         loop {
-            if self.has_next_token() {
-                let current_token = self.get_next_token();
+            while lexing_diagnostics.front().is_some_and(|error| {
+                !self.has_next_token() || error_offset(error) < self.next_span().start.offset
+            }) {
+                result.diagnostics.extend(lexing_diagnostics.pop_front());
+
+                if context.strict {
+                    return result;
+                }
+            }
+
+            if !self.has_next_token() {
+                break;
+            }
 
-                match current_token {
-                    Token::EOF => {
-                        // 루프 종료
+            // 오류 발생 시 복구할 위치 (다음 ; 직후)
+            let boundary = self.consumed_spans.len()
+                + self
+                    .tokens
+                    .iter()
+                    .position(|token| token.token == Token::SemiColon)
+                    .map(|index| index + 1)
+                    .unwrap_or(self.tokens.len());
+
+            let leading = self.take_leading_comments(self.next_span());
+            let current_token = self.get_next_token();
+            let start = self.last_span();
+
+            let statement = match current_token {
+                Token::EOF => {
+                    // 루프 종료
+                    break;
+                }
+                Token::SemiColon => {
+                    // top-level 세미콜론 무시
+                    continue;
+                }
+                Token::Create => self.handle_create_query(context.clone()),
+                Token::Alter => self.handle_alter_query(context.clone()),
+                Token::Drop => self.handle_drop_query(context.clone()),
+                Token::Comment if self.dialect.supports_comment_on() => {
+                    self.handle_comment_on_query(context.clone())
+                }
+                _ => Err(self.parsing_error(
+                    "E1001",
+                    format!("unsupported statement starting with '{}'", current_token),
+                )),
+            };
+
+            match statement {
+                Ok(statement) => {
+                    let end = self.last_span();
+                    let code_comments = CodeComments {
+                        leading,
+                        trailing: self.take_trailing_comments(end, &[Token::SemiColon]),
+                    };
+
+                    let statement = statement
+                        .set_span(start.union(&end))
+                        .set_code_comments(code_comments);
+
+                    // COMMENT ON 문은 가능하면 바로 앞의 문장에 합칩니다.
+                    let statement = if current_token == Token::Comment {
+                        merge_comment_on(result.statements.last_mut(), statement)
+                    } else {
+                        Some(statement)
+                    };
+
+                    result.statements.extend(statement);
+                }
+                Err(error) => {
+                    let error = error.or_span(self.last_span());
+                    log_warn!("{}", error);
+                    result.diagnostics.push(error);
+
+                    if context.strict {
                         break;
                     }
-                    Token::SemiColon => {
-                        // top-level 세미콜론 무시
-                        continue;
-                    }
-                    Token::Create => {
-                        if let Ok(query) = self.handle_create_query(context.clone()) {
-                            statements.push(query);
-                        } else {
-                            continue;
-                        }
-                    }
-                    Token::Alter => {
-                        if let Ok(query) = self.handle_alter_query(context.clone()) {
-                            statements.push(query);
-                        } else {
-                            continue;
-                        }
-                    }
-                    Token::Drop => {
-                        if let Ok(query) = self.handle_drop_query(context.clone()) {
-                            statements.push(query);
-                        } else {
-                            continue;
-                        }
-                    }
-                    // DDL 쿼리가 나올때까지 삼킴
-                    _ => {
-                        continue;
+
+                    while self.consumed_spans.len() < boundary && self.has_next_token() {
+                        self.get_next_token();
                     }
                 }
-            } else {
-                break;
             }
         }
 
-        Ok(statements)
+        result
+    }
+}
+
+fn error_offset(error: &Error) -> usize {
+    error
+        .span()
+        .map(|span| span.start.offset)
+        .unwrap_or_default()
+}
+
+// 분석에 성공한 쿼리와 실패한 쿼리의 오류 목록
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ParseResult {
+    pub statements: Vec<SQLStatement>,
+    pub diagnostics: Vec<Error>,
+}
+
+impl ParseResult {
+    pub fn has_errors(&self) -> bool {
+        !self.diagnostics.is_empty()
     }
 }
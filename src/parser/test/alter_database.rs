@@ -1,7 +1,12 @@
 #![cfg(test)]
+
+use std::sync::Arc;
+
 use crate::ast::ddl::{AlterDatabaseAction, AlterDatabaseQuery, AlterDatabaseRenameTo};
+use crate::dialect::predule::GenericDialect;
 use crate::parser::context::ParserContext;
 use crate::parser::predule::Parser;
+use crate::renderer::test::round_trip::assert_round_trip;
 
 #[test]
 pub fn alter_database_1() {
@@ -19,6 +24,8 @@ pub fn alter_database_1() {
         }))
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -38,8 +45,26 @@ pub fn alter_database_2() {
         .set_name("foo".to_owned())
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
     );
 }
+
+#[test]
+pub fn alter_database_error() {
+    let cases = [
+        ("ALTER DATABASE foo RENAME bar;", "E0107"),
+        ("ALTER DATABASE foo OWNER TO bar;", "E0116"),
+    ];
+
+    for (text, code) in cases {
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+
+        let error = parser.parse(ParserContext::default()).unwrap_err();
+
+        assert_eq!(error.code(), code, "{}", text);
+    }
+}
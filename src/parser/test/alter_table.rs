@@ -1,12 +1,26 @@
 #![cfg(test)]
+
+use std::sync::Arc;
+
 use crate::ast::ddl::{
-    AlterColumnDropDefault, AlterColumnDropNotNull, AlterColumnSetDefault, AlterColumnSetNotNull,
-    AlterColumnSetType, AlterTableAlterColumn, AlterTableDropColumn, AlterTableRenameColumn,
+    AlterColumnAction, AlterColumnAddIdentity, AlterColumnDropComment, AlterColumnDropDefault,
+    AlterColumnDropIdentity, AlterColumnDropNotNull, AlterColumnSetCollation,
+    AlterColumnSetComment, AlterColumnSetDefault, AlterColumnSetIdentity, AlterColumnSetNotNull,
+    AlterColumnSetStatistics, AlterColumnSetType, AlterTableAction, AlterTableAddConstraint,
+    AlterTableAddIndex, AlterTableAlterColumn, AlterTableChangeColumn, AlterTableDropColumn,
+    AlterTableDropConstraint, AlterTableDropIndex, AlterTableModifyColumn, AlterTableRenameColumn,
+    AlterTableRenameConstraint, AlterTableSetTableOption, AlterTableValidateConstraint,
+    ColumnPosition,
 };
 use crate::ast::predule::{
-    AlterTableAddColumn, AlterTableQuery, AlterTableRenameTo, Column, DataType, TableName,
+    AlterTableAddColumn, AlterTableQuery, AlterTableRenameTo, Column, ColumnName, DataType,
+    DropBehavior, Expression, Identity, IdentityKind, Index, IndexColumn, SortOrder, TableName,
+    TableOption, UniqueKey,
 };
+use crate::dialect::predule::{GenericDialect, MySQLDialect, PostgreSQLDialect};
+use crate::lexer::predule::NodeSpan;
 use crate::parser::predule::{Parser, ParserContext};
+use crate::renderer::test::round_trip::assert_round_trip;
 
 #[test]
 pub fn alter_table_rename_1() {
@@ -22,9 +36,11 @@ pub fn alter_table_rename_1() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(AlterTableRenameTo { name: "bar".into() }.into())
+        .add_action(AlterTableRenameTo { name: "bar".into() }.into())
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -45,17 +61,20 @@ pub fn alter_table_add_column_1() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(
+        .add_action(
             AlterTableAddColumn {
                 column: Column::builder()
                     .set_name("name".to_owned())
-                    .set_data_type(DataType::Varchar(100))
+                    .set_data_type(DataType::Varchar(Some(100)))
                     .build(),
+                position: None,
             }
             .into(),
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -76,17 +95,20 @@ pub fn alter_table_add_column_2() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(
+        .add_action(
             AlterTableAddColumn {
                 column: Column::builder()
                     .set_name("name".to_owned())
-                    .set_data_type(DataType::Varchar(100))
+                    .set_data_type(DataType::Varchar(Some(100)))
                     .build(),
+                position: None,
             }
             .into(),
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -107,7 +129,7 @@ pub fn alter_table_rename_column_1() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(
+        .add_action(
             AlterTableRenameColumn {
                 from_name: "name".into(),
                 to_name: "name_1".into(),
@@ -116,6 +138,8 @@ pub fn alter_table_rename_column_1() {
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -136,7 +160,7 @@ pub fn alter_table_rename_column_2() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(
+        .add_action(
             AlterTableRenameColumn {
                 from_name: "name".into(),
                 to_name: "name_1".into(),
@@ -145,6 +169,8 @@ pub fn alter_table_rename_column_2() {
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -165,7 +191,7 @@ pub fn alter_table_alter_column_drop_not_null_1() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(
+        .add_action(
             AlterTableAlterColumn {
                 column_name: "name".into(),
                 action: AlterColumnDropNotNull {}.into(),
@@ -174,6 +200,8 @@ pub fn alter_table_alter_column_drop_not_null_1() {
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -194,7 +222,7 @@ pub fn alter_table_alter_column_set_not_null_1() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(
+        .add_action(
             AlterTableAlterColumn {
                 column_name: "name".into(),
                 action: AlterColumnSetNotNull {}.into(),
@@ -203,6 +231,8 @@ pub fn alter_table_alter_column_set_not_null_1() {
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -223,11 +253,12 @@ pub fn alter_table_alter_column_set_type_1() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(
+        .add_action(
             AlterTableAlterColumn {
                 column_name: "name".into(),
                 action: AlterColumnSetType {
-                    data_type: DataType::Int,
+                    data_type: DataType::Int(None),
+                    using: None,
                 }
                 .into(),
             }
@@ -235,6 +266,8 @@ pub fn alter_table_alter_column_set_type_1() {
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -255,11 +288,12 @@ pub fn alter_table_alter_column_set_type_2() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(
+        .add_action(
             AlterTableAlterColumn {
                 column_name: "name".into(),
                 action: AlterColumnSetType {
-                    data_type: DataType::Int,
+                    data_type: DataType::Int(None),
+                    using: None,
                 }
                 .into(),
             }
@@ -267,6 +301,8 @@ pub fn alter_table_alter_column_set_type_2() {
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -287,11 +323,11 @@ pub fn alter_table_alter_column_set_default_1() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(
+        .add_action(
             AlterTableAlterColumn {
                 column_name: "id".into(),
                 action: AlterColumnSetDefault {
-                    expression: "0".to_owned(),
+                    expression: Expression::Integer(0),
                 }
                 .into(),
             }
@@ -299,6 +335,8 @@ pub fn alter_table_alter_column_set_default_1() {
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -319,7 +357,7 @@ pub fn alter_table_alter_column_drop_default_1() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(
+        .add_action(
             AlterTableAlterColumn {
                 column_name: "id".into(),
                 action: AlterColumnDropDefault {}.into(),
@@ -328,6 +366,8 @@ pub fn alter_table_alter_column_drop_default_1() {
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -348,7 +388,7 @@ pub fn alter_table_drop_column_1() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(
+        .add_action(
             AlterTableDropColumn {
                 column_name: "name".into(),
             }
@@ -356,6 +396,8 @@ pub fn alter_table_drop_column_1() {
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -376,7 +418,7 @@ pub fn alter_table_drop_column_2() {
             table_name: "foo".to_owned(),
             database_name: None,
         })
-        .set_action(
+        .add_action(
             AlterTableDropColumn {
                 column_name: "name".into(),
             }
@@ -384,8 +426,570 @@ pub fn alter_table_drop_column_2() {
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn alter_table_multiple_actions() {
+    let text = r#"
+        ALTER TABLE foo ADD COLUMN a int, DROP COLUMN b, ALTER COLUMN c SET NOT NULL;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = AlterTableQuery::builder()
+        .set_table(TableName {
+            table_name: "foo".to_owned(),
+            database_name: None,
+        })
+        .add_action(
+            AlterTableAddColumn {
+                column: Column::builder()
+                    .set_name("a".into())
+                    .set_data_type(DataType::Int(None))
+                    .build(),
+                position: None,
+            }
+            .into(),
+        )
+        .add_action(
+            AlterTableDropColumn {
+                column_name: "b".into(),
+            }
+            .into(),
+        )
+        .add_action(
+            AlterTableAlterColumn {
+                column_name: "c".into(),
+                action: AlterColumnSetNotNull {}.into(),
+            }
+            .into(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn alter_table_multiple_actions_error() {
+    let cases = [
+        ("ALTER TABLE foo DROP COLUMN a DROP COLUMN b;", "E1242"),
+        ("ALTER TABLE foo DROP COLUMN a, ;", "E1202"),
+        ("ALTER TABLE foo DROP COLUMN a,", "E1243"),
+        ("ALTER TABLE foo;", "E1243"),
+        ("ALTER TABLE foo", "E1243"),
+    ];
+
+    for (text, code) in cases {
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+
+        let error = parser.parse(ParserContext::default()).unwrap_err();
+
+        assert_eq!(error.code(), code, "{}", text);
+    }
+}
+
+#[test]
+pub fn alter_table_add_constraint_1() {
+    let text = r#"
+        ALTER TABLE foo ADD CONSTRAINT uq_foo_email UNIQUE (email) NOT VALID;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = AlterTableQuery::builder()
+        .set_table(TableName {
+            table_name: "foo".to_owned(),
+            database_name: None,
+        })
+        .add_action(
+            AlterTableAddConstraint {
+                constraint: UniqueKey {
+                    key_name: Some("uq_foo_email".to_owned()),
+                    database_name: None,
+                    columns: vec!["email".to_owned()],
+                    span: NodeSpan::default(),
+                }
+                .into(),
+                not_valid: true,
+            }
+            .into(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn alter_table_drop_constraint_1() {
+    let text = r#"
+        ALTER TABLE foo
+            DROP CONSTRAINT IF EXISTS uq_foo_email CASCADE,
+            DROP FOREIGN KEY fk_foo_bar,
+            DROP CONSTRAINT ck_foo RESTRICT;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = AlterTableQuery::builder()
+        .set_table(TableName {
+            table_name: "foo".to_owned(),
+            database_name: None,
+        })
+        .add_action(
+            AlterTableDropConstraint {
+                constraint_name: "uq_foo_email".into(),
+                if_exists: true,
+                drop_behavior: Some(DropBehavior::Cascade),
+            }
+            .into(),
+        )
+        .add_action(
+            AlterTableDropConstraint {
+                constraint_name: "fk_foo_bar".into(),
+                if_exists: false,
+                drop_behavior: None,
+            }
+            .into(),
+        )
+        .add_action(
+            AlterTableDropConstraint {
+                constraint_name: "ck_foo".into(),
+                if_exists: false,
+                drop_behavior: Some(DropBehavior::Restrict),
+            }
+            .into(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn alter_table_index_1() {
+    let text = r#"
+        ALTER TABLE foo ADD INDEX idx_foo_name (name DESC), ADD KEY (age), DROP INDEX idx_foo_old, DROP PRIMARY KEY;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = AlterTableQuery::builder()
+        .set_table(TableName {
+            table_name: "foo".to_owned(),
+            database_name: None,
+        })
+        .add_action(
+            AlterTableAddIndex {
+                index: Index {
+                    index_name: "idx_foo_name".into(),
+                    columns: vec![IndexColumn {
+                        column_name: "name".into(),
+                        order: Some(SortOrder::Desc),
+                    }],
+                    is_unique: false,
+                    method: None,
+                },
+            }
+            .into(),
+        )
+        .add_action(
+            AlterTableAddIndex {
+                index: Index {
+                    index_name: "age".into(),
+                    columns: vec![IndexColumn {
+                        column_name: "age".into(),
+                        order: None,
+                    }],
+                    is_unique: false,
+                    method: None,
+                },
+            }
+            .into(),
+        )
+        .add_action(
+            AlterTableDropIndex {
+                index_name: "idx_foo_old".into(),
+            }
+            .into(),
+        )
+        .add_action(AlterTableAction::DropPrimaryKey)
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn alter_table_rename_validate_constraint_1() {
+    let text = r#"
+        ALTER TABLE foo RENAME CONSTRAINT fk_a TO fk_b, VALIDATE CONSTRAINT fk_b;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = AlterTableQuery::builder()
+        .set_table(TableName {
+            table_name: "foo".to_owned(),
+            database_name: None,
+        })
+        .add_action(
+            AlterTableRenameConstraint {
+                from_name: "fk_a".into(),
+                to_name: "fk_b".into(),
+            }
+            .into(),
+        )
+        .add_action(
+            AlterTableValidateConstraint {
+                constraint_name: "fk_b".into(),
+            }
+            .into(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn alter_table_constraint_error() {
+    let cases = [
+        ("ALTER TABLE foo VALIDATE fk_a;", "E1245"),
+        ("ALTER TABLE foo RENAME CONSTRAINT fk_a fk_b;", "E1248"),
+        ("ALTER TABLE foo DROP PRIMARY foo;", "E1253"),
+        ("ALTER TABLE foo DROP CONSTRAINT;", "E1251"),
+    ];
+
+    for (text, code) in cases {
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+
+        let error = parser.parse(ParserContext::default()).unwrap_err();
+
+        assert_eq!(error.code(), code, "{}", text);
+    }
+}
+
+#[test]
+pub fn alter_table_modify_change_column_1() {
+    let text = r#"
+        ALTER TABLE foo
+            MODIFY COLUMN name varchar(20) NOT NULL AFTER id,
+            CHANGE old_age age int FIRST,
+            ADD COLUMN email text AFTER name;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_dialect(text, Arc::new(MySQLDialect {})).unwrap();
+
+    let expected = AlterTableQuery::builder()
+        .set_table(TableName {
+            table_name: "foo".to_owned(),
+            database_name: None,
+        })
+        .add_action(
+            AlterTableModifyColumn {
+                column: Column::builder()
+                    .set_name("name".into())
+                    .set_data_type(DataType::Varchar(Some(20)))
+                    .set_not_null(true)
+                    .build(),
+                position: Some(ColumnPosition::After("id".into())),
+            }
+            .into(),
+        )
+        .add_action(
+            AlterTableChangeColumn {
+                old_name: "old_age".into(),
+                column: Column::builder()
+                    .set_name("age".into())
+                    .set_data_type(DataType::Int(None))
+                    .build(),
+                position: Some(ColumnPosition::First),
+            }
+            .into(),
+        )
+        .add_action(
+            AlterTableAddColumn {
+                column: Column::builder()
+                    .set_name("email".into())
+                    .set_data_type(DataType::Text)
+                    .build(),
+                position: Some(ColumnPosition::After("name".into())),
+            }
+            .into(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(MySQLDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn alter_table_set_table_option_1() {
+    let text = r#"
+        ALTER TABLE foo ENGINE = InnoDB, DEFAULT CHARSET=utf8mb4, COMMENT 'foo table';
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_dialect(text, Arc::new(MySQLDialect {})).unwrap();
+
+    let expected = AlterTableQuery::builder()
+        .set_table(TableName::new(None, "foo".to_owned()))
+        .add_action(
+            AlterTableSetTableOption {
+                option: TableOption::new("ENGINE", Some("InnoDB".into())),
+            }
+            .into(),
+        )
+        .add_action(
+            AlterTableSetTableOption {
+                option: TableOption::new("CHARSET", Some("utf8mb4".into())),
+            }
+            .into(),
+        )
+        .add_action(
+            AlterTableSetTableOption {
+                option: TableOption::new("COMMENT", Some("foo table".into())),
+            }
+            .into(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(MySQLDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn alter_table_modify_change_column_error() {
+    let cases = [
+        ("ALTER TABLE foo MODIFY COLUMN", "E1256"),
+        ("ALTER TABLE foo CHANGE COLUMN ;", "E1257"),
+        ("ALTER TABLE foo CHANGE a", "E1258"),
+        ("ALTER TABLE foo MODIFY a int AFTER ;", "E1259"),
+    ];
+
+    for (text, code) in cases {
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+
+        let error = parser.parse(ParserContext::default()).unwrap_err();
+
+        assert_eq!(error.code(), code, "{}", text);
+    }
+}
+
+#[test]
+pub fn alter_table_alter_column_postgres_1() {
+    let text = r#"
+        ALTER TABLE foo
+            ALTER COLUMN age SET DATA TYPE bigint USING age,
+            ALTER COLUMN age SET COMMENT 'age in years',
+            ALTER COLUMN name DROP COMMENT,
+            ALTER COLUMN id ADD GENERATED BY DEFAULT AS IDENTITY (START WITH 10 INCREMENT BY 2),
+            ALTER COLUMN id SET GENERATED ALWAYS,
+            ALTER COLUMN no DROP IDENTITY IF EXISTS,
+            ALTER COLUMN name SET STATISTICS -1,
+            ALTER COLUMN name SET COLLATION "C";
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let alter_column = |column_name: &str, action: AlterColumnAction| -> AlterTableAction {
+        AlterTableAlterColumn {
+            column_name: column_name.into(),
+            action,
+        }
+        .into()
+    };
+
+    let expected = AlterTableQuery::builder()
+        .set_table(TableName {
+            table_name: "foo".to_owned(),
+            database_name: None,
+        })
+        .add_action(alter_column(
+            "age",
+            AlterColumnSetType {
+                data_type: DataType::BigInt(None),
+                using: Some(ColumnName::new(None, "age".to_owned()).into()),
+            }
+            .into(),
+        ))
+        .add_action(alter_column(
+            "age",
+            AlterColumnSetComment {
+                comment: "age in years".into(),
+            }
+            .into(),
+        ))
+        .add_action(alter_column("name", AlterColumnDropComment {}.into()))
+        .add_action(alter_column(
+            "id",
+            AlterColumnAddIdentity {
+                identity: Identity {
+                    kind: IdentityKind::ByDefault,
+                    start: Some(10),
+                    increment: Some(2),
+                },
+            }
+            .into(),
+        ))
+        .add_action(alter_column(
+            "id",
+            AlterColumnSetIdentity {
+                kind: IdentityKind::Always,
+            }
+            .into(),
+        ))
+        .add_action(alter_column(
+            "no",
+            AlterColumnDropIdentity { if_exists: true }.into(),
+        ))
+        .add_action(alter_column(
+            "name",
+            AlterColumnSetStatistics { value: -1 }.into(),
+        ))
+        .add_action(alter_column(
+            "name",
+            AlterColumnSetCollation {
+                collation: "C".into(),
+            }
+            .into(),
+        ))
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
     );
 }
+
+#[test]
+pub fn alter_table_alter_column_postgres_error() {
+    let cases = [
+        ("ALTER TABLE foo ALTER COLUMN a SET COMMENT 1;", "E1262"),
+        ("ALTER TABLE foo ALTER COLUMN a SET STATISTICS x;", "E0055"),
+        ("ALTER TABLE foo ALTER COLUMN a ADD IDENTITY;", "E1266"),
+        (
+            "ALTER TABLE foo ALTER COLUMN a ADD GENERATED ALWAYS IDENTITY;",
+            "E1271",
+        ),
+        (
+            "ALTER TABLE foo ALTER COLUMN a ADD GENERATED ALWAYS",
+            "E1267",
+        ),
+        (
+            "ALTER TABLE foo ALTER COLUMN a ADD GENERATED ALWAYS AS",
+            "E1268",
+        ),
+        (
+            "ALTER TABLE foo ALTER COLUMN a ADD GENERATED ALWAYS AS SEQUENCE;",
+            "E1272",
+        ),
+        ("ALTER TABLE foo ALTER COLUMN a DROP STATISTICS;", "E1231"),
+    ];
+
+    for (text, code) in cases {
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+
+        let error = parser.parse(ParserContext::default()).unwrap_err();
+
+        assert_eq!(error.code(), code, "{}", text);
+    }
+}
+
+#[test]
+pub fn alter_table_only() {
+    let text = r#"
+        ALTER TABLE ONLY public.users ADD CONSTRAINT users_email_key UNIQUE (email);
+        ALTER TABLE only ADD COLUMN a INT;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_dialect(text, Arc::new(PostgreSQLDialect {})).unwrap();
+
+    let expected = vec![
+        AlterTableQuery::builder()
+            .set_table(TableName::new(
+                Some("public".to_owned()),
+                "users".to_owned(),
+            ))
+            .set_only(true)
+            .add_action(
+                AlterTableAddConstraint {
+                    constraint: UniqueKey {
+                        key_name: Some("users_email_key".to_owned()),
+                        database_name: Some("public".to_owned()),
+                        columns: vec!["email".to_owned()],
+                        span: NodeSpan::default(),
+                    }
+                    .into(),
+                    not_valid: false,
+                }
+                .into(),
+            )
+            .build(),
+        AlterTableQuery::builder()
+            .set_table(TableName::new(None, "only".to_owned()))
+            .add_action(
+                AlterTableAddColumn {
+                    column: Column::builder()
+                        .set_name("a".to_owned())
+                        .set_data_type(DataType::Int(None))
+                        .build(),
+                    position: None,
+                }
+                .into(),
+            )
+            .build(),
+    ];
+
+    for statement in &expected {
+        assert_round_trip(statement, Arc::new(PostgreSQLDialect {}));
+    }
+
+    assert_eq!(parser.parse(ParserContext::default()).unwrap(), expected);
+}
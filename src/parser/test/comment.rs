@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::ast::ddl::{AlterTableAction, AlterTableAddColumn, DDLStatement};
+use crate::ast::predule::{SQLStatement, TableOption, TableOptions};
+use crate::dialect::predule::MySQLDialect;
+use crate::lexer::predule::Tokenizer;
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+
+const TEXT: &str = r#"-- 회원 정보
+/* deprecated */
+CREATE TABLE person (
+    -- primary key
+    id INTEGER PRIMARY KEY, -- auto
+    -- user email
+    email /* inline */ TEXT NOT NULL,
+    name TEXT -- display name
+); -- end of person
+
+SELECT 1; -- skipped
+DROP TABLE person;"#;
+
+// 지원하지 않는 문장(SELECT)은 오류로 보고하고 건너뜁니다.
+fn parse(text: &str) -> Vec<SQLStatement> {
+    let mut parser = Parser::new(text.to_owned()).unwrap();
+
+    let result = parser.parse_with_diagnostics(ParserContext::default());
+
+    assert!(result
+        .diagnostics
+        .iter()
+        .all(|error| error.code() == "E1001"));
+
+    result.statements
+}
+
+#[test]
+pub fn statement_comments() {
+    let statements = parse(TEXT);
+
+    assert_eq!(statements.len(), 2);
+
+    let comments = statements[0].code_comments();
+    assert_eq!(comments.leading, vec!["회원 정보", "deprecated"]);
+    assert_eq!(comments.trailing, vec!["end of person"]);
+
+    // 건너뛴 문장 뒤의 주석은 다음 문장의 앞 주석이 됩니다.
+    let comments = statements[1].code_comments();
+    assert_eq!(comments.leading, vec!["skipped"]);
+    assert!(comments.trailing.is_empty());
+}
+
+#[test]
+pub fn column_comments() {
+    let statements = parse(TEXT);
+
+    let SQLStatement::DDL(DDLStatement::CreateTableQuery(query)) = &statements[0] else {
+        panic!("expected CREATE TABLE: {:?}", statements[0]);
+    };
+
+    let comments: Vec<_> = query
+        .columns
+        .iter()
+        .map(|column| {
+            (
+                column.code_comments.leading.clone(),
+                column.code_comments.trailing.clone(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        comments,
+        vec![
+            (vec!["primary key".to_owned()], vec!["auto".to_owned()]),
+            (vec!["user email".to_owned()], vec![]),
+            (vec![], vec!["display name".to_owned()]),
+        ]
+    );
+
+    // SQL의 COMMENT 절과는 별개
+    assert!(query.columns[2].comment.is_empty());
+}
+
+#[test]
+pub fn comments_do_not_break_parsing() {
+    let statements = parse("ALTER /* x */ TABLE person ADD COLUMN -- y\n age INT -- z\n;");
+
+    let SQLStatement::DDL(DDLStatement::AlterTableQuery(query)) = &statements[0] else {
+        panic!("expected ALTER TABLE: {:?}", statements[0]);
+    };
+
+    let [AlterTableAction::AddColumn(AlterTableAddColumn { column, .. })] =
+        query.actions.as_slice()
+    else {
+        panic!("expected ADD COLUMN: {:?}", query.actions);
+    };
+
+    assert_eq!(column.name, "age");
+    assert_eq!(column.code_comments.leading, vec!["y"]);
+    assert_eq!(column.code_comments.trailing, vec!["z"]);
+    assert!(statements[0].code_comments().is_empty());
+}
+
+#[test]
+pub fn conditional_comments_as_sql() {
+    let text = "/*!40101 CREATE TABLE person (id INT) */ /*!50100 ENGINE=InnoDB */;";
+
+    let tokenizer = Tokenizer::with_dialect(text.to_owned(), Arc::new(MySQLDialect {}))
+        .set_conditional_comments(true);
+    let mut parser = Parser::with_tokenizer(tokenizer).unwrap();
+
+    let statements = parser.parse(ParserContext::default()).unwrap();
+
+    let SQLStatement::DDL(DDLStatement::CreateTableQuery(query)) = &statements[0] else {
+        panic!("expected CREATE TABLE: {:?}", statements[0]);
+    };
+
+    assert_eq!(
+        query.table_options,
+        Some(TableOptions {
+            options: vec![TableOption::new("ENGINE", Some("InnoDB".to_owned()))],
+        })
+    );
+}
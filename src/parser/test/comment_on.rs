@@ -0,0 +1,160 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::ast::ddl::{
+    AlterColumnAction, AlterColumnSetComment, AlterTableAddColumn, AlterTableAlterColumn,
+    AlterTableQuery, AlterTableSetTableOption, CreateTableQuery,
+};
+use crate::ast::types::{Column, DataType, TableName, TableOption, TableOptions};
+use crate::dialect::predule::{GenericDialect, PostgreSQLDialect};
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+use crate::renderer::test::round_trip::assert_round_trip;
+
+#[test]
+pub fn comment_on_merged_into_create_table() {
+    let text = r#"
+        CREATE TABLE person (id integer, name text);
+        COMMENT ON COLUMN person.name IS 'person name';
+        COMMENT ON TABLE person IS 'people';
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_dialect(text, Arc::new(PostgreSQLDialect {})).unwrap();
+
+    let expected = vec![CreateTableQuery::builder()
+        .set_table(TableName::new(None, "person".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("id".to_owned())
+                .set_data_type(DataType::Int(None))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("name".to_owned())
+                .set_data_type(DataType::Text)
+                .set_comment("person name".to_owned())
+                .build(),
+        )
+        .set_table_option(TableOptions {
+            options: vec![TableOption::new("COMMENT", Some("people".to_owned()))],
+        })
+        .build()];
+
+    for statement in &expected {
+        assert_round_trip(statement, Arc::new(PostgreSQLDialect {}));
+    }
+
+    assert_eq!(parser.parse(ParserContext::default()).unwrap(), expected);
+}
+
+#[test]
+pub fn comment_on_merged_into_alter_table() {
+    let text = r#"
+        ALTER TABLE foo.person ADD COLUMN name text;
+        COMMENT ON COLUMN foo.person.name IS 'person name';
+        COMMENT ON COLUMN foo.person.id IS NULL;
+        COMMENT ON COLUMN person.id IS 'id';
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_dialect(text, Arc::new(PostgreSQLDialect {})).unwrap();
+
+    let expected = vec![
+        AlterTableQuery::builder()
+            .set_table(TableName::new(Some("foo".to_owned()), "person".to_owned()))
+            .add_action(
+                AlterTableAddColumn {
+                    column: Column::builder()
+                        .set_name("name".to_owned())
+                        .set_data_type(DataType::Text)
+                        .set_comment("person name".to_owned())
+                        .build(),
+                    position: None,
+                }
+                .into(),
+            )
+            .add_action(
+                AlterTableAlterColumn {
+                    column_name: "id".to_owned(),
+                    action: AlterColumnAction::AlterColumnDropComment,
+                }
+                .into(),
+            )
+            .build(),
+        AlterTableQuery::builder()
+            .set_table(TableName::new(None, "person".to_owned()))
+            .add_action(
+                AlterTableAlterColumn {
+                    column_name: "id".to_owned(),
+                    action: AlterColumnSetComment {
+                        comment: "id".to_owned(),
+                    }
+                    .into(),
+                }
+                .into(),
+            )
+            .build(),
+    ];
+
+    for statement in &expected {
+        assert_round_trip(statement, Arc::new(PostgreSQLDialect {}));
+    }
+
+    assert_eq!(parser.parse(ParserContext::default()).unwrap(), expected);
+}
+
+#[test]
+pub fn comment_on_table_statement() {
+    let statement = AlterTableQuery::builder()
+        .set_table(TableName::new(None, "person".to_owned()))
+        .add_action(
+            AlterTableSetTableOption {
+                option: TableOption::new("COMMENT", Some("people".to_owned())),
+            }
+            .into(),
+        )
+        .build();
+
+    assert_round_trip(&statement, Arc::new(PostgreSQLDialect {}));
+}
+
+#[test]
+pub fn comment_on_error() {
+    let cases = [
+        ("COMMENT", "E1111"),
+        ("COMMENT IN", "E1112"),
+        ("COMMENT ON", "E1113"),
+        ("COMMENT ON INDEX idx IS 'x'", "E1114"),
+        ("COMMENT ON TABLE person", "E1115"),
+        ("COMMENT ON TABLE person = 'x'", "E1116"),
+        ("COMMENT ON TABLE person IS", "E1117"),
+        ("COMMENT ON TABLE person IS 1", "E1118"),
+        ("COMMENT ON COLUMN", "E1119"),
+        ("COMMENT ON COLUMN person.", "E1119"),
+        ("COMMENT ON COLUMN 'name' IS 'x'", "E1120"),
+        ("COMMENT ON COLUMN name IS 'x'", "E1121"),
+    ];
+
+    for (text, code) in cases {
+        let mut parser =
+            Parser::with_dialect(text.to_owned(), Arc::new(PostgreSQLDialect {})).unwrap();
+
+        let error = parser.parse(ParserContext::default()).unwrap_err();
+
+        assert_eq!(error.code(), code, "{}", text);
+    }
+
+    // COMMENT ON을 지원하지 않는 방언
+    let mut parser = Parser::with_dialect(
+        "COMMENT ON TABLE person IS 'x';".to_owned(),
+        Arc::new(GenericDialect {}),
+    )
+    .unwrap();
+
+    let error = parser.parse(ParserContext::default()).unwrap_err();
+
+    assert_eq!(error.code(), "E1001");
+}
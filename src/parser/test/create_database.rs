@@ -1,7 +1,12 @@
 #![cfg(test)]
+
+use std::sync::Arc;
+
 use crate::ast::ddl::CreateDatabaseQuery;
+use crate::dialect::predule::GenericDialect;
 use crate::parser::context::ParserContext;
 use crate::parser::predule::Parser;
+use crate::renderer::test::round_trip::assert_round_trip;
 
 #[test]
 pub fn create_database_1() {
@@ -17,6 +22,8 @@ pub fn create_database_1() {
         .set_if_not_exists(true)
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -37,6 +44,8 @@ pub fn create_database_2() {
         .set_if_not_exists(false)
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
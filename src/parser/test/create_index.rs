@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::ast::ddl::CreateIndexQuery;
+use crate::ast::types::{IndexColumn, SortOrder, TableName};
+use crate::dialect::predule::{GenericDialect, MySQLDialect, PostgreSQLDialect};
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+use crate::renderer::test::round_trip::assert_round_trip;
+
+#[test]
+pub fn create_index_1() {
+    let text = r#"
+        CREATE INDEX idx_person_name ON person (name);
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = CreateIndexQuery::builder()
+        .set_index_name("idx_person_name".to_owned())
+        .set_table(TableName::new(None, "person".to_owned()))
+        .add_column(IndexColumn::new("name".to_owned(), None))
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn create_index_2() {
+    let text = r#"
+        CREATE UNIQUE INDEX IF NOT EXISTS idx_person ON "test_db".person (name ASC, age DESC, id);
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = CreateIndexQuery::builder()
+        .set_index_name("idx_person".to_owned())
+        .set_table(TableName::new(
+            Some("test_db".to_owned()),
+            "person".to_owned(),
+        ))
+        .set_unique(true)
+        .set_if_not_exists(true)
+        .add_column(IndexColumn::new("name".to_owned(), Some(SortOrder::Asc)))
+        .add_column(IndexColumn::new("age".to_owned(), Some(SortOrder::Desc)))
+        .add_column(IndexColumn::new("id".to_owned(), None))
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn create_index_3() {
+    let text = r#"
+        CREATE INDEX idx_person_name ON person (name);
+        CREATE TABLE foo (id INTEGER);
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    assert_eq!(parser.parse(ParserContext::default()).unwrap().len(), 2);
+}
+
+#[test]
+pub fn create_index_using() {
+    let text = "CREATE INDEX idx_users_email ON public.users USING btree (email);".to_owned();
+
+    let mut parser = Parser::with_dialect(text.clone(), Arc::new(PostgreSQLDialect {})).unwrap();
+
+    let expected = CreateIndexQuery::builder()
+        .set_index_name("idx_users_email".to_owned())
+        .set_table(TableName::new(
+            Some("public".to_owned()),
+            "users".to_owned(),
+        ))
+        .set_method("btree".to_owned())
+        .add_column(IndexColumn::new("email".to_owned(), None))
+        .build();
+
+    assert_round_trip(&expected, Arc::new(PostgreSQLDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+
+    // 인덱스 방식을 지원하지 않는 방언
+    let mut parser = Parser::with_dialect(text, Arc::new(MySQLDialect {})).unwrap();
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap_err().code(),
+        "E1308"
+    );
+}
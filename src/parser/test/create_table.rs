@@ -1,9 +1,19 @@
 #![cfg(test)]
 
+use std::sync::Arc;
+
 use crate::ast::ddl::CreateTableQuery;
-use crate::ast::types::{Column, DataType, TableName};
+use crate::ast::dml::{BinaryOperator, BinaryOperatorExpression, Expression};
+use crate::ast::types::{
+    CheckConstraint, Column, ColumnName, DataType, ForeignKey, GeneratedColumn, Identity,
+    IdentityKind, Index, IndexColumn, PrimaryKey, Reference, ReferentialAction, SortOrder,
+    TableName, UniqueKey,
+};
+use crate::dialect::predule::{GenericDialect, MySQLDialect, PostgreSQLDialect};
+use crate::lexer::predule::NodeSpan;
 use crate::parser::context::ParserContext;
 use crate::parser::predule::Parser;
+use crate::renderer::test::round_trip::assert_round_trip;
 
 #[test]
 pub fn create_table() {
@@ -27,24 +37,26 @@ pub fn create_table() {
         .add_column(
             Column::builder()
                 .set_name("id".to_owned())
-                .set_data_type(DataType::Int)
+                .set_data_type(DataType::Int(None))
                 .set_primary_key(true)
                 .build(),
         )
         .add_column(
             Column::builder()
                 .set_name("name".to_owned())
-                .set_data_type(DataType::Varchar(100))
+                .set_data_type(DataType::Varchar(Some(100)))
                 .build(),
         )
         .add_column(
             Column::builder()
                 .set_name("age".to_owned())
-                .set_data_type(DataType::Int)
+                .set_data_type(DataType::Int(None))
                 .build(),
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -73,25 +85,27 @@ pub fn create_table_2() {
         .add_column(
             Column::builder()
                 .set_name("id".to_owned())
-                .set_data_type(DataType::Int)
+                .set_data_type(DataType::Int(None))
                 .set_primary_key(true)
                 .build(),
         )
         .add_column(
             Column::builder()
                 .set_name("name".to_owned())
-                .set_data_type(DataType::Varchar(100))
+                .set_data_type(DataType::Varchar(Some(100)))
                 .set_not_null(true)
                 .build(),
         )
         .add_column(
             Column::builder()
                 .set_name("age".to_owned())
-                .set_data_type(DataType::Int)
+                .set_data_type(DataType::Int(None))
                 .build(),
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
@@ -120,30 +134,583 @@ pub fn create_table_3() {
         .add_column(
             Column::builder()
                 .set_name("id".to_owned())
-                .set_data_type(DataType::Int)
+                .set_data_type(DataType::Int(None))
                 .set_primary_key(true)
-                .set_default("0".to_owned())
+                .set_default(Expression::Integer(0))
                 .build(),
         )
         .add_column(
             Column::builder()
                 .set_name("name".to_owned())
-                .set_data_type(DataType::Varchar(100))
+                .set_data_type(DataType::Varchar(Some(100)))
                 .set_not_null(true)
-                .set_default("test".to_owned())
+                .set_default(Expression::String("test".to_owned()))
                 .build(),
         )
         .add_column(
             Column::builder()
                 .set_name("age".to_owned())
-                .set_data_type(DataType::Int)
-                .set_default("0".to_owned())
+                .set_data_type(DataType::Int(None))
+                .set_default(Expression::Integer(0))
                 .build(),
         )
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
     );
 }
+
+#[test]
+pub fn create_table_constraints_1() {
+    let text = r#"
+        CREATE TABLE order_item
+        (
+            order_id INTEGER,
+            item_id INTEGER,
+            quantity INTEGER,
+            PRIMARY KEY (order_id, item_id),
+            UNIQUE (item_id),
+            FOREIGN KEY (order_id) REFERENCES orders (id) ON DELETE CASCADE ON UPDATE NO ACTION,
+            CHECK (quantity > 0)
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let table = TableName::new(None, "order_item".to_owned());
+
+    let expected = CreateTableQuery::builder()
+        .set_table(table.clone())
+        .add_column(
+            Column::builder()
+                .set_name("order_id".to_owned())
+                .set_data_type(DataType::Int(None))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("item_id".to_owned())
+                .set_data_type(DataType::Int(None))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("quantity".to_owned())
+                .set_data_type(DataType::Int(None))
+                .build(),
+        )
+        .set_primary_key(PrimaryKey {
+            key_name: None,
+            columns: vec!["order_id".to_owned(), "item_id".to_owned()],
+            span: NodeSpan::default(),
+        })
+        .add_unique_key(UniqueKey {
+            key_name: None,
+            database_name: None,
+            columns: vec!["item_id".to_owned()],
+            span: NodeSpan::default(),
+        })
+        .add_foreign_key(ForeignKey {
+            key_name: None,
+            table,
+            columns: vec!["order_id".to_owned()],
+            referenced_table: TableName::new(None, "orders".to_owned()),
+            referenced_columns: vec!["id".to_owned()],
+            on_delete: Some(ReferentialAction::Cascade),
+            on_update: Some(ReferentialAction::NoAction),
+            span: NodeSpan::default(),
+        })
+        .add_check_constraint(CheckConstraint {
+            constraint_name: None,
+            expression: BinaryOperatorExpression {
+                operator: BinaryOperator::Gt,
+                lhs: Box::new(ColumnName::new(None, "quantity".to_owned()).into()),
+                rhs: Box::new(Expression::Integer(0)),
+            }
+            .into(),
+            span: NodeSpan::default(),
+        })
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn create_table_constraints_2() {
+    let text = r#"
+        CREATE TABLE "test_db".person
+        (
+            id INTEGER,
+            email varchar(100),
+            team_id INTEGER,
+            CONSTRAINT pk_person PRIMARY KEY (id),
+            CONSTRAINT uq_person_email UNIQUE (email),
+            CONSTRAINT fk_person_team FOREIGN KEY (team_id) REFERENCES team (id) ON UPDATE SET NULL,
+            UNIQUE KEY uq_person_team (team_id, email)
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let table = TableName::new(Some("test_db".to_owned()), "person".to_owned());
+
+    let expected = CreateTableQuery::builder()
+        .set_table(table.clone())
+        .add_column(
+            Column::builder()
+                .set_name("id".to_owned())
+                .set_data_type(DataType::Int(None))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("email".to_owned())
+                .set_data_type(DataType::Varchar(Some(100)))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("team_id".to_owned())
+                .set_data_type(DataType::Int(None))
+                .build(),
+        )
+        .set_primary_key(PrimaryKey {
+            key_name: Some("pk_person".to_owned()),
+            columns: vec!["id".to_owned()],
+            span: NodeSpan::default(),
+        })
+        .add_unique_key(UniqueKey {
+            key_name: Some("uq_person_email".to_owned()),
+            database_name: Some("test_db".to_owned()),
+            columns: vec!["email".to_owned()],
+            span: NodeSpan::default(),
+        })
+        .add_foreign_key(ForeignKey {
+            key_name: Some("fk_person_team".to_owned()),
+            table,
+            columns: vec!["team_id".to_owned()],
+            referenced_table: TableName::new(None, "team".to_owned()),
+            referenced_columns: vec!["id".to_owned()],
+            on_delete: None,
+            on_update: Some(ReferentialAction::SetNull),
+            span: NodeSpan::default(),
+        })
+        .add_unique_key(UniqueKey {
+            key_name: Some("uq_person_team".to_owned()),
+            database_name: Some("test_db".to_owned()),
+            columns: vec!["team_id".to_owned(), "email".to_owned()],
+            span: NodeSpan::default(),
+        })
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn create_table_column_constraints_1() {
+    let text = r#"
+        CREATE TABLE person
+        (
+            id INTEGER PRIMARY KEY AUTO_INCREMENT,
+            email varchar(100) NOT NULL UNIQUE COLLATE utf8mb4_bin,
+            team_id INTEGER CONSTRAINT fk_person_team REFERENCES team (id) ON DELETE SET NULL,
+            age INTEGER CHECK (age > 0)
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "person".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("id".to_owned())
+                .set_data_type(DataType::Int(None))
+                .set_primary_key(true)
+                .set_auto_increment(true)
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("email".to_owned())
+                .set_data_type(DataType::Varchar(Some(100)))
+                .set_not_null(true)
+                .set_unique(true)
+                .set_collation("utf8mb4_bin".to_owned())
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("team_id".to_owned())
+                .set_data_type(DataType::Int(None))
+                .set_reference(Reference {
+                    key_name: Some("fk_person_team".to_owned()),
+                    referenced_table: TableName::new(None, "team".to_owned()),
+                    referenced_columns: vec!["id".to_owned()],
+                    on_delete: Some(ReferentialAction::SetNull),
+                    on_update: None,
+                })
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("age".to_owned())
+                .set_data_type(DataType::Int(None))
+                .add_check(CheckConstraint {
+                    constraint_name: None,
+                    expression: BinaryOperatorExpression {
+                        operator: BinaryOperator::Gt,
+                        lhs: Box::new(ColumnName::new(None, "age".to_owned()).into()),
+                        rhs: Box::new(Expression::Integer(0)),
+                    }
+                    .into(),
+                    span: NodeSpan::default(),
+                })
+                .build(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn create_table_column_constraints_2() {
+    let text = r#"
+        CREATE TABLE item
+        (
+            id INTEGER GENERATED BY DEFAULT AS IDENTITY (START WITH 10 INCREMENT BY 5),
+            seq INTEGER GENERATED ALWAYS AS IDENTITY,
+            price INTEGER,
+            total INTEGER GENERATED ALWAYS AS (price * 2) STORED,
+            half INTEGER AS (price / 2)
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "item".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("id".to_owned())
+                .set_data_type(DataType::Int(None))
+                .set_identity(Identity {
+                    kind: IdentityKind::ByDefault,
+                    start: Some(10),
+                    increment: Some(5),
+                })
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("seq".to_owned())
+                .set_data_type(DataType::Int(None))
+                .set_identity(Identity {
+                    kind: IdentityKind::Always,
+                    start: None,
+                    increment: None,
+                })
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("price".to_owned())
+                .set_data_type(DataType::Int(None))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("total".to_owned())
+                .set_data_type(DataType::Int(None))
+                .set_generated(GeneratedColumn {
+                    expression: BinaryOperatorExpression {
+                        operator: BinaryOperator::Mul,
+                        lhs: Box::new(ColumnName::new(None, "price".to_owned()).into()),
+                        rhs: Box::new(Expression::Integer(2)),
+                    }
+                    .into(),
+                    stored: true,
+                })
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("half".to_owned())
+                .set_data_type(DataType::Int(None))
+                .set_generated(GeneratedColumn {
+                    expression: BinaryOperatorExpression {
+                        operator: BinaryOperator::Div,
+                        lhs: Box::new(ColumnName::new(None, "price".to_owned()).into()),
+                        rhs: Box::new(Expression::Integer(2)),
+                    }
+                    .into(),
+                    stored: false,
+                })
+                .build(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn create_table_multiple_primary_keys_error() {
+    let cases = [
+        (
+            "CREATE TABLE foo (a INT, b INT, PRIMARY KEY (a), PRIMARY KEY (b));",
+            "E1269",
+        ),
+        (
+            "CREATE TABLE foo (a INT PRIMARY KEY, b INT, PRIMARY KEY (b));",
+            "E1269",
+        ),
+        (
+            "CREATE TABLE foo (a INT, PRIMARY KEY (a), b INT PRIMARY KEY);",
+            "E1270",
+        ),
+        (
+            "CREATE TABLE foo (a INT PRIMARY KEY, b INT PRIMARY KEY);",
+            "E1270",
+        ),
+    ];
+
+    for (text, code) in cases {
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+
+        let error = parser.parse(ParserContext::default()).unwrap_err();
+
+        assert_eq!(error.code(), code, "{}", text);
+        assert!(error.span().is_some(), "{}", text);
+    }
+}
+
+#[test]
+pub fn create_table_column_constraints_3() {
+    let text = r#"
+        CREATE TABLE item
+        (
+            qty INT CONSTRAINT qty_min CHECK (qty > 0) CHECK (qty < 10)
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "item".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("qty".to_owned())
+                .set_data_type(DataType::Int(None))
+                .add_check(CheckConstraint {
+                    constraint_name: Some("qty_min".to_owned()),
+                    expression: BinaryOperatorExpression {
+                        operator: BinaryOperator::Gt,
+                        lhs: Box::new(ColumnName::new(None, "qty".to_owned()).into()),
+                        rhs: Box::new(Expression::Integer(0)),
+                    }
+                    .into(),
+                    span: NodeSpan::default(),
+                })
+                .add_check(CheckConstraint {
+                    constraint_name: None,
+                    expression: BinaryOperatorExpression {
+                        operator: BinaryOperator::Lt,
+                        lhs: Box::new(ColumnName::new(None, "qty".to_owned()).into()),
+                        rhs: Box::new(Expression::Integer(10)),
+                    }
+                    .into(),
+                    span: NodeSpan::default(),
+                })
+                .build(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn create_table_column_constraint_names() {
+    let text = r#"
+        CREATE TABLE person
+        (
+            id INT CONSTRAINT pk_person PRIMARY KEY,
+            email TEXT CONSTRAINT uq_email UNIQUE CONSTRAINT nn_email NOT NULL,
+            name TEXT CHARACTER SET utf8mb4 COLLATE utf8mb4_bin,
+            nickname TEXT CHARSET latin1
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "person".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("id".to_owned())
+                .set_data_type(DataType::Int(None))
+                .set_primary_key(true)
+                .set_primary_key_name("pk_person".to_owned())
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("email".to_owned())
+                .set_data_type(DataType::Text)
+                .set_unique(true)
+                .set_unique_name("uq_email".to_owned())
+                .set_not_null(true)
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("name".to_owned())
+                .set_data_type(DataType::Text)
+                .set_charset("utf8mb4".to_owned())
+                .set_collation("utf8mb4_bin".to_owned())
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("nickname".to_owned())
+                .set_data_type(DataType::Text)
+                .set_charset("latin1".to_owned())
+                .build(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn create_table_inline_index() {
+    let text = r#"
+        CREATE TABLE person
+        (
+            id INT,
+            name TEXT,
+            KEY idx_name (name),
+            INDEX (id DESC, name)
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_dialect(text.clone(), Arc::new(MySQLDialect {})).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "person".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("id".to_owned())
+                .set_data_type(DataType::Int(None))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("name".to_owned())
+                .set_data_type(DataType::Text)
+                .build(),
+        )
+        .add_index(Index {
+            index_name: "idx_name".to_owned(),
+            columns: vec![IndexColumn::new("name".to_owned(), None)],
+            is_unique: false,
+            method: None,
+        })
+        .add_index(Index {
+            index_name: "id".to_owned(),
+            columns: vec![
+                IndexColumn::new("id".to_owned(), Some(SortOrder::Desc)),
+                IndexColumn::new("name".to_owned(), None),
+            ],
+            is_unique: false,
+            method: None,
+        })
+        .build();
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+
+    // 테이블 안의 인덱스 정의를 지원하지 않는 방언
+    let mut parser = Parser::with_dialect(text, Arc::new(PostgreSQLDialect {})).unwrap();
+
+    assert!(parser.parse(ParserContext::default()).is_err());
+}
+
+#[test]
+pub fn create_table_column_charset_dialect() {
+    let text = "CREATE TABLE foo (a TEXT CHARSET latin1);".to_owned();
+
+    let mut parser = Parser::with_dialect(text.clone(), Arc::new(MySQLDialect {})).unwrap();
+    let statements = parser.parse(ParserContext::default()).unwrap();
+
+    assert_round_trip(&statements[0], Arc::new(MySQLDialect {}));
+
+    let mut parser = Parser::with_dialect(text, Arc::new(PostgreSQLDialect {})).unwrap();
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap_err().code(),
+        "E0046"
+    );
+}
+
+#[test]
+pub fn create_table_column_constraints_error() {
+    let cases = [
+        ("CREATE TABLE foo (a INT CONSTRAINT nn DEFAULT 1);", "E0082"),
+        ("CREATE TABLE foo (a INT CONSTRAINT nn", "E0081"),
+        ("CREATE TABLE foo (a TEXT CHARACTER utf8);", "E0085"),
+        ("CREATE TABLE foo (a TEXT CHARSET", "E0086"),
+        ("CREATE TABLE foo (a TEXT CHARSET 1);", "E0087"),
+        ("CREATE TABLE foo (a INT PRIMARY KEY PRIMARY KEY);", "E0083"),
+    ];
+
+    for (text, code) in cases {
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+
+        let error = parser.parse(ParserContext::default()).unwrap_err();
+
+        assert_eq!(error.code(), code, "{}", text);
+    }
+}
@@ -0,0 +1,432 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::ast::ddl::CreateTableQuery;
+use crate::ast::types::{Column, DataType, TableName};
+use crate::dialect::predule::{GenericDialect, MySQLDialect, PostgreSQLDialect};
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+use crate::renderer::predule::ToSql;
+use crate::renderer::test::round_trip::assert_round_trip;
+
+#[test]
+pub fn data_type_numeric() {
+    let text = r#"
+        CREATE TABLE foo
+        (
+            a BIGINT,
+            b DECIMAL(10, 2),
+            c NUMERIC(5),
+            d DOUBLE PRECISION,
+            e INT(11) UNSIGNED,
+            f smallint,
+            g serial
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "foo".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("a".to_owned())
+                .set_data_type(DataType::BigInt(None))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("b".to_owned())
+                .set_data_type(DataType::Decimal {
+                    precision: Some(10),
+                    scale: Some(2),
+                })
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("c".to_owned())
+                .set_data_type(DataType::Decimal {
+                    precision: Some(5),
+                    scale: None,
+                })
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("d".to_owned())
+                .set_data_type(DataType::Double)
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("e".to_owned())
+                .set_data_type(DataType::Unsigned(Box::new(DataType::Int(Some(11)))))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("f".to_owned())
+                .set_data_type(DataType::SmallInt(None))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("g".to_owned())
+                .set_data_type(DataType::Serial)
+                .build(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn data_type_string_and_time() {
+    let text = r#"
+        CREATE TABLE foo
+        (
+            a CHAR(3),
+            b CHARACTER VARYING(20),
+            c TEXT,
+            d TIMESTAMP(3) WITH TIME ZONE,
+            e timestamp without time zone,
+            f DATE,
+            g UUID,
+            h JSONB,
+            i BYTEA,
+            j ENUM('small', 'large'),
+            k public.mood
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "foo".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("a".to_owned())
+                .set_data_type(DataType::Char(Some(3)))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("b".to_owned())
+                .set_data_type(DataType::Varchar(Some(20)))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("c".to_owned())
+                .set_data_type(DataType::Text)
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("d".to_owned())
+                .set_data_type(DataType::Timestamp {
+                    precision: Some(3),
+                    with_time_zone: true,
+                })
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("e".to_owned())
+                .set_data_type(DataType::Timestamp {
+                    precision: None,
+                    with_time_zone: false,
+                })
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("f".to_owned())
+                .set_data_type(DataType::Date)
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("g".to_owned())
+                .set_data_type(DataType::Uuid)
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("h".to_owned())
+                .set_data_type(DataType::Jsonb)
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("i".to_owned())
+                .set_data_type(DataType::Bytea)
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("j".to_owned())
+                .set_data_type(DataType::Enum(vec!["small".to_owned(), "large".to_owned()]))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("k".to_owned())
+                .set_data_type(DataType::Custom("public.mood".to_owned()))
+                .build(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn data_type_to_string() {
+    assert_eq!(
+        String::from(DataType::Decimal {
+            precision: Some(10),
+            scale: Some(2)
+        }),
+        "decimal(10, 2)"
+    );
+    assert_eq!(
+        String::from(DataType::Timestamp {
+            precision: None,
+            with_time_zone: true
+        }),
+        "timestamp with time zone"
+    );
+    assert_eq!(
+        String::from(DataType::Unsigned(Box::new(DataType::BigInt(None)))),
+        "bigint unsigned"
+    );
+    assert_eq!(String::from(DataType::TinyInt(Some(1))), "tinyint(1)");
+    assert_eq!(String::from(DataType::Float(Some(53))), "float(53)");
+    assert_eq!(String::from(DataType::Varchar(None)), "varchar");
+}
+
+#[test]
+pub fn data_type_optional_length() {
+    let text = r#"
+        CREATE TABLE foo
+        (
+            a TINYINT(1),
+            b INT(11),
+            c FLOAT(53),
+            d FLOAT,
+            e VARCHAR,
+            f CHARACTER VARYING
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "foo".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("a".to_owned())
+                .set_data_type(DataType::TinyInt(Some(1)))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("b".to_owned())
+                .set_data_type(DataType::Int(Some(11)))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("c".to_owned())
+                .set_data_type(DataType::Float(Some(53)))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("d".to_owned())
+                .set_data_type(DataType::Float(None))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("e".to_owned())
+                .set_data_type(DataType::Varchar(None))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("f".to_owned())
+                .set_data_type(DataType::Varchar(None))
+                .build(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn data_type_array() {
+    let text = r#"
+        CREATE TABLE foo
+        (
+            tags text[],
+            matrix INT[][]
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_dialect(text, Arc::new(PostgreSQLDialect {})).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "foo".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("tags".to_owned())
+                .set_data_type(DataType::Array(Box::new(DataType::Text)))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("matrix".to_owned())
+                .set_data_type(DataType::Array(Box::new(DataType::Array(Box::new(
+                    DataType::Int(None),
+                )))))
+                .build(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(PostgreSQLDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected.clone()],
+    );
+
+    // 배열 타입을 지원하지 않는 방언은 json 타입으로 출력
+    assert_eq!(
+        expected.to_sql(&MySQLDialect {}),
+        "CREATE TABLE foo (\n    tags json,\n    matrix json\n);"
+    );
+
+    let errors = [
+        ("CREATE TABLE foo (tags text[);", "E0089"),
+        ("CREATE TABLE foo (tags text[", "E0088"),
+    ];
+
+    for (text, code) in errors {
+        let mut parser =
+            Parser::with_dialect(text.to_owned(), Arc::new(PostgreSQLDialect {})).unwrap();
+
+        assert_eq!(
+            parser.parse(ParserContext::default()).unwrap_err().code(),
+            code,
+            "{}",
+            text
+        );
+    }
+}
+
+#[test]
+pub fn data_type_modifiers() {
+    let text = r#"
+        CREATE TABLE foo
+        (
+            a YEAR(4),
+            b INT(5) UNSIGNED ZEROFILL,
+            c INT SIGNED,
+            d DECIMAL(10, 2) ZEROFILL UNSIGNED
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_dialect(text, Arc::new(MySQLDialect {})).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "foo".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("a".to_owned())
+                .set_data_type(DataType::Year(Some(4)))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("b".to_owned())
+                .set_data_type(DataType::Zerofill(Box::new(DataType::Unsigned(Box::new(
+                    DataType::Int(Some(5)),
+                )))))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("c".to_owned())
+                .set_data_type(DataType::Signed(Box::new(DataType::Int(None))))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("d".to_owned())
+                .set_data_type(DataType::Unsigned(Box::new(DataType::Zerofill(Box::new(
+                    DataType::Decimal {
+                        precision: Some(10),
+                        scale: Some(2),
+                    },
+                )))))
+                .build(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(MySQLDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected.clone()],
+    );
+
+    // 수식어를 지원하지 않는 방언은 생략
+    assert_eq!(
+        expected.to_sql(&PostgreSQLDialect {}),
+        "CREATE TABLE foo (\n    a smallint,\n    b integer,\n    c integer,\n    d decimal(10, 2)\n);"
+    );
+
+    let errors = [
+        ("CREATE TABLE foo (a INT UNSIGNED UNSIGNED);", "E0090"),
+        (
+            "CREATE TABLE foo (a INT ZEROFILL UNSIGNED ZEROFILL);",
+            "E0090",
+        ),
+        ("CREATE TABLE foo (a INT SIGNED UNSIGNED);", "E0091"),
+        ("CREATE TABLE foo (a INT UNSIGNED SIGNED);", "E0091"),
+    ];
+
+    for (text, code) in errors {
+        let mut parser = Parser::with_dialect(text.to_owned(), Arc::new(MySQLDialect {})).unwrap();
+
+        assert_eq!(
+            parser.parse(ParserContext::default()).unwrap_err().code(),
+            code,
+            "{}",
+            text
+        );
+    }
+}
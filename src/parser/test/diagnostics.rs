@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+use crate::ast::ddl::{CreateTableQuery, DropTableQuery};
+use crate::ast::types::{Column, DataType, TableName};
+use crate::errors::predule::Error;
+use crate::lexer::predule::{Location, Token};
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+
+const TEXT: &str = r#"CREATE TABLE foo (id INTEGER BOGUS, name TEXT);
+CREATE TABLE bar (id INTEGER);
+DROP TABLE;
+DROP TABLE bar;"#;
+
+#[test]
+pub fn diagnostics_recovery() {
+    let mut parser = Parser::new(TEXT.to_owned()).unwrap();
+
+    let result = parser.parse_with_diagnostics(ParserContext::default());
+
+    assert_eq!(
+        result.statements,
+        vec![
+            CreateTableQuery::builder()
+                .set_table(TableName::new(None, "bar".to_owned()))
+                .add_column(
+                    Column::builder()
+                        .set_name("id".to_owned())
+                        .set_data_type(DataType::Int(None))
+                        .build(),
+                )
+                .build(),
+            DropTableQuery::builder()
+                .add_table(TableName::new(None, "bar".to_owned()))
+                .build(),
+        ]
+    );
+
+    assert!(result.has_errors());
+    assert_eq!(result.diagnostics.len(), 2);
+    assert!(result.diagnostics[0].code() == "E0046");
+    assert_eq!(
+        result.diagnostics[0].span().unwrap().start,
+        Location::new(29, 1, 30)
+    );
+    assert_eq!(result.diagnostics[1].span().unwrap().start.line, 3);
+}
+
+#[test]
+pub fn diagnostics_strict() {
+    let mut parser = Parser::new(TEXT.to_owned()).unwrap();
+
+    let result = parser.parse_with_diagnostics(ParserContext::default().set_strict(true));
+
+    assert!(result.statements.is_empty());
+    assert_eq!(result.diagnostics.len(), 1);
+}
+
+#[test]
+pub fn diagnostics_parse_error() {
+    let mut parser = Parser::new(TEXT.to_owned()).unwrap();
+
+    let error = parser.parse(ParserContext::default()).unwrap_err();
+
+    assert!(error.to_string().contains("E0046"));
+    assert!(error.to_string().contains("line 1, column 30"));
+    assert!(error.to_string().contains("'BOGUS'"));
+}
+
+#[test]
+pub fn diagnostics_expected_token() {
+    let text = r#"CREATE INDEX idx_name person (name);"#.to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let error = parser.parse(ParserContext::default()).unwrap_err();
+    let kind = error.kind();
+
+    assert!(matches!(error, Error::Parsing(_)));
+    assert_eq!(kind.expected, vec!["ON".to_owned()]);
+    assert_eq!(kind.found, Some(Token::Identifier("person".to_owned())));
+    assert_eq!(kind.span.unwrap().start, Location::new(22, 1, 23));
+}
+
+#[test]
+pub fn diagnostics_unsupported_statement() {
+    let text = r#"SELECT 1;
+FOO BAR;
+CRATE TABLE x (a INTEGER);
+DROP TABLE bar;"#;
+
+    let mut parser = Parser::new(text.to_owned()).unwrap();
+
+    let result = parser.parse_with_diagnostics(ParserContext::default());
+
+    assert_eq!(
+        result.statements,
+        vec![DropTableQuery::builder()
+            .add_table(TableName::new(None, "bar".to_owned()))
+            .build()]
+    );
+
+    assert_eq!(
+        result
+            .diagnostics
+            .iter()
+            .map(|error| (error.code(), error.span().unwrap().start.line))
+            .collect::<Vec<_>>(),
+        vec![("E1001", 1), ("E1001", 2), ("E1001", 3)]
+    );
+}
+
+#[test]
+pub fn diagnostics_lexing_recovery() {
+    let text = r#"DROP TABLE foo;
+CREATE TABLE bar (id INTEGER # comment);
+CREATE TABLE baz (id INTEGER BOGUS);
+DROP TABLE bar;"#;
+
+    let mut parser = Parser::new(text.to_owned()).unwrap();
+
+    let result = parser.parse_with_diagnostics(ParserContext::default());
+
+    assert_eq!(
+        result.statements,
+        vec![
+            DropTableQuery::builder()
+                .add_table(TableName::new(None, "foo".to_owned()))
+                .build(),
+            DropTableQuery::builder()
+                .add_table(TableName::new(None, "bar".to_owned()))
+                .build(),
+        ]
+    );
+
+    // 어휘 분석 오류와 구문 분석 오류를 원본 위치 순서대로 모읍니다.
+    assert_eq!(
+        result
+            .diagnostics
+            .iter()
+            .map(|error| (error.code(), error.span().unwrap().start.line))
+            .collect::<Vec<_>>(),
+        vec![("E0304", 2), ("E0046", 3)]
+    );
+    assert!(matches!(result.diagnostics[0], Error::Lexing(_)));
+
+    // strict 모드에서는 첫 어휘 분석 오류에서 중단합니다.
+    let mut parser = Parser::new(text.to_owned()).unwrap();
+
+    let result = parser.parse_with_diagnostics(ParserContext::default().set_strict(true));
+
+    assert_eq!(result.statements.len(), 1);
+    assert_eq!(result.diagnostics.len(), 1);
+    assert_eq!(result.diagnostics[0].code(), "E0304");
+}
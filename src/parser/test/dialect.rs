@@ -0,0 +1,170 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::ast::ddl::CreateTableQuery;
+use crate::ast::predule::{Expression, SQLValueFunction};
+use crate::ast::types::{Column, DataType, TableName, TableOption, TableOptions};
+use crate::dialect::predule::{MySQLDialect, PostgreSQLDialect, SQLiteDialect};
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+use crate::renderer::test::round_trip::assert_round_trip;
+
+#[test]
+pub fn dialect_mysql() {
+    let text = r#"
+        CREATE TABLE `user`
+        (
+            `id` SERIAL,
+            `name` varchar(100) DEFAULT "anonymous",
+            `updated_at` TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+            `seq` INT AUTO_INCREMENT
+        ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT='users';
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_dialect(text, Arc::new(MySQLDialect {})).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "user".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("id".to_owned())
+                .set_data_type(DataType::Unsigned(Box::new(DataType::BigInt(None))))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("name".to_owned())
+                .set_data_type(DataType::Varchar(Some(100)))
+                .set_default(Expression::String("anonymous".to_owned()))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("updated_at".to_owned())
+                .set_data_type(DataType::Timestamp {
+                    precision: None,
+                    with_time_zone: false,
+                })
+                .set_default(SQLValueFunction::CurrentTimestamp.into())
+                .set_on_update(SQLValueFunction::CurrentTimestamp.into())
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("seq".to_owned())
+                .set_data_type(DataType::Int(None))
+                .set_auto_increment(true)
+                .build(),
+        )
+        .set_table_option(TableOptions {
+            options: vec![
+                TableOption::new("ENGINE", Some("InnoDB".to_owned())),
+                TableOption::new("CHARSET", Some("utf8mb4".to_owned())),
+                TableOption::new("COMMENT", Some("users".to_owned())),
+            ],
+        })
+        .build();
+
+    assert_round_trip(&expected, Arc::new(MySQLDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
+
+#[test]
+pub fn dialect_postgresql() {
+    let text = r#"
+        CREATE TABLE Public.Person
+        (
+            ID SERIAL,
+            "Name" TEXT
+        );
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_dialect(text, Arc::new(PostgreSQLDialect {})).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(
+            Some("public".to_owned()),
+            "person".to_owned(),
+        ))
+        .add_column(
+            Column::builder()
+                .set_name("id".to_owned())
+                .set_data_type(DataType::Serial)
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("Name".to_owned())
+                .set_data_type(DataType::Text)
+                .build(),
+        )
+        .build();
+
+    assert_round_trip(&expected, Arc::new(PostgreSQLDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+
+    // PostgreSQL은 `를 식별자로 허용하지 않음
+    assert!(Parser::with_dialect(
+        "CREATE TABLE `foo` (id INT);".to_owned(),
+        Arc::new(PostgreSQLDialect {})
+    )
+    .unwrap()
+    .parse(ParserContext::default())
+    .is_err());
+}
+
+#[test]
+pub fn dialect_sqlite() {
+    let text = r#"
+        CREATE TABLE foo
+        (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            "value" TEXT
+        ) WITHOUT ROWID, STRICT;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_dialect(text, Arc::new(SQLiteDialect {})).unwrap();
+
+    let expected = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "foo".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("id".to_owned())
+                .set_data_type(DataType::Int(None))
+                .set_primary_key(true)
+                .set_auto_increment(true)
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("value".to_owned())
+                .set_data_type(DataType::Text)
+                .build(),
+        )
+        .set_table_option(TableOptions {
+            options: vec![
+                TableOption::new("WITHOUT ROWID", None),
+                TableOption::new("STRICT", None),
+            ],
+        })
+        .build();
+
+    assert_round_trip(&expected, Arc::new(SQLiteDialect {}));
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![expected],
+    );
+}
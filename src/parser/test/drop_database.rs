@@ -1,7 +1,13 @@
 #![cfg(test)]
+
+use std::sync::Arc;
+
 use crate::ast::ddl::DropDatabaseQuery;
+use crate::ast::types::DropBehavior;
+use crate::dialect::predule::GenericDialect;
 use crate::parser::context::ParserContext;
 use crate::parser::predule::Parser;
+use crate::renderer::test::round_trip::assert_round_trip;
 
 #[test]
 pub fn drop_database() {
@@ -13,12 +19,57 @@ pub fn drop_database() {
     let mut parser = Parser::new(text).unwrap();
 
     let expected = DropDatabaseQuery::builder()
-        .set_name("test_db".to_owned())
+        .add_name("test_db".to_owned())
         .set_if_exists(true)
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
     );
 }
+
+#[test]
+pub fn drop_database_multiple() {
+    let text = r#"
+        DROP DATABASE test_db, other_db CASCADE;
+        DROP DATABASE test_db RESTRICT;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = vec![
+        DropDatabaseQuery::builder()
+            .add_name("test_db".to_owned())
+            .add_name("other_db".to_owned())
+            .set_drop_behavior(DropBehavior::Cascade)
+            .build(),
+        DropDatabaseQuery::builder()
+            .add_name("test_db".to_owned())
+            .set_drop_behavior(DropBehavior::Restrict)
+            .build(),
+    ];
+
+    for statement in &expected {
+        assert_round_trip(statement, Arc::new(GenericDialect {}));
+    }
+
+    assert_eq!(parser.parse(ParserContext::default()).unwrap(), expected);
+
+    let mut parser = Parser::new("DROP DATABASE test_db,".to_owned()).unwrap();
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap_err().code(),
+        "E0115"
+    );
+
+    let mut parser = Parser::new("DROP DATABASE test_db, 'other_db';".to_owned()).unwrap();
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap_err().code(),
+        "E0112"
+    );
+}
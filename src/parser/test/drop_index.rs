@@ -0,0 +1,56 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::ast::ddl::DropIndexQuery;
+use crate::ast::types::TableName;
+use crate::dialect::predule::{GenericDialect, MySQLDialect};
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+use crate::renderer::test::round_trip::assert_round_trip;
+
+#[test]
+pub fn drop_index() {
+    let text = r#"
+        DROP INDEX IF EXISTS foo_db.idx_name;
+        DROP INDEX idx_name ON foo;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::with_dialect(text, Arc::new(MySQLDialect {})).unwrap();
+
+    let expected = vec![
+        DropIndexQuery::builder()
+            .set_if_exists(true)
+            .set_database_name(Some("foo_db".to_owned()))
+            .set_index_name("idx_name".to_owned())
+            .build(),
+        DropIndexQuery::builder()
+            .set_index_name("idx_name".to_owned())
+            .set_table(TableName::new(None, "foo".to_owned()))
+            .build(),
+    ];
+
+    for statement in &expected {
+        assert_round_trip(statement, Arc::new(GenericDialect {}));
+    }
+
+    assert_eq!(parser.parse(ParserContext::default()).unwrap(), expected);
+}
+
+#[test]
+pub fn drop_index_error() {
+    let cases = [
+        ("DROP INDEX", "E1314"),
+        ("DROP INDEX idx_name foo", "E1315"),
+        ("DROP INDEX idx_name ON foo bar", "E1315"),
+    ];
+
+    for (text, code) in cases {
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+
+        let error = parser.parse(ParserContext::default()).unwrap_err();
+
+        assert_eq!(error.code(), code, "{}", text);
+    }
+}
@@ -1,9 +1,13 @@
 #![cfg(test)]
 
+use std::sync::Arc;
+
 use crate::ast::ddl::DropTableQuery;
-use crate::ast::types::TableName;
+use crate::ast::types::{DropBehavior, TableName};
+use crate::dialect::predule::GenericDialect;
 use crate::parser::context::ParserContext;
 use crate::parser::predule::Parser;
+use crate::renderer::test::round_trip::assert_round_trip;
 
 #[test]
 pub fn drop_table() {
@@ -15,12 +19,64 @@ pub fn drop_table() {
     let mut parser = Parser::new(text).unwrap();
 
     let expected = DropTableQuery::builder()
-        .set_table(TableName::new(Some("foo_db".to_owned()), "foo".to_owned()))
+        .add_table(TableName::new(Some("foo_db".to_owned()), "foo".to_owned()))
         .set_if_exists(true)
         .build();
 
+    assert_round_trip(&expected, Arc::new(GenericDialect {}));
+
     assert_eq!(
         parser.parse(ParserContext::default()).unwrap(),
         vec![expected],
     );
 }
+
+#[test]
+pub fn drop_table_multiple() {
+    let text = r#"
+        DROP TEMPORARY TABLE IF EXISTS foo, foo_db.bar, baz CASCADE;
+        DROP TABLE foo RESTRICT;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let expected = vec![
+        DropTableQuery::builder()
+            .add_table(TableName::new(None, "foo".to_owned()))
+            .add_table(TableName::new(Some("foo_db".to_owned()), "bar".to_owned()))
+            .add_table(TableName::new(None, "baz".to_owned()))
+            .set_if_exists(true)
+            .set_temporary(true)
+            .set_drop_behavior(DropBehavior::Cascade)
+            .build(),
+        DropTableQuery::builder()
+            .add_table(TableName::new(None, "foo".to_owned()))
+            .set_drop_behavior(DropBehavior::Restrict)
+            .build(),
+    ];
+
+    for statement in &expected {
+        assert_round_trip(statement, Arc::new(GenericDialect {}));
+    }
+
+    assert_eq!(parser.parse(ParserContext::default()).unwrap(), expected);
+}
+
+#[test]
+pub fn drop_table_error() {
+    let cases = [
+        ("DROP VIEW foo;", "E1106"),
+        ("DROP TEMPORARY foo;", "E1110"),
+        ("DROP TABLE foo bar;", "E1204"),
+        ("DROP TABLE foo CASCADE bar;", "E1204"),
+    ];
+
+    for (text, code) in cases {
+        let mut parser = Parser::new(text.to_owned()).unwrap();
+
+        let error = parser.parse(ParserContext::default()).unwrap_err();
+
+        assert_eq!(error.code(), code, "{}", text);
+    }
+}
@@ -0,0 +1,289 @@
+#![cfg(test)]
+
+use crate::ast::predule::{
+    AggregateFunction, BetweenExpression, BinaryOperator, BinaryOperatorExpression, CallExpression,
+    CaseExpression, CastExpression, ColumnName, DataType, Expression, InListExpression,
+    ParenthesesExpression, SQLValueFunction, UnaryOperator, UnaryOperatorExpression,
+    UserDefinedFunction, WhenClause,
+};
+use crate::dialect::predule::GenericDialect;
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+use crate::renderer::predule::ToSql;
+
+fn parse(text: &str) -> Expression {
+    let mut parser = Parser::new(text.to_owned()).unwrap();
+
+    let expression = parser.parse_expression(ParserContext::default()).unwrap();
+
+    // 생성한 SQL을 다시 파싱해도 같은 표현식이어야 합니다.
+    let mut parser = Parser::new(expression.to_sql(&GenericDialect {})).unwrap();
+    assert_eq!(
+        parser.parse_expression(ParserContext::default()).unwrap(),
+        expression
+    );
+
+    expression
+}
+
+fn column(name: &str) -> Box<Expression> {
+    Box::new(ColumnName::new(None, name.to_owned()).into())
+}
+
+#[test]
+pub fn expression_literal() {
+    assert_eq!(parse("'a b'"), Expression::String("a b".to_owned()));
+    assert_eq!(parse("-1"), Expression::Integer(-1));
+    assert_eq!(parse("NULL"), Expression::Null);
+    assert_eq!(
+        parse("CURRENT_TIMESTAMP"),
+        SQLValueFunction::CurrentTimestamp.into()
+    );
+}
+
+#[test]
+pub fn expression_function_call() {
+    assert_eq!(
+        parse("now()"),
+        CallExpression {
+            function: UserDefinedFunction {
+                database_name: None,
+                function_name: "now".to_owned(),
+            }
+            .into(),
+            arguments: vec![],
+        }
+        .into()
+    );
+
+    assert_eq!(
+        parse("max(a, 1)"),
+        CallExpression {
+            function: AggregateFunction::Max.into(),
+            arguments: vec![*column("a"), Expression::Integer(1)],
+        }
+        .into()
+    );
+}
+
+#[test]
+pub fn expression_precedence() {
+    // a + b * 2 > 0 AND NOT c
+    assert_eq!(
+        parse("a + b * 2 > 0 AND NOT c"),
+        BinaryOperatorExpression {
+            operator: BinaryOperator::And,
+            lhs: Box::new(
+                BinaryOperatorExpression {
+                    operator: BinaryOperator::Gt,
+                    lhs: Box::new(
+                        BinaryOperatorExpression {
+                            operator: BinaryOperator::Add,
+                            lhs: column("a"),
+                            rhs: Box::new(
+                                BinaryOperatorExpression {
+                                    operator: BinaryOperator::Mul,
+                                    lhs: column("b"),
+                                    rhs: Box::new(Expression::Integer(2)),
+                                }
+                                .into()
+                            ),
+                        }
+                        .into()
+                    ),
+                    rhs: Box::new(Expression::Integer(0)),
+                }
+                .into()
+            ),
+            rhs: Box::new(
+                UnaryOperatorExpression {
+                    operator: UnaryOperator::Not,
+                    operand: column("c"),
+                }
+                .into()
+            ),
+        }
+        .into()
+    );
+
+    assert_eq!(
+        parse("(a - 1) * 2"),
+        BinaryOperatorExpression {
+            operator: BinaryOperator::Mul,
+            lhs: Box::new(
+                ParenthesesExpression {
+                    expression: Box::new(
+                        BinaryOperatorExpression {
+                            operator: BinaryOperator::Sub,
+                            lhs: column("a"),
+                            rhs: Box::new(Expression::Integer(1)),
+                        }
+                        .into()
+                    ),
+                }
+                .into()
+            ),
+            rhs: Box::new(Expression::Integer(2)),
+        }
+        .into()
+    );
+}
+
+#[test]
+pub fn expression_cast_and_case() {
+    assert_eq!(
+        parse("CAST(a AS BIGINT)"),
+        CastExpression {
+            expression: column("a"),
+            data_type: DataType::BigInt(None),
+        }
+        .into()
+    );
+
+    assert_eq!(
+        parse("CASE WHEN a IS NULL THEN 0 ELSE a END"),
+        CaseExpression {
+            operand: None,
+            when_clauses: vec![WhenClause {
+                condition: BinaryOperatorExpression {
+                    operator: BinaryOperator::Is,
+                    lhs: column("a"),
+                    rhs: Box::new(Expression::Null),
+                }
+                .into(),
+                result: Expression::Integer(0),
+            }],
+            else_result: Some(column("a")),
+        }
+        .into()
+    );
+}
+
+#[test]
+pub fn expression_in_and_between() {
+    assert_eq!(
+        parse("status NOT IN ('a', 'b')"),
+        InListExpression {
+            expression: column("status"),
+            list: vec![
+                Expression::String("a".to_owned()),
+                Expression::String("b".to_owned())
+            ],
+            negated: true,
+        }
+        .into()
+    );
+
+    assert_eq!(
+        parse("a BETWEEN 1 AND 10 OR b"),
+        BinaryOperatorExpression {
+            operator: BinaryOperator::Or,
+            lhs: Box::new(
+                BetweenExpression {
+                    expression: column("a"),
+                    low: Box::new(Expression::Integer(1)),
+                    high: Box::new(Expression::Integer(10)),
+                    negated: false,
+                }
+                .into()
+            ),
+            rhs: column("b"),
+        }
+        .into()
+    );
+}
+
+#[test]
+pub fn expression_multi_char_operators() {
+    assert_eq!(
+        parse("a || b <> c"),
+        BinaryOperatorExpression {
+            operator: BinaryOperator::Neq,
+            lhs: Box::new(
+                BinaryOperatorExpression {
+                    operator: BinaryOperator::Concat,
+                    lhs: column("a"),
+                    rhs: column("b"),
+                }
+                .into()
+            ),
+            rhs: column("c"),
+        }
+        .into()
+    );
+
+    assert_eq!(
+        parse("a % 2 >= ~b"),
+        BinaryOperatorExpression {
+            operator: BinaryOperator::Gte,
+            lhs: Box::new(
+                BinaryOperatorExpression {
+                    operator: BinaryOperator::Mod,
+                    lhs: column("a"),
+                    rhs: Box::new(Expression::Integer(2)),
+                }
+                .into()
+            ),
+            rhs: Box::new(
+                UnaryOperatorExpression {
+                    operator: UnaryOperator::BitNot,
+                    operand: column("b"),
+                }
+                .into()
+            ),
+        }
+        .into()
+    );
+
+    assert_eq!(
+        parse("a | b & c"),
+        BinaryOperatorExpression {
+            operator: BinaryOperator::BitOr,
+            lhs: column("a"),
+            rhs: Box::new(
+                BinaryOperatorExpression {
+                    operator: BinaryOperator::BitAnd,
+                    lhs: column("b"),
+                    rhs: column("c"),
+                }
+                .into()
+            ),
+        }
+        .into()
+    );
+}
+
+#[test]
+pub fn expression_double_colon_cast() {
+    assert_eq!(
+        parse("a::text || 'x'"),
+        BinaryOperatorExpression {
+            operator: BinaryOperator::Concat,
+            lhs: Box::new(
+                CastExpression {
+                    expression: column("a"),
+                    data_type: DataType::Text,
+                }
+                .into()
+            ),
+            rhs: Box::new(Expression::String("x".to_owned())),
+        }
+        .into()
+    );
+
+    assert_eq!(
+        parse("'1e3'::float + .5"),
+        BinaryOperatorExpression {
+            operator: BinaryOperator::Add,
+            lhs: Box::new(
+                CastExpression {
+                    expression: Box::new(Expression::String("1e3".to_owned())),
+                    data_type: DataType::Float(None),
+                }
+                .into()
+            ),
+            rhs: Box::new(Expression::Float(0.5)),
+        }
+        .into()
+    );
+}
@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+use crate::ast::predule::SQLStatement;
+use crate::parse_to_json;
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+
+#[test]
+pub fn json_shape() {
+    let json = parse_to_json("DROP TABLE foo_db.foo;").unwrap();
+
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(value[0]["type"], "DDL");
+    assert_eq!(value[0]["value"]["type"], "DropTableQuery");
+    assert_eq!(
+        value[0]["value"]["value"]["tables"][0],
+        serde_json::json!({ "database_name": "foo_db", "table_name": "foo" })
+    );
+    assert_eq!(value[0]["value"]["value"]["span"]["start"]["column"], 1);
+}
+
+#[test]
+pub fn json_round_trip() {
+    let text = r#"
+        CREATE TABLE IF NOT EXISTS foo (
+            id INT UNSIGNED NOT NULL AUTO_INCREMENT PRIMARY KEY,
+            name VARCHAR(100) DEFAULT 'none' CHECK (name > 'a'),
+            score DECIMAL(10, 2) DEFAULT CAST(0 AS DECIMAL(10, 2)),
+            writer_id INTEGER REFERENCES person (id) ON DELETE CASCADE,
+            CONSTRAINT uk_name UNIQUE (name)
+        ) ENGINE=InnoDB;
+        ALTER TABLE foo ALTER COLUMN name SET DEFAULT COALESCE(1, 2);
+        CREATE UNIQUE INDEX idx_name ON foo (name DESC);
+        ALTER DATABASE foo_db RENAME TO bar_db;
+    "#
+    .to_owned();
+
+    let mut parser = Parser::new(text.clone()).unwrap();
+    let statements = parser.parse(ParserContext::default()).unwrap();
+
+    let json = Parser::new(text)
+        .unwrap()
+        .parse_to_json(ParserContext::default())
+        .unwrap();
+
+    let decoded: Vec<SQLStatement> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(decoded, statements);
+}
@@ -5,3 +5,18 @@ pub(crate) mod drop_database;
 pub(crate) mod alter_table;
 pub(crate) mod create_table;
 pub(crate) mod drop_table;
+
+pub(crate) mod create_index;
+pub(crate) mod drop_index;
+
+pub(crate) mod comment_on;
+
+pub(crate) mod data_type;
+pub(crate) mod expression;
+
+pub(crate) mod dialect;
+pub(crate) mod json;
+
+pub(crate) mod comment;
+pub(crate) mod diagnostics;
+pub(crate) mod span;
@@ -0,0 +1,56 @@
+#![cfg(test)]
+
+use crate::ast::predule::{DDLStatement, SQLStatement};
+use crate::errors::predule::Error;
+use crate::lexer::predule::Location;
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+
+#[test]
+pub fn span_statement() {
+    let text = r#"CREATE TABLE person
+(
+    id INTEGER PRIMARY KEY,
+    age INTEGER CHECK (age > 0),
+    CONSTRAINT uk_age UNIQUE (age)
+);
+DROP TABLE person;"#
+        .to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let statements = parser.parse(ParserContext::default()).unwrap();
+
+    assert_eq!(statements[0].span().start, Location::new(0, 1, 1));
+    assert_eq!(statements[0].span().end, Location::new(120, 6, 3));
+    assert_eq!(statements[1].span().start, Location::new(121, 7, 1));
+    assert_eq!(statements[1].span().end, Location::new(139, 7, 19));
+
+    if let SQLStatement::DDL(DDLStatement::CreateTableQuery(query)) = &statements[0] {
+        assert_eq!(query.columns[0].span.start, Location::new(26, 3, 5));
+        assert_eq!(query.columns[0].span.end, Location::new(48, 3, 27));
+        assert_eq!(query.columns[1].span.start, Location::new(54, 4, 5));
+        assert_eq!(query.columns[1].span.end, Location::new(81, 4, 32));
+
+        let check = &query.columns[1].checks[0];
+        assert_eq!(check.span.start, Location::new(66, 4, 17));
+
+        assert_eq!(query.unique_keys[0].span.start, Location::new(87, 5, 5));
+        assert_eq!(query.unique_keys[0].span.end, Location::new(117, 5, 35));
+    } else {
+        panic!("expected create table query");
+    }
+}
+
+#[test]
+pub fn span_parsing_error() {
+    let text = "CAST(1 AS\n  INTEGER, 2)".to_owned();
+
+    let mut parser = Parser::new(text).unwrap();
+
+    let error = parser
+        .parse_expression(ParserContext::default())
+        .unwrap_err();
+    assert!(matches!(error, Error::Parsing(_)));
+    assert_eq!(error.span().unwrap().start, Location::new(19, 2, 10));
+}
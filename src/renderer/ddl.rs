@@ -0,0 +1,536 @@
+use crate::ast::predule::{
+    AlterColumnAction, AlterDatabaseAction, AlterDatabaseQuery, AlterTableAction, AlterTableQuery,
+    Column, ColumnPosition, CreateDatabaseQuery, CreateIndexQuery, CreateTableQuery, DDLStatement,
+    DropBehavior, DropDatabaseQuery, DropIndexQuery, DropTableQuery, IdentityKind, SQLStatement,
+    TableName,
+};
+use crate::dialect::predule::Dialect;
+use crate::renderer::predule::{quote_identifier, quote_string, ToSql};
+
+// 컬럼, 제약조건 정의 들여쓰기
+const INDENT: &str = "    ";
+
+impl ToSql for SQLStatement {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        match self {
+            SQLStatement::DDL(statement) => statement.to_sql(dialect),
+        }
+    }
+}
+
+impl ToSql for DDLStatement {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        match self {
+            DDLStatement::CreateDatabaseQuery(query) => query.to_sql(dialect),
+            DDLStatement::AlterDatabase(query) => query.to_sql(dialect),
+            DDLStatement::DropDatabaseQuery(query) => query.to_sql(dialect),
+            DDLStatement::CreateTableQuery(query) => query.to_sql(dialect),
+            DDLStatement::AlterTableQuery(query) => query.to_sql(dialect),
+            DDLStatement::DropTableQuery(query) => query.to_sql(dialect),
+            DDLStatement::CreateIndexQuery(query) => query.to_sql(dialect),
+            DDLStatement::DropIndexQuery(query) => query.to_sql(dialect),
+        }
+    }
+}
+
+// CREATE DATABASE [IF NOT EXISTS] database_name;
+impl ToSql for CreateDatabaseQuery {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        format!(
+            "CREATE DATABASE {}{};",
+            if self.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            quote_identifier(self.database_name.as_deref().unwrap_or_default(), dialect)
+        )
+    }
+}
+
+// ALTER DATABASE database_name [RENAME TO new_database_name];
+impl ToSql for AlterDatabaseQuery {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        let mut sql = format!(
+            "ALTER DATABASE {}",
+            quote_identifier(self.database_name.as_deref().unwrap_or_default(), dialect)
+        );
+
+        if let Some(AlterDatabaseAction::RenameTo(rename_to)) = &self.action {
+            sql.push_str(&format!(
+                " RENAME TO {}",
+                quote_identifier(&rename_to.name, dialect)
+            ));
+        }
+
+        sql.push(';');
+        sql
+    }
+}
+
+// DROP DATABASE [IF EXISTS] database_name [, ...] [CASCADE | RESTRICT];
+impl ToSql for DropDatabaseQuery {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        format!(
+            "DROP DATABASE {}{}{};",
+            if self.if_exists { "IF EXISTS " } else { "" },
+            self.database_names
+                .iter()
+                .map(|name| quote_identifier(name, dialect))
+                .collect::<Vec<_>>()
+                .join(", "),
+            drop_behavior(self.drop_behavior, dialect)
+        )
+    }
+}
+
+// CREATE TABLE [IF NOT EXISTS] table_name (
+//     column_definition, ...
+//     table_constraint, ...
+// ) [table_options];
+impl ToSql for CreateTableQuery {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        let mut definitions: Vec<String> = self
+            .columns
+            .iter()
+            .map(|column| column.to_sql(dialect))
+            .collect();
+
+        if let Some(primary_key) = &self.primary_key {
+            definitions.push(primary_key.to_sql(dialect));
+        }
+
+        definitions.extend(self.unique_keys.iter().map(|key| key.to_sql(dialect)));
+        definitions.extend(self.foreign_keys.iter().map(|key| key.to_sql(dialect)));
+        definitions.extend(
+            self.check_constraints
+                .iter()
+                .map(|check| check.to_sql(dialect)),
+        );
+
+        if dialect.supports_inline_index() {
+            definitions.extend(self.indexes.iter().map(|index| index.to_sql(dialect)));
+        }
+
+        let mut sql = format!(
+            "CREATE TABLE {}{} (\n{}\n)",
+            if self.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            self.table
+                .as_ref()
+                .map(|table| table.to_sql(dialect))
+                .unwrap_or_default(),
+            definitions
+                .iter()
+                .map(|definition| format!("{}{}", INDENT, definition))
+                .collect::<Vec<_>>()
+                .join(",\n")
+        );
+
+        if let Some(table_options) = &self.table_options {
+            if dialect.supports_table_options() {
+                let options: Vec<String> = table_options
+                    .options
+                    .iter()
+                    .filter(|option| dialect.supports_table_option(option))
+                    .map(|option| option.to_sql(dialect))
+                    .collect();
+
+                if !options.is_empty() {
+                    sql.push_str(&format!(" {}", options.join(", ")));
+                }
+            }
+        }
+
+        sql.push(';');
+        sql.push_str(&comment_on_columns(
+            self.table.as_ref(),
+            self.columns.iter(),
+            dialect,
+        ));
+
+        if dialect.supports_comment_on() {
+            if let Some(option) = self
+                .table_options
+                .iter()
+                .flat_map(|table_options| &table_options.options)
+                .find(|option| option.name == "COMMENT")
+            {
+                sql.push('\n');
+                sql.push_str(&comment_on(
+                    &format!(
+                        "TABLE {}",
+                        self.table
+                            .as_ref()
+                            .map(|table| table.to_sql(dialect))
+                            .unwrap_or_default()
+                    ),
+                    option.value.as_deref().unwrap_or_default(),
+                    dialect,
+                ));
+            }
+        }
+
+        // 테이블 안에 인덱스를 정의할 수 없는 방언은 CREATE INDEX 문으로 따로 출력합니다.
+        if !dialect.supports_inline_index() {
+            for index in &self.indexes {
+                let mut query = CreateIndexQuery::builder()
+                    .set_table(self.table.clone().unwrap_or_default())
+                    .set_index_name(index.index_name.clone())
+                    .set_unique(index.is_unique);
+
+                query.columns = index.columns.clone();
+                query.method = index.method.clone();
+
+                sql.push('\n');
+                sql.push_str(&query.build().to_sql(dialect));
+            }
+        }
+
+        sql
+    }
+}
+
+// ALTER TABLE [ONLY] table_name action [, action ...];
+impl ToSql for AlterTableQuery {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        let table = self
+            .table
+            .as_ref()
+            .map(|table| table.to_sql(dialect))
+            .unwrap_or_default();
+
+        // 주석 변경은 COMMENT ON 문으로 따로 출력합니다. (PostgreSQL)
+        let (comments, actions): (Vec<&AlterTableAction>, Vec<&AlterTableAction>) = self
+            .actions
+            .iter()
+            .partition(|action| dialect.supports_comment_on() && is_comment_action(action));
+
+        let mut sql = String::new();
+
+        if !actions.is_empty() || comments.is_empty() {
+            let actions: Vec<String> = actions
+                .into_iter()
+                .map(|action| action.to_sql(dialect))
+                .collect();
+
+            let target = if self.only && dialect.supports_alter_table_only() {
+                format!("ONLY {}", table)
+            } else {
+                table.clone()
+            };
+
+            // 동작이 여러개일 경우 한 줄에 하나씩 출력합니다.
+            sql = match actions.as_slice() {
+                [] => format!("ALTER TABLE {};", target),
+                [action] => format!("ALTER TABLE {} {};", target, action),
+                _ => format!(
+                    "ALTER TABLE {}\n{};",
+                    target,
+                    actions
+                        .iter()
+                        .map(|action| format!("{}{}", INDENT, action))
+                        .collect::<Vec<_>>()
+                        .join(",\n")
+                ),
+            };
+        }
+
+        sql.push_str(&comment_on_columns(
+            self.table.as_ref(),
+            self.actions.iter().filter_map(|action| match action {
+                AlterTableAction::AddColumn(action) => Some(&action.column),
+                AlterTableAction::ModifyColumn(action) => Some(&action.column),
+                AlterTableAction::ChangeColumn(action) => Some(&action.column),
+                _ => None,
+            }),
+            dialect,
+        ));
+
+        for action in comments {
+            if !sql.is_empty() {
+                sql.push('\n');
+            }
+
+            match action {
+                AlterTableAction::AlterColumn(action) => {
+                    let comment = match &action.action {
+                        AlterColumnAction::AlterColumnSetComment(action) => &action.comment,
+                        _ => "",
+                    };
+
+                    sql.push_str(&comment_on(
+                        &format!(
+                            "COLUMN {}.{}",
+                            table,
+                            quote_identifier(&action.column_name, dialect)
+                        ),
+                        comment,
+                        dialect,
+                    ));
+                }
+                AlterTableAction::SetTableOption(action) => {
+                    sql.push_str(&comment_on(
+                        &format!("TABLE {}", table),
+                        action.option.value.as_deref().unwrap_or_default(),
+                        dialect,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        sql
+    }
+}
+
+// COMMENT ON 문으로 출력하는 주석 변경인지
+fn is_comment_action(action: &AlterTableAction) -> bool {
+    match action {
+        AlterTableAction::AlterColumn(action) => matches!(
+            action.action,
+            AlterColumnAction::AlterColumnSetComment(_) | AlterColumnAction::AlterColumnDropComment
+        ),
+        AlterTableAction::SetTableOption(action) => action.option.name == "COMMENT",
+        _ => false,
+    }
+}
+
+impl ToSql for AlterTableAction {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        match self {
+            AlterTableAction::AlterTableRenameTo(action) => {
+                format!("RENAME TO {}", quote_identifier(&action.name, dialect))
+            }
+            AlterTableAction::AddColumn(action) => format!(
+                "ADD COLUMN {}{}",
+                action.column.to_sql(dialect),
+                column_position(action.position.as_ref(), dialect)
+            ),
+            AlterTableAction::ModifyColumn(action) => format!(
+                "MODIFY COLUMN {}{}",
+                action.column.to_sql(dialect),
+                column_position(action.position.as_ref(), dialect)
+            ),
+            AlterTableAction::ChangeColumn(action) => format!(
+                "CHANGE COLUMN {} {}{}",
+                quote_identifier(&action.old_name, dialect),
+                action.column.to_sql(dialect),
+                column_position(action.position.as_ref(), dialect)
+            ),
+            AlterTableAction::AlterColumn(action) => format!(
+                "ALTER COLUMN {} {}",
+                quote_identifier(&action.column_name, dialect),
+                action.action.to_sql(dialect)
+            ),
+            AlterTableAction::DropColumn(action) => format!(
+                "DROP COLUMN {}",
+                quote_identifier(&action.column_name, dialect)
+            ),
+            AlterTableAction::RenameColumn(action) => format!(
+                "RENAME COLUMN {} TO {}",
+                quote_identifier(&action.from_name, dialect),
+                quote_identifier(&action.to_name, dialect)
+            ),
+            AlterTableAction::AddConstraint(action) => format!(
+                "ADD {}{}",
+                action.constraint.to_sql(dialect),
+                if action.not_valid { " NOT VALID" } else { "" }
+            ),
+            AlterTableAction::DropConstraint(action) => format!(
+                "DROP CONSTRAINT {}{}{}",
+                if action.if_exists { "IF EXISTS " } else { "" },
+                quote_identifier(&action.constraint_name, dialect),
+                drop_behavior(action.drop_behavior, dialect)
+            ),
+            AlterTableAction::DropPrimaryKey => "DROP PRIMARY KEY".into(),
+            AlterTableAction::AddIndex(action) => format!("ADD {}", action.index.to_sql(dialect)),
+            AlterTableAction::RenameConstraint(action) => format!(
+                "RENAME CONSTRAINT {} TO {}",
+                quote_identifier(&action.from_name, dialect),
+                quote_identifier(&action.to_name, dialect)
+            ),
+            AlterTableAction::ValidateConstraint(action) => format!(
+                "VALIDATE CONSTRAINT {}",
+                quote_identifier(&action.constraint_name, dialect)
+            ),
+            AlterTableAction::DropIndex(action) => {
+                format!(
+                    "DROP INDEX {}",
+                    quote_identifier(&action.index_name, dialect)
+                )
+            }
+            AlterTableAction::SetTableOption(action) => action.option.to_sql(dialect),
+        }
+    }
+}
+
+// [FIRST | AFTER column_name]
+fn column_position(position: Option<&ColumnPosition>, dialect: &dyn Dialect) -> String {
+    match position {
+        None => "".into(),
+        Some(ColumnPosition::First) => " FIRST".into(),
+        Some(ColumnPosition::After(column_name)) => {
+            format!(" AFTER {}", quote_identifier(column_name, dialect))
+        }
+    }
+}
+
+impl ToSql for AlterColumnAction {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        match self {
+            AlterColumnAction::AlterColumnSetType(action) => {
+                let mut sql = format!("TYPE {}", action.data_type.to_sql(dialect));
+
+                if let Some(using) = &action.using {
+                    sql.push_str(&format!(" USING {}", using.to_sql(dialect)));
+                }
+
+                sql
+            }
+            AlterColumnAction::AlterColumnSetNotNull => "SET NOT NULL".into(),
+            AlterColumnAction::AlterColumnDropNotNull => "DROP NOT NULL".into(),
+            AlterColumnAction::AlterColumnSetDefault(action) => {
+                format!("SET DEFAULT {}", action.expression.to_sql(dialect))
+            }
+            AlterColumnAction::AlterColumnDropDefault(_) => "DROP DEFAULT".into(),
+            AlterColumnAction::AlterColumnSetComment(action) => {
+                format!("SET COMMENT {}", quote_string(&action.comment, dialect))
+            }
+            AlterColumnAction::AlterColumnDropComment => "DROP COMMENT".into(),
+            AlterColumnAction::AlterColumnAddIdentity(action) => {
+                format!("ADD {}", action.identity.to_sql(dialect))
+            }
+            AlterColumnAction::AlterColumnSetIdentity(action) => match action.kind {
+                IdentityKind::Always => "SET GENERATED ALWAYS".into(),
+                IdentityKind::ByDefault => "SET GENERATED BY DEFAULT".into(),
+            },
+            AlterColumnAction::AlterColumnDropIdentity(action) => format!(
+                "DROP IDENTITY{}",
+                if action.if_exists { " IF EXISTS" } else { "" }
+            ),
+            AlterColumnAction::AlterColumnSetStatistics(action) => {
+                format!("SET STATISTICS {}", action.value)
+            }
+            AlterColumnAction::AlterColumnSetCollation(action) => format!(
+                "SET COLLATION {}",
+                quote_identifier(&action.collation, dialect)
+            ),
+        }
+    }
+}
+
+// DROP [TEMPORARY] TABLE [IF EXISTS] table_name [, ...] [CASCADE | RESTRICT];
+impl ToSql for DropTableQuery {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        format!(
+            "DROP {}TABLE {}{}{};",
+            if self.temporary { "TEMPORARY " } else { "" },
+            if self.if_exists { "IF EXISTS " } else { "" },
+            self.tables
+                .iter()
+                .map(|table| table.to_sql(dialect))
+                .collect::<Vec<_>>()
+                .join(", "),
+            drop_behavior(self.drop_behavior, dialect)
+        )
+    }
+}
+
+// CREATE [UNIQUE] INDEX [IF NOT EXISTS] index_name ON table_name [USING method] (column_name [DESC], ...);
+impl ToSql for CreateIndexQuery {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        format!(
+            "CREATE {}INDEX {}{} ON {}{} ({});",
+            if self.is_unique { "UNIQUE " } else { "" },
+            if self.if_not_exists {
+                "IF NOT EXISTS "
+            } else {
+                ""
+            },
+            quote_identifier(&self.index_name, dialect),
+            self.table.to_sql(dialect),
+            match &self.method {
+                Some(method) if dialect.supports_index_method() => format!(" USING {}", method),
+                _ => "".into(),
+            },
+            self.columns
+                .iter()
+                .map(|column| column.to_sql(dialect))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+// DROP INDEX [IF EXISTS] [database_name.]index_name [ON table_name];
+impl ToSql for DropIndexQuery {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        let index = TableName::new(self.database_name.clone(), self.index_name.clone());
+
+        format!(
+            "DROP INDEX {}{}{};",
+            if self.if_exists { "IF EXISTS " } else { "" },
+            index.to_sql(dialect),
+            self.table
+                .as_ref()
+                .map(|table| format!(" ON {}", table.to_sql(dialect)))
+                .unwrap_or_default()
+        )
+    }
+}
+
+// [CASCADE | RESTRICT]
+fn drop_behavior(drop_behavior: Option<DropBehavior>, dialect: &dyn Dialect) -> String {
+    drop_behavior
+        .map(|drop_behavior| format!(" {}", drop_behavior.to_sql(dialect)))
+        .unwrap_or_default()
+}
+
+// 컬럼 정의 안에 주석을 쓸 수 없는 방언은 COMMENT ON COLUMN 문으로 이어서 출력합니다. (PostgreSQL)
+fn comment_on_columns<'a>(
+    table: Option<&TableName>,
+    columns: impl Iterator<Item = &'a Column>,
+    dialect: &dyn Dialect,
+) -> String {
+    if !dialect.supports_comment_on() {
+        return "".into();
+    }
+
+    let table = table.map(|table| table.to_sql(dialect)).unwrap_or_default();
+
+    columns
+        .filter(|column| !column.comment.is_empty())
+        .map(|column| {
+            format!(
+                "\n{}",
+                comment_on(
+                    &format!(
+                        "COLUMN {}.{}",
+                        table,
+                        quote_identifier(&column.name, dialect)
+                    ),
+                    &column.comment,
+                    dialect
+                )
+            )
+        })
+        .collect()
+}
+
+// COMMENT ON { TABLE | COLUMN } object IS { 'comment' | NULL };
+// 빈 주석은 주석 삭제로 출력합니다.
+fn comment_on(object: &str, comment: &str, dialect: &dyn Dialect) -> String {
+    if comment.is_empty() {
+        format!("COMMENT ON {} IS NULL;", object)
+    } else {
+        format!(
+            "COMMENT ON {} IS {};",
+            object,
+            quote_string(comment, dialect)
+        )
+    }
+}
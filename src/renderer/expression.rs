@@ -0,0 +1,228 @@
+use crate::ast::predule::{
+    AggregateFunction, BinaryOperator, BuiltInFunction, ColumnName, ConditionalFunction,
+    Expression, Function, SQLValueFunction, UnaryOperator,
+};
+use crate::dialect::predule::Dialect;
+use crate::renderer::predule::{quote_identifier, quote_string, ToSql};
+
+// 괄호 없이 출력해도 결합이 바뀌지 않는 표현식의 우선순위
+const ATOM_PRECEDENCE: i32 = i32::MAX;
+
+impl ToSql for Expression {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        match self {
+            Expression::Integer(integer) => integer.to_string(),
+            Expression::Float(float) => format!("{:?}", float),
+            Expression::String(string) => quote_string(string, dialect),
+            Expression::HexString(digits) => format!("X'{}'", digits),
+            Expression::Boolean(boolean) => if *boolean { "TRUE" } else { "FALSE" }.into(),
+            Expression::Null => "NULL".into(),
+            Expression::Column(column) => column.to_sql(dialect),
+            Expression::SQLValueFunction(function) => function.to_sql(dialect),
+            Expression::Unary(unary) => {
+                let operand = if matches!(*unary.operand, Expression::Unary(_)) {
+                    unary.operand.to_sql(dialect)
+                } else {
+                    wrap(&unary.operand, unary.operator.get_precedence() + 1, dialect)
+                };
+
+                match unary.operator {
+                    // --는 주석으로 해석되므로 공백을 둡니다.
+                    UnaryOperator::Neg if operand.starts_with('-') => format!("- {}", operand),
+                    UnaryOperator::Neg => format!("-{}", operand),
+                    UnaryOperator::Pos => format!("+{}", operand),
+                    UnaryOperator::Not => format!("NOT {}", operand),
+                    UnaryOperator::BitNot => format!("~{}", operand),
+                }
+            }
+            Expression::Binary(binary) => {
+                let precedence = binary.operator.get_precedence();
+
+                format!(
+                    "{} {} {}",
+                    wrap(&binary.lhs, precedence, dialect),
+                    binary.operator,
+                    wrap(&binary.rhs, precedence + 1, dialect)
+                )
+            }
+            Expression::FunctionCall(call) => format!(
+                "{}({})",
+                call.function.to_sql(dialect),
+                expression_list(&call.arguments, dialect)
+            ),
+            Expression::Cast(cast) => format!(
+                "CAST({} AS {})",
+                cast.expression.to_sql(dialect),
+                cast.data_type.to_sql(dialect)
+            ),
+            Expression::Case(case) => {
+                let mut sql = "CASE".to_owned();
+
+                if let Some(operand) = &case.operand {
+                    sql.push_str(&format!(" {}", operand.to_sql(dialect)));
+                }
+
+                for when_clause in &case.when_clauses {
+                    sql.push_str(&format!(
+                        " WHEN {} THEN {}",
+                        when_clause.condition.to_sql(dialect),
+                        when_clause.result.to_sql(dialect)
+                    ));
+                }
+
+                if let Some(else_result) = &case.else_result {
+                    sql.push_str(&format!(" ELSE {}", else_result.to_sql(dialect)));
+                }
+
+                sql.push_str(" END");
+                sql
+            }
+            Expression::InList(in_list) => format!(
+                "{} {}IN ({})",
+                wrap(
+                    &in_list.expression,
+                    BinaryOperator::In.get_precedence(),
+                    dialect
+                ),
+                if in_list.negated { "NOT " } else { "" },
+                expression_list(&in_list.list, dialect)
+            ),
+            Expression::Between(between) => {
+                let precedence = BinaryOperator::In.get_precedence();
+
+                format!(
+                    "{} {}BETWEEN {} AND {}",
+                    wrap(&between.expression, precedence, dialect),
+                    if between.negated { "NOT " } else { "" },
+                    wrap(&between.low, precedence + 1, dialect),
+                    wrap(&between.high, precedence + 1, dialect)
+                )
+            }
+            Expression::Parentheses(parentheses) => {
+                format!("({})", parentheses.expression.to_sql(dialect))
+            }
+        }
+    }
+}
+
+impl ToSql for ColumnName {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        match &self.table_name {
+            Some(table_name) => format!(
+                "{}.{}",
+                quote_identifier(table_name, dialect),
+                quote_identifier(&self.column_name, dialect)
+            ),
+            None => quote_identifier(&self.column_name, dialect),
+        }
+    }
+}
+
+impl ToSql for SQLValueFunction {
+    fn to_sql(&self, _dialect: &dyn Dialect) -> String {
+        match self {
+            SQLValueFunction::CurrentDate => "CURRENT_DATE",
+            SQLValueFunction::CurrentTime => "CURRENT_TIME",
+            SQLValueFunction::CurrentTimestamp => "CURRENT_TIMESTAMP",
+            SQLValueFunction::LocalTime => "LOCALTIME",
+            SQLValueFunction::LocalTimestamp => "LOCALTIMESTAMP",
+            SQLValueFunction::CurrentUser => "CURRENT_USER",
+            SQLValueFunction::SessionUser => "SESSION_USER",
+        }
+        .into()
+    }
+}
+
+impl ToSql for Function {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        match self {
+            Function::BuiltIn(BuiltInFunction::Aggregate(function)) => match function {
+                AggregateFunction::Sum => "SUM",
+                AggregateFunction::Count => "COUNT",
+                AggregateFunction::Max => "MAX",
+                AggregateFunction::Min => "MIN",
+                AggregateFunction::Avg => "AVG",
+                AggregateFunction::Every => "EVERY",
+                AggregateFunction::ArrayAgg => "ARRAYAGG",
+                AggregateFunction::StringAgg => "STRINGAGG",
+            }
+            .into(),
+            Function::BuiltIn(BuiltInFunction::Conditional(function)) => match function {
+                ConditionalFunction::NullIf => "NULLIF",
+                ConditionalFunction::Coalesce => "COALESCE",
+                ConditionalFunction::Greatest => "GREATEST",
+                ConditionalFunction::Least => "LEAST",
+            }
+            .into(),
+            Function::UserDefined(function) => match &function.database_name {
+                Some(database_name) => format!(
+                    "{}.{}",
+                    quote_identifier(database_name, dialect),
+                    quote_identifier(&function.function_name, dialect)
+                ),
+                None => quote_identifier(&function.function_name, dialect),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operator = match self {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Sub => "-",
+            BinaryOperator::Mul => "*",
+            BinaryOperator::Div => "/",
+            BinaryOperator::Mod => "%",
+            BinaryOperator::Caret => "^",
+            BinaryOperator::Concat => "||",
+            BinaryOperator::BitAnd => "&",
+            BinaryOperator::BitOr => "|",
+            BinaryOperator::ShiftLeft => "<<",
+            BinaryOperator::ShiftRight => ">>",
+            BinaryOperator::And => "AND",
+            BinaryOperator::Or => "OR",
+            BinaryOperator::Lt => "<",
+            BinaryOperator::Gt => ">",
+            BinaryOperator::Lte => "<=",
+            BinaryOperator::Gte => ">=",
+            BinaryOperator::Eq => "=",
+            BinaryOperator::Neq => "<>",
+            BinaryOperator::Like => "LIKE",
+            BinaryOperator::NotLike => "NOT LIKE",
+            BinaryOperator::In => "IN",
+            BinaryOperator::NotIn => "NOT IN",
+            BinaryOperator::Is => "IS",
+            BinaryOperator::IsNot => "IS NOT",
+        };
+
+        write!(f, "{}", operator)
+    }
+}
+
+// 표현식의 결합 우선순위
+fn precedence(expression: &Expression) -> i32 {
+    match expression {
+        Expression::Binary(binary) => binary.operator.get_precedence(),
+        Expression::Unary(unary) => unary.operator.get_precedence(),
+        Expression::InList(_) | Expression::Between(_) => BinaryOperator::In.get_precedence(),
+        _ => ATOM_PRECEDENCE,
+    }
+}
+
+// 우선순위가 min_precedence보다 낮은 표현식은 괄호로 감쌉니다.
+fn wrap(expression: &Expression, min_precedence: i32, dialect: &dyn Dialect) -> String {
+    if precedence(expression) < min_precedence {
+        format!("({})", expression.to_sql(dialect))
+    } else {
+        expression.to_sql(dialect)
+    }
+}
+
+fn expression_list(expressions: &[Expression], dialect: &dyn Dialect) -> String {
+    expressions
+        .iter()
+        .map(|expression| expression.to_sql(dialect))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
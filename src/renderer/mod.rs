@@ -0,0 +1,7 @@
+pub mod ddl;
+pub mod expression;
+pub mod predule;
+pub mod to_sql;
+pub mod types;
+
+pub(crate) mod test;
@@ -0,0 +1 @@
+pub use super::to_sql::*;
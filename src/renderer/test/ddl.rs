@@ -0,0 +1,245 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::ast::ddl::{
+    AlterTableDropColumn, AlterTableQuery, AlterTableRenameColumn, CreateTableQuery, DropTableQuery,
+};
+use crate::ast::predule::{Expression, SQLValueFunction};
+use crate::ast::types::{
+    Column, DataType, Index, IndexColumn, PrimaryKey, SortOrder, TableName, TableOption,
+    TableOptions,
+};
+use crate::dialect::predule::{
+    Dialect, GenericDialect, MySQLDialect, PostgreSQLDialect, SQLiteDialect,
+};
+use crate::lexer::predule::NodeSpan;
+use crate::renderer::predule::ToSql;
+use crate::renderer::test::round_trip::assert_round_trip;
+
+fn user_table() -> CreateTableQuery {
+    CreateTableQuery::builder()
+        .set_table(TableName::new(None, "user".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("id".to_owned())
+                .set_data_type(DataType::Unsigned(Box::new(DataType::Int(None))))
+                .set_not_null(true)
+                .set_auto_increment(true)
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("Name".to_owned())
+                .set_data_type(DataType::Varchar(Some(100)))
+                .set_default(Expression::String("it's".to_owned()))
+                .set_comment("user name".to_owned())
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("created_at".to_owned())
+                .set_data_type(DataType::DateTime(None))
+                .set_default(SQLValueFunction::CurrentTimestamp.into())
+                .build(),
+        )
+        .set_primary_key(PrimaryKey {
+            key_name: None,
+            columns: vec!["id".to_owned()],
+            span: NodeSpan::default(),
+        })
+        .set_table_option(TableOptions {
+            options: vec![TableOption::new("ENGINE", Some("InnoDB".to_owned()))],
+        })
+}
+
+#[test]
+pub fn render_create_table_mysql() {
+    assert_eq!(
+        user_table().to_sql(&MySQLDialect {}),
+        "CREATE TABLE user (
+    id integer unsigned NOT NULL AUTO_INCREMENT,
+    Name varchar(100) DEFAULT 'it''s' COMMENT 'user name',
+    created_at datetime DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id)
+) ENGINE=InnoDB;"
+    );
+
+    assert_round_trip(&user_table().build(), Arc::new(MySQLDialect {}));
+}
+
+#[test]
+pub fn render_create_table_postgresql() {
+    assert_eq!(
+        user_table().to_sql(&PostgreSQLDialect {}),
+        r#"CREATE TABLE "user" (
+    id integer NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+    "Name" varchar(100) DEFAULT 'it''s',
+    created_at timestamp DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id)
+);
+COMMENT ON COLUMN "user"."Name" IS 'user name';"#
+    );
+}
+
+#[test]
+pub fn render_create_table_sqlite() {
+    assert_eq!(
+        user_table().to_sql(&SQLiteDialect {}),
+        r#"CREATE TABLE user (
+    id integer unsigned NOT NULL,
+    Name varchar(100) DEFAULT 'it''s',
+    created_at datetime DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id)
+);"#
+    );
+}
+
+#[test]
+pub fn render_create_table_index() {
+    let statement = user_table().add_index(Index {
+        index_name: "idx_name".to_owned(),
+        columns: vec![
+            IndexColumn::new("Name".to_owned(), None),
+            IndexColumn::new("created_at".to_owned(), Some(SortOrder::Desc)),
+        ],
+        is_unique: false,
+        method: None,
+    });
+
+    assert_eq!(
+        statement.to_sql(&MySQLDialect {}),
+        "CREATE TABLE user (
+    id integer unsigned NOT NULL AUTO_INCREMENT,
+    Name varchar(100) DEFAULT 'it''s' COMMENT 'user name',
+    created_at datetime DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id),
+    INDEX idx_name (Name, created_at DESC)
+) ENGINE=InnoDB;"
+    );
+
+    assert_eq!(
+        statement.to_sql(&PostgreSQLDialect {}),
+        r#"CREATE TABLE "user" (
+    id integer NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+    "Name" varchar(100) DEFAULT 'it''s',
+    created_at timestamp DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id)
+);
+COMMENT ON COLUMN "user"."Name" IS 'user name';
+CREATE INDEX idx_name ON "user" ("Name", created_at DESC);"#
+    );
+
+    assert_round_trip(&statement.build(), Arc::new(MySQLDialect {}));
+}
+
+#[test]
+pub fn render_create_table_round_trip() {
+    let statement = CreateTableQuery::builder()
+        .set_table(TableName::new(None, "user".to_owned()))
+        .add_column(
+            Column::builder()
+                .set_name("id".to_owned())
+                .set_data_type(DataType::Int(None))
+                .set_primary_key(true)
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("name".to_owned())
+                .set_data_type(DataType::Varchar(Some(100)))
+                .set_not_null(true)
+                .set_default(Expression::String("it's".to_owned()))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("score".to_owned())
+                .set_data_type(DataType::Float(None))
+                .build(),
+        )
+        .add_column(
+            Column::builder()
+                .set_name("created_at".to_owned())
+                .set_data_type(DataType::Timestamp {
+                    precision: None,
+                    with_time_zone: false,
+                })
+                .set_default(SQLValueFunction::CurrentTimestamp.into())
+                .build(),
+        )
+        .build();
+
+    let dialects: [Arc<dyn Dialect>; 4] = [
+        Arc::new(GenericDialect {}),
+        Arc::new(MySQLDialect {}),
+        Arc::new(PostgreSQLDialect {}),
+        Arc::new(SQLiteDialect {}),
+    ];
+
+    for dialect in dialects {
+        assert_round_trip(&statement, dialect);
+    }
+}
+
+#[test]
+pub fn render_primary_key_column() {
+    let column = Column::builder()
+        .set_name("id".to_owned())
+        .set_data_type(DataType::Int(None))
+        .set_primary_key(true)
+        .set_not_null(false)
+        .set_auto_increment(true)
+        .build();
+
+    assert_eq!(
+        column.to_sql(&SQLiteDialect {}),
+        "id integer PRIMARY KEY AUTOINCREMENT"
+    );
+    assert_eq!(
+        column.to_sql(&MySQLDialect {}),
+        "id integer PRIMARY KEY AUTO_INCREMENT"
+    );
+}
+
+#[test]
+pub fn render_display() {
+    let statement = DropTableQuery::builder()
+        .add_table(TableName::new(Some("my db".to_owned()), "order".to_owned()))
+        .set_if_exists(true)
+        .build();
+
+    assert_eq!(
+        statement.to_string(),
+        r#"DROP TABLE IF EXISTS "my db"."order";"#
+    );
+
+    assert_round_trip(&statement, Arc::new(GenericDialect {}));
+}
+
+#[test]
+pub fn render_alter_table_multiple_actions() {
+    let statement = AlterTableQuery::builder()
+        .set_table(TableName::new(None, "person".to_owned()))
+        .add_action(
+            AlterTableDropColumn {
+                column_name: "age".to_owned(),
+            }
+            .into(),
+        )
+        .add_action(
+            AlterTableRenameColumn {
+                from_name: "name".to_owned(),
+                to_name: "nickname".to_owned(),
+            }
+            .into(),
+        )
+        .build();
+
+    assert_eq!(
+        statement.to_sql(&MySQLDialect {}),
+        "ALTER TABLE person\n    DROP COLUMN age,\n    RENAME COLUMN name TO nickname;"
+    );
+
+    assert_round_trip(&statement, Arc::new(MySQLDialect {}));
+}
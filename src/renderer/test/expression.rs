@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+use crate::ast::predule::{
+    BinaryOperator, BinaryOperatorExpression, ColumnName, Expression, UnaryOperator,
+    UnaryOperatorExpression,
+};
+use crate::dialect::predule::{GenericDialect, MySQLDialect};
+use crate::renderer::predule::ToSql;
+
+fn column(name: &str) -> Box<Expression> {
+    Box::new(ColumnName::new(None, name.to_owned()).into())
+}
+
+fn binary(operator: BinaryOperator, lhs: Box<Expression>, rhs: Box<Expression>) -> Box<Expression> {
+    Box::new(BinaryOperatorExpression { operator, lhs, rhs }.into())
+}
+
+#[test]
+pub fn render_expression_precedence() {
+    // 괄호 노드 없이 만든 트리도 결합 순서가 유지되어야 합니다.
+    let expression = binary(
+        BinaryOperator::Mul,
+        binary(BinaryOperator::Add, column("a"), column("b")),
+        column("c"),
+    );
+    assert_eq!(expression.to_sql(&GenericDialect {}), "(a + b) * c");
+
+    let expression = binary(
+        BinaryOperator::Sub,
+        column("a"),
+        binary(BinaryOperator::Sub, column("b"), column("c")),
+    );
+    assert_eq!(expression.to_sql(&GenericDialect {}), "a - (b - c)");
+
+    let expression = binary(
+        BinaryOperator::And,
+        binary(BinaryOperator::Or, column("a"), column("b")),
+        Box::new(
+            UnaryOperatorExpression {
+                operator: UnaryOperator::Not,
+                operand: column("c"),
+            }
+            .into(),
+        ),
+    );
+    assert_eq!(expression.to_sql(&GenericDialect {}), "(a OR b) AND NOT c");
+}
+
+#[test]
+pub fn render_expression_negative() {
+    let expression: Expression = UnaryOperatorExpression {
+        operator: UnaryOperator::Neg,
+        operand: Box::new(Expression::Integer(-1)),
+    }
+    .into();
+    assert_eq!(expression.to_sql(&GenericDialect {}), "- -1");
+
+    assert_eq!(Expression::Float(1.0).to_sql(&GenericDialect {}), "1.0");
+    assert_eq!(Expression::Boolean(true).to_sql(&GenericDialect {}), "TRUE");
+}
+
+#[test]
+pub fn render_string_literals() {
+    let string = Expression::String(r"C:\dir\it's".into());
+    assert_eq!(string.to_sql(&GenericDialect {}), r"'C:\dir\it''s'");
+    // MySQL에서는 백슬래시가 이스케이프 문자이므로 두번 씁니다.
+    assert_eq!(string.to_sql(&MySQLDialect {}), r"'C:\\dir\\it''s'");
+
+    let hex = Expression::HexString("1F".into());
+    assert_eq!(hex.to_sql(&GenericDialect {}), "X'1F'");
+}
@@ -0,0 +1,3 @@
+pub(crate) mod ddl;
+pub(crate) mod expression;
+pub(crate) mod round_trip;
@@ -0,0 +1,23 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use crate::ast::predule::SQLStatement;
+use crate::dialect::predule::Dialect;
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+use crate::renderer::predule::ToSql;
+
+// 생성한 SQL을 같은 방언으로 다시 파싱했을 때 원래의 AST가 나오는지 확인합니다.
+pub(crate) fn assert_round_trip(statement: &SQLStatement, dialect: Arc<dyn Dialect>) {
+    let sql = statement.to_sql(dialect.as_ref());
+
+    let mut parser = Parser::with_dialect(sql.clone(), dialect).unwrap();
+
+    assert_eq!(
+        parser.parse(ParserContext::default()).unwrap(),
+        vec![statement.clone()],
+        "rendered sql: {}",
+        sql
+    );
+}
@@ -0,0 +1,107 @@
+use crate::ast::predule::SQLStatement;
+use crate::dialect::predule::{Dialect, GenericDialect};
+
+// AST를 SQL 문자열로 변환합니다.
+// 생성된 SQL을 같은 방언으로 다시 파싱하면 원래의 AST가 나와야 합니다.
+pub trait ToSql {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String;
+}
+
+impl std::fmt::Display for SQLStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_sql(&GenericDialect {}))
+    }
+}
+
+// 문맥에 따라 키워드로 해석되는 단어
+// 토큰으로는 식별자지만, 그대로 출력하면 다르게 파싱될 수 있습니다.
+const CONTEXTUAL_KEYWORDS: &[&str] = &[
+    "CASE",
+    "WHEN",
+    "THEN",
+    "ELSE",
+    "END",
+    "CAST",
+    "GENERATED",
+    "ALWAYS",
+    "IDENTITY",
+    "STORED",
+    "VIRTUAL",
+    "UNSIGNED",
+    "SIGNED",
+    "ZEROFILL",
+    "PRECISION",
+    "VARYING",
+    "WITH",
+    "WITHOUT",
+    "ZONE",
+    "NO",
+    "ACTION",
+    "RESTRICT",
+    "CASCADE",
+    "CURRENT_DATE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "CURRENT_USER",
+    "SESSION_USER",
+];
+
+// 식별자를 출력합니다.
+// 키워드와 겹치거나 특수문자가 포함된 경우, 대소문자 변환이 일어나는 경우에만 따옴표로 감쌉니다.
+pub fn quote_identifier(identifier: &str, dialect: &dyn Dialect) -> String {
+    let mut chars = identifier.chars();
+
+    let is_plain = match chars.next() {
+        Some(first) => {
+            (first.is_alphabetic() || first == '_')
+                && chars.all(|ch| ch.is_alphanumeric() || ch == '_')
+        }
+        None => false,
+    };
+
+    let upper = identifier.to_uppercase();
+
+    let is_bare = is_plain
+        && dialect.keyword(&upper).is_none()
+        && !dialect.is_reserved_word(&upper)
+        && !CONTEXTUAL_KEYWORDS.contains(&upper.as_str())
+        && dialect.fold_identifier(identifier.to_owned()) == identifier;
+
+    if is_bare {
+        identifier.to_owned()
+    } else {
+        let quote = dialect.identifier_quote();
+        format!(
+            "{}{}{}",
+            quote,
+            identifier.replace(quote, &format!("{}{}", quote, quote)),
+            quote
+        )
+    }
+}
+
+// 문자열 리터럴을 출력합니다.
+// 백슬래시 이스케이프를 쓰는 방언에서는 백슬래시도 이스케이프합니다.
+pub fn quote_string(value: &str, dialect: &dyn Dialect) -> String {
+    let value = if dialect.supports_backslash_escapes() {
+        value.replace('\\', "\\\\")
+    } else {
+        value.to_owned()
+    };
+
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+// 식별자 목록을 (a, b, c) 형태로 출력합니다.
+pub fn identifier_list(identifiers: &[String], dialect: &dyn Dialect) -> String {
+    format!(
+        "({})",
+        identifiers
+            .iter()
+            .map(|identifier| quote_identifier(identifier, dialect))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
@@ -0,0 +1,382 @@
+use crate::ast::predule::{
+    CheckConstraint, Column, DataType, DropBehavior, ForeignKey, GeneratedColumn, Identity,
+    IdentityKind, Index, IndexColumn, PrimaryKey, Reference, ReferentialAction, SortOrder,
+    TableConstraint, TableName, TableOption, TableOptions, UniqueKey,
+};
+use crate::dialect::predule::Dialect;
+use crate::renderer::predule::{identifier_list, quote_identifier, quote_string, ToSql};
+
+impl ToSql for DataType {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        dialect.data_type_to_sql(self)
+    }
+}
+
+impl ToSql for TableName {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        match &self.database_name {
+            Some(database_name) => format!(
+                "{}.{}",
+                quote_identifier(database_name, dialect),
+                quote_identifier(&self.table_name, dialect)
+            ),
+            None => quote_identifier(&self.table_name, dialect),
+        }
+    }
+}
+
+// column_name data_type [COLLATE collation] [PRIMARY KEY | NOT NULL] [DEFAULT expression] ...
+impl ToSql for Column {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        let mut sql = format!(
+            "{} {}",
+            quote_identifier(&self.name, dialect),
+            self.data_type.to_sql(dialect)
+        );
+
+        if let Some(charset) = &self.charset {
+            if dialect.supports_column_charset() {
+                sql.push_str(&format!(
+                    " CHARACTER SET {}",
+                    quote_identifier(charset, dialect)
+                ));
+            }
+        }
+
+        if let Some(collation) = &self.collation {
+            sql.push_str(&format!(
+                " COLLATE {}",
+                quote_identifier(collation, dialect)
+            ));
+        }
+
+        // SQLite의 AUTOINCREMENT는 INTEGER PRIMARY KEY 바로 뒤에만 올 수 있고, 그 외의 컬럼에서는 생략합니다.
+        let auto_increment_after_primary_key =
+            dialect.auto_increment_requires_integer_primary_key();
+
+        // 기본키는 항상 NOT NULL이므로 NULL 여부를 따로 출력하지 않습니다.
+        if self.primary_key {
+            sql.push_str(&format!(
+                " {}PRIMARY KEY",
+                constraint_prefix(&self.primary_key_name, dialect)
+            ));
+
+            if auto_increment_after_primary_key
+                && self.data_type == DataType::Int(None)
+                && (self.auto_increment || self.identity.is_some())
+            {
+                if let Some(keyword) = dialect.auto_increment_keyword() {
+                    sql.push_str(&format!(" {}", keyword));
+                }
+            }
+        } else if self.not_null {
+            sql.push_str(" NOT NULL");
+        }
+
+        if let Some(default) = &self.default {
+            sql.push_str(&format!(" DEFAULT {}", default.to_sql(dialect)));
+        }
+
+        if self.unique {
+            sql.push_str(&format!(
+                " {}UNIQUE",
+                constraint_prefix(&self.unique_name, dialect)
+            ));
+        }
+
+        // 자동 증가 키워드가 없는 방언은 IDENTITY 컬럼으로, IDENTITY 컬럼이 없는 방언은 자동 증가 키워드로 대체합니다.
+        if self.auto_increment && !auto_increment_after_primary_key {
+            match dialect.auto_increment_keyword() {
+                Some(keyword) => sql.push_str(&format!(" {}", keyword)),
+                None if self.identity.is_none() => {
+                    sql.push_str(" GENERATED BY DEFAULT AS IDENTITY")
+                }
+                None => {}
+            }
+        }
+
+        if let Some(identity) = &self.identity {
+            if dialect.supports_identity_columns() {
+                sql.push_str(&format!(" {}", identity.to_sql(dialect)));
+            } else if !self.auto_increment && !auto_increment_after_primary_key {
+                if let Some(keyword) = dialect.auto_increment_keyword() {
+                    sql.push_str(&format!(" {}", keyword));
+                }
+            }
+        }
+
+        if let Some(generated) = &self.generated {
+            sql.push_str(&format!(" {}", generated.to_sql(dialect)));
+        }
+
+        if let Some(reference) = &self.reference {
+            sql.push_str(&format!(" {}", reference.to_sql(dialect)));
+        }
+
+        for check in &self.checks {
+            sql.push_str(&format!(" {}", check.to_sql(dialect)));
+        }
+
+        if let Some(on_update) = &self.on_update {
+            if dialect.supports_on_update() {
+                sql.push_str(&format!(" ON UPDATE {}", on_update.to_sql(dialect)));
+            }
+        }
+
+        if !self.comment.is_empty() && dialect.supports_column_comments() {
+            sql.push_str(&format!(
+                " COMMENT {}",
+                quote_string(&self.comment, dialect)
+            ));
+        }
+
+        sql
+    }
+}
+
+// GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY [(START WITH n INCREMENT BY n)]
+impl ToSql for Identity {
+    fn to_sql(&self, _dialect: &dyn Dialect) -> String {
+        let mut sql = match self.kind {
+            IdentityKind::Always => "GENERATED ALWAYS AS IDENTITY",
+            IdentityKind::ByDefault => "GENERATED BY DEFAULT AS IDENTITY",
+        }
+        .to_owned();
+
+        let mut options = vec![];
+
+        if let Some(start) = self.start {
+            options.push(format!("START WITH {}", start));
+        }
+
+        if let Some(increment) = self.increment {
+            options.push(format!("INCREMENT BY {}", increment));
+        }
+
+        if !options.is_empty() {
+            sql.push_str(&format!(" ({})", options.join(" ")));
+        }
+
+        sql
+    }
+}
+
+// GENERATED ALWAYS AS (expression) [STORED]
+impl ToSql for GeneratedColumn {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        format!(
+            "GENERATED ALWAYS AS ({}){}",
+            self.expression.to_sql(dialect),
+            if self.stored { " STORED" } else { "" }
+        )
+    }
+}
+
+// [CONSTRAINT constraint_name] REFERENCES table_name [(column_name)] [ON DELETE action] [ON UPDATE action]
+impl ToSql for Reference {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        let mut sql = constraint_prefix(&self.key_name, dialect);
+
+        sql.push_str(&format!(
+            "REFERENCES {}",
+            self.referenced_table.to_sql(dialect)
+        ));
+
+        if !self.referenced_columns.is_empty() {
+            sql.push_str(&format!(
+                " {}",
+                identifier_list(&self.referenced_columns, dialect)
+            ));
+        }
+
+        sql.push_str(&referential_actions(
+            &self.on_delete,
+            &self.on_update,
+            dialect,
+        ));
+
+        sql
+    }
+}
+
+impl ToSql for ReferentialAction {
+    fn to_sql(&self, _dialect: &dyn Dialect) -> String {
+        match self {
+            ReferentialAction::Cascade => "CASCADE",
+            ReferentialAction::SetNull => "SET NULL",
+            ReferentialAction::SetDefault => "SET DEFAULT",
+            ReferentialAction::Restrict => "RESTRICT",
+            ReferentialAction::NoAction => "NO ACTION",
+        }
+        .into()
+    }
+}
+
+impl ToSql for DropBehavior {
+    fn to_sql(&self, _dialect: &dyn Dialect) -> String {
+        match self {
+            DropBehavior::Cascade => "CASCADE",
+            DropBehavior::Restrict => "RESTRICT",
+        }
+        .into()
+    }
+}
+
+// [CONSTRAINT constraint_name] PRIMARY KEY (column_name [, ...])
+impl ToSql for PrimaryKey {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        format!(
+            "{}PRIMARY KEY {}",
+            constraint_prefix(&self.key_name, dialect),
+            identifier_list(&self.columns, dialect)
+        )
+    }
+}
+
+// [CONSTRAINT constraint_name] UNIQUE (column_name [, ...])
+impl ToSql for UniqueKey {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        format!(
+            "{}UNIQUE {}",
+            constraint_prefix(&self.key_name, dialect),
+            identifier_list(&self.columns, dialect)
+        )
+    }
+}
+
+// [CONSTRAINT constraint_name] FOREIGN KEY (column_name [, ...]) REFERENCES table_name (column_name [, ...]) ...
+impl ToSql for ForeignKey {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        let mut sql = format!(
+            "{}FOREIGN KEY {} REFERENCES {}",
+            constraint_prefix(&self.key_name, dialect),
+            identifier_list(&self.columns, dialect),
+            self.referenced_table.to_sql(dialect)
+        );
+
+        if !self.referenced_columns.is_empty() {
+            sql.push_str(&format!(
+                " {}",
+                identifier_list(&self.referenced_columns, dialect)
+            ));
+        }
+
+        sql.push_str(&referential_actions(
+            &self.on_delete,
+            &self.on_update,
+            dialect,
+        ));
+
+        sql
+    }
+}
+
+// [CONSTRAINT constraint_name] CHECK (expression)
+impl ToSql for CheckConstraint {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        format!(
+            "{}CHECK ({})",
+            constraint_prefix(&self.constraint_name, dialect),
+            self.expression.to_sql(dialect)
+        )
+    }
+}
+
+impl ToSql for TableConstraint {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        match self {
+            TableConstraint::PrimaryKey(primary_key) => primary_key.to_sql(dialect),
+            TableConstraint::UniqueKey(unique_key) => unique_key.to_sql(dialect),
+            TableConstraint::ForeignKey(foreign_key) => foreign_key.to_sql(dialect),
+            TableConstraint::Check(check_constraint) => check_constraint.to_sql(dialect),
+        }
+    }
+}
+
+// column_name [ASC | DESC]
+impl ToSql for IndexColumn {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        let column_name = quote_identifier(&self.column_name, dialect);
+
+        match self.order {
+            None => column_name,
+            Some(SortOrder::Asc) => format!("{} ASC", column_name),
+            Some(SortOrder::Desc) => format!("{} DESC", column_name),
+        }
+    }
+}
+
+// INDEX index_name (column_name [ASC | DESC], ...)
+impl ToSql for Index {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        format!(
+            "INDEX {} ({})",
+            quote_identifier(&self.index_name, dialect),
+            self.columns
+                .iter()
+                .map(|column| column.to_sql(dialect))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl ToSql for TableOptions {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        self.options
+            .iter()
+            .map(|option| option.to_sql(dialect))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+// option_name=value
+// 값이 식별자나 숫자로 읽히지 않으면 문자열로 출력합니다.
+impl ToSql for TableOption {
+    fn to_sql(&self, dialect: &dyn Dialect) -> String {
+        match &self.value {
+            Some(value) => {
+                let is_bare = self.name != "COMMENT"
+                    && (value == "DEFAULT"
+                        || value.parse::<i64>().map(|number| number.to_string())
+                            == Ok(value.clone())
+                        || quote_identifier(value, dialect) == *value);
+
+                if is_bare {
+                    format!("{}={}", self.name, value)
+                } else {
+                    format!("{}={}", self.name, quote_string(value, dialect))
+                }
+            }
+            None => self.name.clone(),
+        }
+    }
+}
+
+fn constraint_prefix(constraint_name: &Option<String>, dialect: &dyn Dialect) -> String {
+    match constraint_name {
+        Some(constraint_name) => {
+            format!("CONSTRAINT {} ", quote_identifier(constraint_name, dialect))
+        }
+        None => "".into(),
+    }
+}
+
+fn referential_actions(
+    on_delete: &Option<ReferentialAction>,
+    on_update: &Option<ReferentialAction>,
+    dialect: &dyn Dialect,
+) -> String {
+    let mut sql = String::new();
+
+    if let Some(on_delete) = on_delete {
+        sql.push_str(&format!(" ON DELETE {}", on_delete.to_sql(dialect)));
+    }
+
+    if let Some(on_update) = on_update {
+        sql.push_str(&format!(" ON UPDATE {}", on_update.to_sql(dialect)));
+    }
+
+    sql
+}
@@ -0,0 +1,6 @@
+pub mod predule;
+#[allow(clippy::module_inception)]
+pub mod visitor;
+pub mod visitor_mut;
+
+pub(crate) mod test;
@@ -0,0 +1,2 @@
+pub use super::visitor::*;
+pub use super::visitor_mut::*;
@@ -0,0 +1,15 @@
+#![cfg(test)]
+
+use crate::ast::predule::SQLStatement;
+use crate::parser::context::ParserContext;
+use crate::parser::predule::Parser;
+
+pub(crate) mod visitor;
+pub(crate) mod visitor_mut;
+
+// 기본 방언으로 문장 목록을 파싱합니다.
+pub(crate) fn parse(text: &str) -> Vec<SQLStatement> {
+    let mut parser = Parser::new(text.to_owned()).unwrap();
+
+    parser.parse(ParserContext::default()).unwrap()
+}
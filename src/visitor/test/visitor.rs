@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+use crate::ast::predule::{Column, ColumnName, TableConstraintRef, TableName};
+use crate::visitor::predule::Visitor;
+use crate::visitor::test::parse;
+
+// 문장 내에서 참조하는 테이블명과 컬럼명을 수집합니다.
+#[derive(Default)]
+struct NameCollector {
+    tables: Vec<String>,
+    columns: Vec<String>,
+}
+
+impl Visitor for NameCollector {
+    fn visit_table_name(&mut self, table_name: &TableName) {
+        self.tables.push(table_name.table_name.clone());
+    }
+
+    fn visit_column_name(&mut self, column_name: &ColumnName) {
+        self.columns.push(column_name.column_name.clone());
+    }
+}
+
+#[test]
+pub fn visitor_collect_names() {
+    let statements = parse(
+        r#"
+        CREATE TABLE post (
+            id INTEGER,
+            writer_id INTEGER REFERENCES person (id),
+            score INTEGER CHECK (score BETWEEN 0 AND max_score),
+            FOREIGN KEY (writer_id) REFERENCES member (id)
+        );
+        ALTER TABLE post ALTER COLUMN score SET DEFAULT base + 1;
+        CREATE INDEX idx_writer ON post (writer_id);
+    "#,
+    );
+
+    let mut collector = NameCollector::default();
+
+    for statement in &statements {
+        collector.visit_statement(statement);
+    }
+
+    assert_eq!(
+        collector.tables,
+        vec!["post", "person", "post", "member", "post", "post"]
+    );
+    assert_eq!(collector.columns, vec!["score", "max_score", "base"]);
+}
+
+// 재정의한 메서드에서 walk 함수를 호출하지 않으면 하위 노드를 순회하지 않습니다.
+#[derive(Default)]
+struct ColumnCounter {
+    columns: usize,
+    column_names: usize,
+}
+
+impl Visitor for ColumnCounter {
+    fn visit_column(&mut self, _column: &Column) {
+        self.columns += 1;
+    }
+
+    fn visit_column_name(&mut self, _column_name: &ColumnName) {
+        self.column_names += 1;
+    }
+}
+
+#[test]
+pub fn visitor_skip_children() {
+    let statements = parse("CREATE TABLE foo (a INTEGER DEFAULT b, c INTEGER CHECK (c > 0));");
+
+    let mut counter = ColumnCounter::default();
+    counter.visit_statement(&statements[0]);
+
+    assert_eq!(counter.columns, 2);
+    assert_eq!(counter.column_names, 0);
+}
+
+// CREATE TABLE과 ALTER TABLE ADD CONSTRAINT의 제약조건을 모두 visit_table_constraint로 방문합니다.
+#[derive(Default)]
+struct ConstraintCounter {
+    constraints: usize,
+}
+
+impl Visitor for ConstraintCounter {
+    fn visit_table_constraint(&mut self, _constraint: TableConstraintRef) {
+        self.constraints += 1;
+    }
+}
+
+#[test]
+pub fn visitor_table_constraints() {
+    let statements = parse(
+        r#"
+        CREATE TABLE post (
+            id INTEGER,
+            writer_id INTEGER,
+            PRIMARY KEY (id),
+            UNIQUE (writer_id),
+            FOREIGN KEY (writer_id) REFERENCES person (id),
+            CHECK (id > 0)
+        );
+        ALTER TABLE post ADD CONSTRAINT uk_id UNIQUE (id);
+    "#,
+    );
+
+    let mut counter = ConstraintCounter::default();
+
+    for statement in &statements {
+        counter.visit_statement(statement);
+    }
+
+    assert_eq!(counter.constraints, 5);
+}
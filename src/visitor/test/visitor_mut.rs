@@ -0,0 +1,181 @@
+#![cfg(test)]
+
+use crate::ast::predule::{
+    AlterTableDropColumn, AlterTableRenameColumn, AlterTableRenameTo, ColumnName, DataType,
+    TableConstraint, TableName,
+};
+use crate::visitor::predule::{walk_data_type_mut, VisitorMut};
+use crate::visitor::test::parse;
+
+// 테이블명과 컬럼명을 바꾸는 방문자
+struct Renamer {
+    from: String,
+    to: String,
+}
+
+impl VisitorMut for Renamer {
+    fn visit_table_name_mut(&mut self, table_name: &mut TableName) {
+        if table_name.table_name == self.from {
+            table_name.table_name = self.to.clone();
+        }
+    }
+
+    fn visit_column_name_mut(&mut self, column_name: &mut ColumnName) {
+        if column_name.column_name == self.from {
+            column_name.column_name = self.to.clone();
+        }
+    }
+
+    fn visit_alter_table_rename_to_mut(&mut self, action: &mut AlterTableRenameTo) {
+        if action.name == self.from {
+            action.name = self.to.clone();
+        }
+    }
+
+    fn visit_alter_table_rename_column_mut(&mut self, action: &mut AlterTableRenameColumn) {
+        if action.from_name == self.from {
+            action.from_name = self.to.clone();
+        }
+    }
+
+    fn visit_alter_table_drop_column_mut(&mut self, action: &mut AlterTableDropColumn) {
+        if action.column_name == self.from {
+            action.column_name = self.to.clone();
+        }
+    }
+}
+
+#[test]
+pub fn visitor_mut_rename() {
+    let mut statements = parse(
+        r#"
+        CREATE TABLE person (id INTEGER, age INTEGER CHECK (age > 0));
+        CREATE INDEX idx_age ON person (age);
+        ALTER TABLE person RENAME COLUMN age TO old_age, DROP COLUMN age;
+        ALTER TABLE log RENAME TO person;
+    "#,
+    );
+
+    let mut renamer = Renamer {
+        from: "person".to_owned(),
+        to: "member".to_owned(),
+    };
+
+    for statement in &mut statements {
+        renamer.visit_statement_mut(statement);
+    }
+
+    let mut renamer = Renamer {
+        from: "age".to_owned(),
+        to: "years".to_owned(),
+    };
+
+    for statement in &mut statements {
+        renamer.visit_statement_mut(statement);
+    }
+
+    assert_eq!(
+        statements,
+        parse(
+            r#"
+            CREATE TABLE member (id INTEGER, age INTEGER CHECK (years > 0));
+            CREATE INDEX idx_age ON member (age);
+            ALTER TABLE member RENAME COLUMN years TO old_age, DROP COLUMN years;
+            ALTER TABLE log RENAME TO member;
+        "#,
+        )
+    );
+}
+
+// 부호 없는 정수 타입을 부호 있는 타입으로 바꾸는 방문자
+struct RemoveUnsigned;
+
+impl VisitorMut for RemoveUnsigned {
+    fn visit_data_type_mut(&mut self, data_type: &mut DataType) {
+        walk_data_type_mut(self, data_type);
+
+        if let DataType::Unsigned(inner) = data_type {
+            *data_type = (**inner).clone();
+        }
+    }
+}
+
+#[test]
+pub fn visitor_mut_data_type() {
+    let mut statements = parse("CREATE TABLE foo (id INT UNSIGNED, value BIGINT);");
+
+    RemoveUnsigned.visit_statement_mut(&mut statements[0]);
+
+    assert_eq!(
+        statements,
+        parse("CREATE TABLE foo (id INT, value BIGINT);")
+    );
+
+    // 배열의 요소 타입도 방문
+    let mut data_type =
+        DataType::Array(Box::new(DataType::Unsigned(Box::new(DataType::Int(None)))));
+
+    RemoveUnsigned.visit_data_type_mut(&mut data_type);
+
+    assert_eq!(data_type, DataType::Array(Box::new(DataType::Int(None))));
+}
+
+// 이름 없는 제약조건에 이름을 붙이는 방문자
+struct ConstraintNamer;
+
+impl VisitorMut for ConstraintNamer {
+    fn visit_table_constraint_mut(&mut self, constraint: &mut TableConstraint) {
+        match constraint {
+            TableConstraint::PrimaryKey(primary_key) => {
+                primary_key.key_name.get_or_insert_with(|| "pk".to_owned());
+            }
+            TableConstraint::UniqueKey(unique_key) => {
+                unique_key.key_name.get_or_insert_with(|| "uk".to_owned());
+            }
+            TableConstraint::ForeignKey(foreign_key) => {
+                foreign_key.key_name.get_or_insert_with(|| "fk".to_owned());
+            }
+            TableConstraint::Check(check) => {
+                check.constraint_name.get_or_insert_with(|| "ck".to_owned());
+            }
+        }
+    }
+}
+
+#[test]
+pub fn visitor_mut_table_constraints() {
+    let mut statements = parse(
+        r#"
+        CREATE TABLE post (
+            id INTEGER,
+            writer_id INTEGER,
+            PRIMARY KEY (id),
+            UNIQUE (writer_id),
+            FOREIGN KEY (writer_id) REFERENCES person (id),
+            CHECK (id > 0)
+        );
+        ALTER TABLE post ADD UNIQUE (id);
+    "#,
+    );
+
+    for statement in &mut statements {
+        ConstraintNamer.visit_statement_mut(statement);
+    }
+
+    assert_eq!(
+        statements,
+        parse(
+            r#"
+            CREATE TABLE post (
+                id INTEGER,
+                writer_id INTEGER,
+                CONSTRAINT pk PRIMARY KEY (id),
+                CONSTRAINT uk UNIQUE (writer_id),
+                CONSTRAINT fk FOREIGN KEY (writer_id) REFERENCES person (id),
+                CONSTRAINT ck CHECK (id > 0)
+            );
+            ALTER TABLE post ADD CONSTRAINT uk UNIQUE (id);
+        "#,
+        )
+    );
+}
@@ -0,0 +1,353 @@
+use crate::ast::predule::{
+    AlterColumnAction, AlterDatabaseQuery, AlterTableAction, AlterTableDropColumn, AlterTableQuery,
+    AlterTableRenameColumn, AlterTableRenameTo, CheckConstraint, Column, ColumnName,
+    CreateDatabaseQuery, CreateIndexQuery, CreateTableQuery, DDLStatement, DataType,
+    DropDatabaseQuery, DropIndexQuery, DropTableQuery, Expression, ForeignKey, IndexColumn,
+    PrimaryKey, Reference, SQLStatement, TableConstraintRef, TableName, TableOptions, UniqueKey,
+};
+
+// AST를 읽기 전용으로 순회하는 방문자
+// 모든 visit 메서드는 기본적으로 하위 노드를 순회(walk)합니다.
+// 필요한 노드의 메서드만 재정의하고, 하위 노드도 순회하려면 재정의한 메서드에서 walk 함수를 호출하면 됩니다.
+pub trait Visitor {
+    fn visit_statement(&mut self, statement: &SQLStatement) {
+        walk_statement(self, statement)
+    }
+
+    fn visit_ddl_statement(&mut self, statement: &DDLStatement) {
+        walk_ddl_statement(self, statement)
+    }
+
+    fn visit_create_database_query(&mut self, _query: &CreateDatabaseQuery) {}
+
+    fn visit_alter_database_query(&mut self, _query: &AlterDatabaseQuery) {}
+
+    fn visit_drop_database_query(&mut self, _query: &DropDatabaseQuery) {}
+
+    fn visit_create_table_query(&mut self, query: &CreateTableQuery) {
+        walk_create_table_query(self, query)
+    }
+
+    fn visit_alter_table_query(&mut self, query: &AlterTableQuery) {
+        walk_alter_table_query(self, query)
+    }
+
+    fn visit_alter_table_action(&mut self, action: &AlterTableAction) {
+        walk_alter_table_action(self, action)
+    }
+
+    fn visit_alter_column_action(&mut self, action: &AlterColumnAction) {
+        walk_alter_column_action(self, action)
+    }
+
+    fn visit_alter_table_rename_to(&mut self, _action: &AlterTableRenameTo) {}
+
+    fn visit_alter_table_rename_column(&mut self, _action: &AlterTableRenameColumn) {}
+
+    fn visit_alter_table_drop_column(&mut self, _action: &AlterTableDropColumn) {}
+
+    fn visit_drop_table_query(&mut self, query: &DropTableQuery) {
+        walk_drop_table_query(self, query)
+    }
+
+    fn visit_create_index_query(&mut self, query: &CreateIndexQuery) {
+        walk_create_index_query(self, query)
+    }
+
+    fn visit_drop_index_query(&mut self, query: &DropIndexQuery) {
+        walk_drop_index_query(self, query)
+    }
+
+    fn visit_table_name(&mut self, _table_name: &TableName) {}
+
+    fn visit_column(&mut self, column: &Column) {
+        walk_column(self, column)
+    }
+
+    fn visit_data_type(&mut self, data_type: &DataType) {
+        walk_data_type(self, data_type)
+    }
+
+    fn visit_table_constraint(&mut self, constraint: TableConstraintRef) {
+        walk_table_constraint(self, constraint)
+    }
+
+    fn visit_primary_key(&mut self, _primary_key: &PrimaryKey) {}
+
+    fn visit_unique_key(&mut self, _unique_key: &UniqueKey) {}
+
+    fn visit_foreign_key(&mut self, foreign_key: &ForeignKey) {
+        walk_foreign_key(self, foreign_key)
+    }
+
+    fn visit_reference(&mut self, reference: &Reference) {
+        walk_reference(self, reference)
+    }
+
+    fn visit_check_constraint(&mut self, check: &CheckConstraint) {
+        walk_check_constraint(self, check)
+    }
+
+    fn visit_index_column(&mut self, _index_column: &IndexColumn) {}
+
+    fn visit_table_options(&mut self, _table_options: &TableOptions) {}
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        walk_expression(self, expression)
+    }
+
+    fn visit_column_name(&mut self, _column_name: &ColumnName) {}
+}
+
+pub fn walk_statement<V: Visitor + ?Sized>(visitor: &mut V, statement: &SQLStatement) {
+    match statement {
+        SQLStatement::DDL(statement) => visitor.visit_ddl_statement(statement),
+    }
+}
+
+pub fn walk_ddl_statement<V: Visitor + ?Sized>(visitor: &mut V, statement: &DDLStatement) {
+    match statement {
+        DDLStatement::CreateDatabaseQuery(query) => visitor.visit_create_database_query(query),
+        DDLStatement::AlterDatabase(query) => visitor.visit_alter_database_query(query),
+        DDLStatement::DropDatabaseQuery(query) => visitor.visit_drop_database_query(query),
+        DDLStatement::CreateTableQuery(query) => visitor.visit_create_table_query(query),
+        DDLStatement::AlterTableQuery(query) => visitor.visit_alter_table_query(query),
+        DDLStatement::DropTableQuery(query) => visitor.visit_drop_table_query(query),
+        DDLStatement::CreateIndexQuery(query) => visitor.visit_create_index_query(query),
+        DDLStatement::DropIndexQuery(query) => visitor.visit_drop_index_query(query),
+    }
+}
+
+pub fn walk_create_table_query<V: Visitor + ?Sized>(visitor: &mut V, query: &CreateTableQuery) {
+    if let Some(table) = &query.table {
+        visitor.visit_table_name(table);
+    }
+
+    for column in &query.columns {
+        visitor.visit_column(column);
+    }
+
+    // ALTER TABLE ADD CONSTRAINT와 같이 visit_table_constraint를 거치도록 TableConstraintRef로 감싸서 방문합니다.
+    let constraints = query
+        .primary_key
+        .iter()
+        .map(TableConstraintRef::PrimaryKey)
+        .chain(query.unique_keys.iter().map(TableConstraintRef::UniqueKey))
+        .chain(
+            query
+                .foreign_keys
+                .iter()
+                .map(TableConstraintRef::ForeignKey),
+        )
+        .chain(
+            query
+                .check_constraints
+                .iter()
+                .map(TableConstraintRef::Check),
+        );
+
+    for constraint in constraints {
+        visitor.visit_table_constraint(constraint);
+    }
+
+    for index in &query.indexes {
+        for column in &index.columns {
+            visitor.visit_index_column(column);
+        }
+    }
+
+    if let Some(table_options) = &query.table_options {
+        visitor.visit_table_options(table_options);
+    }
+}
+
+pub fn walk_alter_table_query<V: Visitor + ?Sized>(visitor: &mut V, query: &AlterTableQuery) {
+    if let Some(table) = &query.table {
+        visitor.visit_table_name(table);
+    }
+
+    for action in &query.actions {
+        visitor.visit_alter_table_action(action);
+    }
+}
+
+pub fn walk_alter_table_action<V: Visitor + ?Sized>(visitor: &mut V, action: &AlterTableAction) {
+    match action {
+        AlterTableAction::AddColumn(action) => visitor.visit_column(&action.column),
+        AlterTableAction::ModifyColumn(action) => visitor.visit_column(&action.column),
+        AlterTableAction::ChangeColumn(action) => visitor.visit_column(&action.column),
+        AlterTableAction::AlterColumn(action) => visitor.visit_alter_column_action(&action.action),
+        AlterTableAction::AddConstraint(action) => {
+            visitor.visit_table_constraint((&action.constraint).into())
+        }
+        AlterTableAction::AddIndex(action) => {
+            for column in &action.index.columns {
+                visitor.visit_index_column(column);
+            }
+        }
+        AlterTableAction::AlterTableRenameTo(action) => visitor.visit_alter_table_rename_to(action),
+        AlterTableAction::RenameColumn(action) => visitor.visit_alter_table_rename_column(action),
+        AlterTableAction::DropColumn(action) => visitor.visit_alter_table_drop_column(action),
+        AlterTableAction::DropConstraint(_)
+        | AlterTableAction::DropPrimaryKey
+        | AlterTableAction::DropIndex(_)
+        | AlterTableAction::RenameConstraint(_)
+        | AlterTableAction::ValidateConstraint(_)
+        | AlterTableAction::SetTableOption(_) => {}
+    }
+}
+
+pub fn walk_alter_column_action<V: Visitor + ?Sized>(visitor: &mut V, action: &AlterColumnAction) {
+    match action {
+        AlterColumnAction::AlterColumnSetType(action) => {
+            visitor.visit_data_type(&action.data_type);
+
+            if let Some(using) = &action.using {
+                visitor.visit_expression(using);
+            }
+        }
+        AlterColumnAction::AlterColumnSetDefault(action) => {
+            visitor.visit_expression(&action.expression)
+        }
+        AlterColumnAction::AlterColumnSetNotNull
+        | AlterColumnAction::AlterColumnDropNotNull
+        | AlterColumnAction::AlterColumnDropDefault(_)
+        | AlterColumnAction::AlterColumnSetComment(_)
+        | AlterColumnAction::AlterColumnDropComment
+        | AlterColumnAction::AlterColumnAddIdentity(_)
+        | AlterColumnAction::AlterColumnSetIdentity(_)
+        | AlterColumnAction::AlterColumnDropIdentity(_)
+        | AlterColumnAction::AlterColumnSetStatistics(_)
+        | AlterColumnAction::AlterColumnSetCollation(_) => {}
+    }
+}
+
+pub fn walk_drop_table_query<V: Visitor + ?Sized>(visitor: &mut V, query: &DropTableQuery) {
+    for table in &query.tables {
+        visitor.visit_table_name(table);
+    }
+}
+
+pub fn walk_create_index_query<V: Visitor + ?Sized>(visitor: &mut V, query: &CreateIndexQuery) {
+    visitor.visit_table_name(&query.table);
+
+    for column in &query.columns {
+        visitor.visit_index_column(column);
+    }
+}
+
+pub fn walk_drop_index_query<V: Visitor + ?Sized>(visitor: &mut V, query: &DropIndexQuery) {
+    if let Some(table) = &query.table {
+        visitor.visit_table_name(table);
+    }
+}
+
+pub fn walk_column<V: Visitor + ?Sized>(visitor: &mut V, column: &Column) {
+    visitor.visit_data_type(&column.data_type);
+
+    if let Some(default) = &column.default {
+        visitor.visit_expression(default);
+    }
+
+    if let Some(reference) = &column.reference {
+        visitor.visit_reference(reference);
+    }
+
+    for check in &column.checks {
+        visitor.visit_check_constraint(check);
+    }
+
+    if let Some(generated) = &column.generated {
+        visitor.visit_expression(&generated.expression);
+    }
+
+    if let Some(on_update) = &column.on_update {
+        visitor.visit_expression(on_update);
+    }
+}
+
+pub fn walk_data_type<V: Visitor + ?Sized>(visitor: &mut V, data_type: &DataType) {
+    if let DataType::Unsigned(data_type)
+    | DataType::Signed(data_type)
+    | DataType::Zerofill(data_type)
+    | DataType::Array(data_type) = data_type
+    {
+        visitor.visit_data_type(data_type);
+    }
+}
+
+pub fn walk_table_constraint<V: Visitor + ?Sized>(visitor: &mut V, constraint: TableConstraintRef) {
+    match constraint {
+        TableConstraintRef::PrimaryKey(primary_key) => visitor.visit_primary_key(primary_key),
+        TableConstraintRef::UniqueKey(unique_key) => visitor.visit_unique_key(unique_key),
+        TableConstraintRef::ForeignKey(foreign_key) => visitor.visit_foreign_key(foreign_key),
+        TableConstraintRef::Check(check) => visitor.visit_check_constraint(check),
+    }
+}
+
+pub fn walk_foreign_key<V: Visitor + ?Sized>(visitor: &mut V, foreign_key: &ForeignKey) {
+    visitor.visit_table_name(&foreign_key.table);
+    visitor.visit_table_name(&foreign_key.referenced_table);
+}
+
+pub fn walk_reference<V: Visitor + ?Sized>(visitor: &mut V, reference: &Reference) {
+    visitor.visit_table_name(&reference.referenced_table);
+}
+
+pub fn walk_check_constraint<V: Visitor + ?Sized>(visitor: &mut V, check: &CheckConstraint) {
+    visitor.visit_expression(&check.expression);
+}
+
+pub fn walk_expression<V: Visitor + ?Sized>(visitor: &mut V, expression: &Expression) {
+    match expression {
+        Expression::Column(column_name) => visitor.visit_column_name(column_name),
+        Expression::Unary(expression) => visitor.visit_expression(&expression.operand),
+        Expression::Binary(expression) => {
+            visitor.visit_expression(&expression.lhs);
+            visitor.visit_expression(&expression.rhs);
+        }
+        Expression::FunctionCall(expression) => {
+            for argument in &expression.arguments {
+                visitor.visit_expression(argument);
+            }
+        }
+        Expression::Cast(expression) => {
+            visitor.visit_expression(&expression.expression);
+            visitor.visit_data_type(&expression.data_type);
+        }
+        Expression::Case(expression) => {
+            if let Some(operand) = &expression.operand {
+                visitor.visit_expression(operand);
+            }
+
+            for when_clause in &expression.when_clauses {
+                visitor.visit_expression(&when_clause.condition);
+                visitor.visit_expression(&when_clause.result);
+            }
+
+            if let Some(else_result) = &expression.else_result {
+                visitor.visit_expression(else_result);
+            }
+        }
+        Expression::InList(expression) => {
+            visitor.visit_expression(&expression.expression);
+
+            for item in &expression.list {
+                visitor.visit_expression(item);
+            }
+        }
+        Expression::Between(expression) => {
+            visitor.visit_expression(&expression.expression);
+            visitor.visit_expression(&expression.low);
+            visitor.visit_expression(&expression.high);
+        }
+        Expression::Parentheses(expression) => visitor.visit_expression(&expression.expression),
+        Expression::Integer(_)
+        | Expression::Float(_)
+        | Expression::String(_)
+        | Expression::HexString(_)
+        | Expression::Boolean(_)
+        | Expression::Null
+        | Expression::SQLValueFunction(_) => {}
+    }
+}
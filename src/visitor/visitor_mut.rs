@@ -0,0 +1,405 @@
+use crate::ast::predule::{
+    AlterColumnAction, AlterDatabaseQuery, AlterTableAction, AlterTableDropColumn, AlterTableQuery,
+    AlterTableRenameColumn, AlterTableRenameTo, CheckConstraint, Column, ColumnName,
+    CreateDatabaseQuery, CreateIndexQuery, CreateTableQuery, DDLStatement, DataType,
+    DropDatabaseQuery, DropIndexQuery, DropTableQuery, Expression, ForeignKey, IndexColumn,
+    PrimaryKey, Reference, SQLStatement, TableConstraint, TableName, TableOptions, UniqueKey,
+};
+
+// AST를 순회하면서 노드를 직접 수정할 수 있는 방문자
+// 모든 visit_*_mut 메서드는 기본적으로 하위 노드를 순회(walk)합니다.
+// 필요한 노드의 메서드만 재정의하고, 하위 노드도 순회하려면 재정의한 메서드에서 walk 함수를 호출하면 됩니다.
+pub trait VisitorMut {
+    fn visit_statement_mut(&mut self, statement: &mut SQLStatement) {
+        walk_statement_mut(self, statement)
+    }
+
+    fn visit_ddl_statement_mut(&mut self, statement: &mut DDLStatement) {
+        walk_ddl_statement_mut(self, statement)
+    }
+
+    fn visit_create_database_query_mut(&mut self, _query: &mut CreateDatabaseQuery) {}
+
+    fn visit_alter_database_query_mut(&mut self, _query: &mut AlterDatabaseQuery) {}
+
+    fn visit_drop_database_query_mut(&mut self, _query: &mut DropDatabaseQuery) {}
+
+    fn visit_create_table_query_mut(&mut self, query: &mut CreateTableQuery) {
+        walk_create_table_query_mut(self, query)
+    }
+
+    fn visit_alter_table_query_mut(&mut self, query: &mut AlterTableQuery) {
+        walk_alter_table_query_mut(self, query)
+    }
+
+    fn visit_alter_table_action_mut(&mut self, action: &mut AlterTableAction) {
+        walk_alter_table_action_mut(self, action)
+    }
+
+    fn visit_alter_column_action_mut(&mut self, action: &mut AlterColumnAction) {
+        walk_alter_column_action_mut(self, action)
+    }
+
+    fn visit_alter_table_rename_to_mut(&mut self, _action: &mut AlterTableRenameTo) {}
+
+    fn visit_alter_table_rename_column_mut(&mut self, _action: &mut AlterTableRenameColumn) {}
+
+    fn visit_alter_table_drop_column_mut(&mut self, _action: &mut AlterTableDropColumn) {}
+
+    fn visit_drop_table_query_mut(&mut self, query: &mut DropTableQuery) {
+        walk_drop_table_query_mut(self, query)
+    }
+
+    fn visit_create_index_query_mut(&mut self, query: &mut CreateIndexQuery) {
+        walk_create_index_query_mut(self, query)
+    }
+
+    fn visit_drop_index_query_mut(&mut self, query: &mut DropIndexQuery) {
+        walk_drop_index_query_mut(self, query)
+    }
+
+    fn visit_table_name_mut(&mut self, _table_name: &mut TableName) {}
+
+    fn visit_column_mut(&mut self, column: &mut Column) {
+        walk_column_mut(self, column)
+    }
+
+    fn visit_data_type_mut(&mut self, data_type: &mut DataType) {
+        walk_data_type_mut(self, data_type)
+    }
+
+    fn visit_table_constraint_mut(&mut self, constraint: &mut TableConstraint) {
+        walk_table_constraint_mut(self, constraint)
+    }
+
+    fn visit_primary_key_mut(&mut self, _primary_key: &mut PrimaryKey) {}
+
+    fn visit_unique_key_mut(&mut self, _unique_key: &mut UniqueKey) {}
+
+    fn visit_foreign_key_mut(&mut self, foreign_key: &mut ForeignKey) {
+        walk_foreign_key_mut(self, foreign_key)
+    }
+
+    fn visit_reference_mut(&mut self, reference: &mut Reference) {
+        walk_reference_mut(self, reference)
+    }
+
+    fn visit_check_constraint_mut(&mut self, check: &mut CheckConstraint) {
+        walk_check_constraint_mut(self, check)
+    }
+
+    fn visit_index_column_mut(&mut self, _index_column: &mut IndexColumn) {}
+
+    fn visit_table_options_mut(&mut self, _table_options: &mut TableOptions) {}
+
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        walk_expression_mut(self, expression)
+    }
+
+    fn visit_column_name_mut(&mut self, _column_name: &mut ColumnName) {}
+}
+
+pub fn walk_statement_mut<V: VisitorMut + ?Sized>(visitor: &mut V, statement: &mut SQLStatement) {
+    match statement {
+        SQLStatement::DDL(statement) => visitor.visit_ddl_statement_mut(statement),
+    }
+}
+
+pub fn walk_ddl_statement_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    statement: &mut DDLStatement,
+) {
+    match statement {
+        DDLStatement::CreateDatabaseQuery(query) => visitor.visit_create_database_query_mut(query),
+        DDLStatement::AlterDatabase(query) => visitor.visit_alter_database_query_mut(query),
+        DDLStatement::DropDatabaseQuery(query) => visitor.visit_drop_database_query_mut(query),
+        DDLStatement::CreateTableQuery(query) => visitor.visit_create_table_query_mut(query),
+        DDLStatement::AlterTableQuery(query) => visitor.visit_alter_table_query_mut(query),
+        DDLStatement::DropTableQuery(query) => visitor.visit_drop_table_query_mut(query),
+        DDLStatement::CreateIndexQuery(query) => visitor.visit_create_index_query_mut(query),
+        DDLStatement::DropIndexQuery(query) => visitor.visit_drop_index_query_mut(query),
+    }
+}
+
+pub fn walk_create_table_query_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    query: &mut CreateTableQuery,
+) {
+    if let Some(table) = &mut query.table {
+        visitor.visit_table_name_mut(table);
+    }
+
+    for column in &mut query.columns {
+        visitor.visit_column_mut(column);
+    }
+
+    // ALTER TABLE ADD CONSTRAINT와 같이 visit_table_constraint_mut을 거치도록 TableConstraint로 꺼내서 방문한 뒤,
+    // 방문이 끝난 제약조건을 종류에 맞는 목록에 되돌려 놓습니다.
+    let mut constraints: Vec<TableConstraint> = query
+        .primary_key
+        .take()
+        .map(TableConstraint::from)
+        .into_iter()
+        .chain(
+            std::mem::take(&mut query.unique_keys)
+                .into_iter()
+                .map(Into::into),
+        )
+        .chain(
+            std::mem::take(&mut query.foreign_keys)
+                .into_iter()
+                .map(Into::into),
+        )
+        .chain(
+            std::mem::take(&mut query.check_constraints)
+                .into_iter()
+                .map(Into::into),
+        )
+        .collect();
+
+    for constraint in &mut constraints {
+        visitor.visit_table_constraint_mut(constraint);
+    }
+
+    for constraint in constraints {
+        match constraint {
+            TableConstraint::PrimaryKey(primary_key) => query.primary_key = Some(primary_key),
+            TableConstraint::UniqueKey(unique_key) => query.unique_keys.push(unique_key),
+            TableConstraint::ForeignKey(foreign_key) => query.foreign_keys.push(foreign_key),
+            TableConstraint::Check(check) => query.check_constraints.push(check),
+        }
+    }
+
+    for index in &mut query.indexes {
+        for column in &mut index.columns {
+            visitor.visit_index_column_mut(column);
+        }
+    }
+
+    if let Some(table_options) = &mut query.table_options {
+        visitor.visit_table_options_mut(table_options);
+    }
+}
+
+pub fn walk_alter_table_query_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    query: &mut AlterTableQuery,
+) {
+    if let Some(table) = &mut query.table {
+        visitor.visit_table_name_mut(table);
+    }
+
+    for action in &mut query.actions {
+        visitor.visit_alter_table_action_mut(action);
+    }
+}
+
+pub fn walk_alter_table_action_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    action: &mut AlterTableAction,
+) {
+    match action {
+        AlterTableAction::AddColumn(action) => visitor.visit_column_mut(&mut action.column),
+        AlterTableAction::ModifyColumn(action) => visitor.visit_column_mut(&mut action.column),
+        AlterTableAction::ChangeColumn(action) => visitor.visit_column_mut(&mut action.column),
+        AlterTableAction::AlterColumn(action) => {
+            visitor.visit_alter_column_action_mut(&mut action.action)
+        }
+        AlterTableAction::AddConstraint(action) => {
+            visitor.visit_table_constraint_mut(&mut action.constraint)
+        }
+        AlterTableAction::AddIndex(action) => {
+            for column in &mut action.index.columns {
+                visitor.visit_index_column_mut(column);
+            }
+        }
+        AlterTableAction::AlterTableRenameTo(action) => {
+            visitor.visit_alter_table_rename_to_mut(action)
+        }
+        AlterTableAction::RenameColumn(action) => {
+            visitor.visit_alter_table_rename_column_mut(action)
+        }
+        AlterTableAction::DropColumn(action) => visitor.visit_alter_table_drop_column_mut(action),
+        AlterTableAction::DropConstraint(_)
+        | AlterTableAction::DropPrimaryKey
+        | AlterTableAction::DropIndex(_)
+        | AlterTableAction::RenameConstraint(_)
+        | AlterTableAction::ValidateConstraint(_)
+        | AlterTableAction::SetTableOption(_) => {}
+    }
+}
+
+pub fn walk_alter_column_action_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    action: &mut AlterColumnAction,
+) {
+    match action {
+        AlterColumnAction::AlterColumnSetType(action) => {
+            visitor.visit_data_type_mut(&mut action.data_type);
+
+            if let Some(using) = &mut action.using {
+                visitor.visit_expression_mut(using);
+            }
+        }
+        AlterColumnAction::AlterColumnSetDefault(action) => {
+            visitor.visit_expression_mut(&mut action.expression)
+        }
+        AlterColumnAction::AlterColumnSetNotNull
+        | AlterColumnAction::AlterColumnDropNotNull
+        | AlterColumnAction::AlterColumnDropDefault(_)
+        | AlterColumnAction::AlterColumnSetComment(_)
+        | AlterColumnAction::AlterColumnDropComment
+        | AlterColumnAction::AlterColumnAddIdentity(_)
+        | AlterColumnAction::AlterColumnSetIdentity(_)
+        | AlterColumnAction::AlterColumnDropIdentity(_)
+        | AlterColumnAction::AlterColumnSetStatistics(_)
+        | AlterColumnAction::AlterColumnSetCollation(_) => {}
+    }
+}
+
+pub fn walk_drop_table_query_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    query: &mut DropTableQuery,
+) {
+    for table in &mut query.tables {
+        visitor.visit_table_name_mut(table);
+    }
+}
+
+pub fn walk_create_index_query_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    query: &mut CreateIndexQuery,
+) {
+    visitor.visit_table_name_mut(&mut query.table);
+
+    for column in &mut query.columns {
+        visitor.visit_index_column_mut(column);
+    }
+}
+
+pub fn walk_drop_index_query_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    query: &mut DropIndexQuery,
+) {
+    if let Some(table) = &mut query.table {
+        visitor.visit_table_name_mut(table);
+    }
+}
+
+pub fn walk_column_mut<V: VisitorMut + ?Sized>(visitor: &mut V, column: &mut Column) {
+    visitor.visit_data_type_mut(&mut column.data_type);
+
+    if let Some(default) = &mut column.default {
+        visitor.visit_expression_mut(default);
+    }
+
+    if let Some(reference) = &mut column.reference {
+        visitor.visit_reference_mut(reference);
+    }
+
+    for check in &mut column.checks {
+        visitor.visit_check_constraint_mut(check);
+    }
+
+    if let Some(generated) = &mut column.generated {
+        visitor.visit_expression_mut(&mut generated.expression);
+    }
+
+    if let Some(on_update) = &mut column.on_update {
+        visitor.visit_expression_mut(on_update);
+    }
+}
+
+pub fn walk_data_type_mut<V: VisitorMut + ?Sized>(visitor: &mut V, data_type: &mut DataType) {
+    if let DataType::Unsigned(data_type)
+    | DataType::Signed(data_type)
+    | DataType::Zerofill(data_type)
+    | DataType::Array(data_type) = data_type
+    {
+        visitor.visit_data_type_mut(data_type);
+    }
+}
+
+pub fn walk_table_constraint_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    constraint: &mut TableConstraint,
+) {
+    match constraint {
+        TableConstraint::PrimaryKey(primary_key) => visitor.visit_primary_key_mut(primary_key),
+        TableConstraint::UniqueKey(unique_key) => visitor.visit_unique_key_mut(unique_key),
+        TableConstraint::ForeignKey(foreign_key) => visitor.visit_foreign_key_mut(foreign_key),
+        TableConstraint::Check(check) => visitor.visit_check_constraint_mut(check),
+    }
+}
+
+pub fn walk_foreign_key_mut<V: VisitorMut + ?Sized>(visitor: &mut V, foreign_key: &mut ForeignKey) {
+    visitor.visit_table_name_mut(&mut foreign_key.table);
+    visitor.visit_table_name_mut(&mut foreign_key.referenced_table);
+}
+
+pub fn walk_reference_mut<V: VisitorMut + ?Sized>(visitor: &mut V, reference: &mut Reference) {
+    visitor.visit_table_name_mut(&mut reference.referenced_table);
+}
+
+pub fn walk_check_constraint_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    check: &mut CheckConstraint,
+) {
+    visitor.visit_expression_mut(&mut check.expression);
+}
+
+pub fn walk_expression_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expression: &mut Expression) {
+    match expression {
+        Expression::Column(column_name) => visitor.visit_column_name_mut(column_name),
+        Expression::Unary(expression) => visitor.visit_expression_mut(&mut expression.operand),
+        Expression::Binary(expression) => {
+            visitor.visit_expression_mut(&mut expression.lhs);
+            visitor.visit_expression_mut(&mut expression.rhs);
+        }
+        Expression::FunctionCall(expression) => {
+            for argument in &mut expression.arguments {
+                visitor.visit_expression_mut(argument);
+            }
+        }
+        Expression::Cast(expression) => {
+            visitor.visit_expression_mut(&mut expression.expression);
+            visitor.visit_data_type_mut(&mut expression.data_type);
+        }
+        Expression::Case(expression) => {
+            if let Some(operand) = &mut expression.operand {
+                visitor.visit_expression_mut(operand);
+            }
+
+            for when_clause in &mut expression.when_clauses {
+                visitor.visit_expression_mut(&mut when_clause.condition);
+                visitor.visit_expression_mut(&mut when_clause.result);
+            }
+
+            if let Some(else_result) = &mut expression.else_result {
+                visitor.visit_expression_mut(else_result);
+            }
+        }
+        Expression::InList(expression) => {
+            visitor.visit_expression_mut(&mut expression.expression);
+
+            for item in &mut expression.list {
+                visitor.visit_expression_mut(item);
+            }
+        }
+        Expression::Between(expression) => {
+            visitor.visit_expression_mut(&mut expression.expression);
+            visitor.visit_expression_mut(&mut expression.low);
+            visitor.visit_expression_mut(&mut expression.high);
+        }
+        Expression::Parentheses(expression) => {
+            visitor.visit_expression_mut(&mut expression.expression)
+        }
+        Expression::Integer(_)
+        | Expression::Float(_)
+        | Expression::String(_)
+        | Expression::HexString(_)
+        | Expression::Boolean(_)
+        | Expression::Null
+        | Expression::SQLValueFunction(_) => {}
+    }
+}